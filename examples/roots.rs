@@ -0,0 +1,110 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+// The following program solves the quadratic equation x^2 - 5 = 0, whose positive root is \sqrt{5}. It first brackets the root in the
+// interval (0,5) with the bisection, false position and Brent algorithms, then polishes it from the initial guess 5 with the Newton,
+// secant and Steffenson algorithms.
+
+extern crate rgsl;
+
+struct QuadraticParams {
+    a: f64,
+    b: f64,
+    c: f64
+}
+
+fn quadratic(x: f64, params: &mut QuadraticParams) -> f64 {
+    (params.a * x + params.b) * x + params.c
+}
+
+fn quadratic_deriv(x: f64, params: &mut QuadraticParams) -> f64 {
+    2f64 * params.a * x + params.b
+}
+
+fn quadratic_fdf(x: f64, params: &mut QuadraticParams, y: &mut f64, dy: &mut f64) {
+    *y = (params.a * x + params.b) * x + params.c;
+    *dy = 2f64 * params.a * x + params.b;
+}
+
+fn bracketing(t: &rgsl::RootFSolverType<QuadraticParams>) {
+    let mut iter = 0usize;
+    let max_iter = 100usize;
+    let r_expected = 5f64.sqrt();
+    let mut params = QuadraticParams { a: 1f64, b: 0f64, c: -5f64 };
+
+    let mut s = rgsl::RootFSolver::new(t).unwrap();
+
+    s.set(quadratic, &mut params, 0f64, 5f64);
+
+    println!("using {} method", s.name());
+
+    println!("{:5} [{:9}, {:9}] {:9} {:10} {:9}", "iter", "lower", "upper", "root", "err", "err(est)");
+
+    loop {
+        iter += 1;
+        s.iterate();
+
+        let r = s.root();
+        let x_lo = s.x_lower();
+        let x_hi = s.x_upper();
+        let status = rgsl::roots::test_interval(x_lo, x_hi, 0f64, 0.001f64);
+
+        if status == rgsl::Value::Success {
+            println!("Converged:");
+        }
+
+        println!("{:5} [{:.7}, {:.7}] {:.7} {:+.7} {:.7}", iter, x_lo, x_hi, r, r - r_expected, x_hi - x_lo);
+        if status != rgsl::Value::Continue || iter >= max_iter {
+            break;
+        }
+    }
+    println!("");
+}
+
+fn polishing(t: &rgsl::RootFdfSolverType<QuadraticParams>) {
+    let mut iter = 0usize;
+    let max_iter = 100usize;
+    let r_expected = 5f64.sqrt();
+    let mut x = 5f64;
+    let mut params = QuadraticParams { a: 1f64, b: 0f64, c: -5f64 };
+
+    let mut s = rgsl::RootFdfSolver::new(t).unwrap();
+
+    s.set(quadratic, quadratic_deriv, quadratic_fdf, &mut params, x);
+
+    println!("using {} method", s.name());
+
+    println!("{:5} {:10} {:10} {:10}", "iter", "root", "err", "err(est)");
+
+    loop {
+        iter += 1;
+        s.iterate();
+
+        let x0 = x;
+
+        x = s.root();
+
+        let status = rgsl::roots::test_delta(x, x0, 0f64, 1e-3f64);
+
+        if status == rgsl::Value::Success {
+            println!("Converged:");
+        }
+
+        println!("{:5} {:10.7} {:+10.7} {:10.7}", iter, x, x - r_expected, x - x0);
+        if status != rgsl::Value::Continue || iter >= max_iter {
+            break;
+        }
+    }
+    println!("");
+}
+
+fn main() {
+    bracketing(&rgsl::RootFSolverType::bisection());
+    bracketing(&rgsl::RootFSolverType::falsepos());
+    bracketing(&rgsl::RootFSolverType::brent());
+
+    polishing(&rgsl::RootFdfSolverType::newton());
+    polishing(&rgsl::RootFdfSolverType::secant());
+    polishing(&rgsl::RootFdfSolverType::steffenson());
+}
@@ -8,8 +8,8 @@ extern crate rgsl;
 
 fn main() {
     println!("=== VECTOR PART ===");
-    let v = rgsl::VectorF64::new(3).unwrap();
-  
+    let mut v = rgsl::VectorF64::new(3).unwrap();
+
     for i in 0..3 {
         v.set(i, 1.23f64 + i as f64);
     }
@@ -21,9 +21,30 @@ fn main() {
     // or you can do :
     // println!("{}", c);
 
+    // the elements can also be accessed with indexing and iterators
+    v[0] = 0.5f64;
+    for x in v.iter_mut() {
+        *x *= 2f64;
+    }
+    println!("v = {:?}, sum = {}", v, v.iter().fold(0f64, |acc, x| acc + x));
+
+    // the arithmetic operators allocate a new vector
+    let w = (&v + &v).unwrap();
+    let w = (&w * 0.25f64).unwrap();
+    println!("(v + v) * 0.25 = {:?}", w);
+
+    // a view borrowing v can modify a part of it
+    {
+        let mut even = v.subvector_with_stride_mut(0, 2, 2);
+
+        even.set_zero();
+    }
+    println!("v with zeroed even elements = {:?}", v);
+    println!("v[1..3] = {:?}", v.subvector(1, 2).vector());
+
     println!("=== MATRIX PART ===");
-    let m = rgsl::MatrixF64::new(10, 3).unwrap();
-  
+    let mut m = rgsl::MatrixF64::new(10, 3).unwrap();
+
     for i in 0..10 {
         for j in 0..3 {
             m.set(i, j, 0.23f64 + 100f64 * i as f64 + j as f64);
@@ -37,4 +58,24 @@ fn main() {
     }
     // or you can do :
     // println!("{}", m);
-}
\ No newline at end of file
+
+    // rows and columns are vector views of the matrix
+    for (i, row) in m.rows().enumerate().take(3) {
+        println!("row {} = {:?}", i, row.vector());
+    }
+    match m.column(5) {
+        Some(c) => println!("column 5 = {:?}", c.vector()),
+        None => println!("the matrix has no column 5"),
+    }
+
+    // and can be modified through the _mut variants
+    m.row_mut(0).unwrap().set_all(-1f64);
+    m.diagonal_mut().scale(10f64);
+    m[(9, 2)] = 0f64;
+
+    let sub = m.submatrix(0, 0, 3, 3).unwrap();
+    for i in 0..3 {
+        println!("{} {} {}", sub.matrix()[(i, 0)], sub.matrix()[(i, 1)], sub.matrix()[(i, 2)]);
+    }
+    println!("m(9,2) = {}", m[(9, 2)]);
+}
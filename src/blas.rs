@@ -761,4 +761,303 @@ pub mod level3 {
         unsafe { ::ffi::gsl_blas_zher2k(uplo, trans, ::std::mem::transmute(*alpha), ::ffi::FFI::unwrap(A),
             ::ffi::FFI::unwrap(B), beta, ::ffi::FFI::unwrap(C)) }
     }
-}
\ No newline at end of file
+}
+
+/// The BLAS functions which exist for every floating point element type, dispatching on the type of their arguments instead of having
+/// a separate entry point for each of them. For example, `generic::dot` calls `sdot`, `ddot`, `cdotu` or `zdotu` depending on whether
+/// its arguments are VectorF32, VectorF64, VectorComplexF32 or VectorComplexF64. For complex vectors, `dot` is the unconjugated product
+/// x^T y and `ger` the unconjugated rank-1 update. The functions which only exist for real or for complex types, like `drot` or
+/// `zherk`, are not part of this module.
+pub mod generic {
+    use enums::GSLResult;
+    use types::generic::{GslVector, GslMatrix};
+    use types::{ComplexF32, ComplexF64, VectorF32, VectorF64, VectorComplexF32, VectorComplexF64, MatrixF32, MatrixF64,
+        MatrixComplexF32, MatrixComplexF64};
+    use super::{level1, level2, level3};
+
+    /// The vectors on which the level 1 BLAS functions are available, with elements of type T.
+    pub trait BlasVector<T>: GslVector<T> {
+        /// The real type of the norms of the vector, which is T for real vectors.
+        type Real;
+
+        /// Computes the scalar product x^T y of the vectors x and y.
+        fn dot(x: &Self, y: &Self) -> GSLResult<T>;
+        /// Computes the Euclidean norm ||x||_2 of the vector x.
+        fn nrm2(x: &Self) -> Self::Real;
+        /// Computes the absolute sum of the elements of the vector x.
+        fn asum(x: &Self) -> Self::Real;
+        /// Returns the index of the largest element of the vector x.
+        fn iamax(x: &Self) -> u32;
+        /// Exchanges the elements of the vectors x and y.
+        fn swap(x: &mut Self, y: &mut Self) -> GSLResult<()>;
+        /// Copies the elements of the vector x into the vector y.
+        fn copy(x: &Self, y: &mut Self) -> GSLResult<()>;
+        /// Computes the sum y = \alpha x + y for the vectors x and y.
+        fn axpy(alpha: T, x: &Self, y: &mut Self) -> GSLResult<()>;
+        /// Rescales the vector x by the multiplicative factor alpha.
+        fn scal(alpha: T, x: &mut Self);
+    }
+
+    /// The matrices on which the level 2 and level 3 BLAS functions are available, with elements of type T.
+    pub trait BlasMatrix<T>: GslMatrix<T> {
+        /// The vector type with the same elements as the matrix.
+        type Vector: BlasVector<T>;
+
+        /// Computes the matrix-vector product and sum y = \alpha op(A) x + \beta y.
+        fn gemv(transA: ::cblas::Transpose, alpha: T, A: &Self, x: &Self::Vector, beta: T, y: &mut Self::Vector) -> GSLResult<()>;
+        /// Computes the matrix-vector product x = op(A) x for the triangular matrix A.
+        fn trmv(uplo: ::cblas::Uplo, transA: ::cblas::Transpose, diag: ::cblas::Diag, A: &Self, x: &mut Self::Vector) -> GSLResult<()>;
+        /// Computes inv(op(A)) x for the triangular matrix A, storing the result in x.
+        fn trsv(uplo: ::cblas::Uplo, transA: ::cblas::Transpose, diag: ::cblas::Diag, A: &Self, x: &mut Self::Vector) -> GSLResult<()>;
+        /// Computes the rank-1 update A = \alpha x y^T + A of the matrix A.
+        fn ger(alpha: T, x: &Self::Vector, y: &Self::Vector, A: &mut Self) -> GSLResult<()>;
+        /// Computes the matrix-matrix product and sum C = \alpha op(A) op(B) + \beta C.
+        fn gemm(transA: ::cblas::Transpose, transB: ::cblas::Transpose, alpha: T, A: &Self, B: &Self, beta: T,
+            C: &mut Self) -> GSLResult<()>;
+        /// Computes the matrix-matrix product and sum C = \alpha A B + \beta C or C = \alpha B A + \beta C for the symmetric
+        /// matrix A.
+        fn symm(side: ::cblas::Side, uplo: ::cblas::Uplo, alpha: T, A: &Self, B: &Self, beta: T, C: &mut Self) -> GSLResult<()>;
+        /// Computes the matrix-matrix product B = \alpha op(A) B or B = \alpha B op(A) for the triangular matrix A.
+        fn trmm(side: ::cblas::Side, uplo: ::cblas::Uplo, transA: ::cblas::Transpose, diag: ::cblas::Diag, alpha: T, A: &Self,
+            B: &mut Self) -> GSLResult<()>;
+        /// Computes B = \alpha inv(op(A)) B or B = \alpha B inv(op(A)) for the triangular matrix A.
+        fn trsm(side: ::cblas::Side, uplo: ::cblas::Uplo, transA: ::cblas::Transpose, diag: ::cblas::Diag, alpha: T, A: &Self,
+            B: &mut Self) -> GSLResult<()>;
+        /// Computes the rank-k update C = \alpha A A^T + \beta C or C = \alpha A^T A + \beta C of the symmetric matrix C.
+        fn syrk(uplo: ::cblas::Uplo, trans: ::cblas::Transpose, alpha: T, A: &Self, beta: T, C: &mut Self) -> GSLResult<()>;
+        /// Computes the rank-2k update C = \alpha A B^T + \alpha B A^T + \beta C or C = \alpha A^T B + \alpha B^T A + \beta C of the
+        /// symmetric matrix C.
+        fn syr2k(uplo: ::cblas::Uplo, trans: ::cblas::Transpose, alpha: T, A: &Self, B: &Self, beta: T, C: &mut Self) -> GSLResult<()>;
+    }
+
+    // The complex functions take their scalar arguments by reference, the other ones by value.
+    macro_rules! arg {
+        (val, $x:expr) => ($x);
+        (ref, $x:expr) => (&$x);
+    }
+
+    macro_rules! impl_blas {
+        ($vector:ident, $matrix:ident, $elem:ty, $real:ty, $zero:expr, $pass:tt, $dot:ident, $nrm2:ident, $asum:ident, $iamax:ident,
+         $swap:ident, $copy:ident, $axpy:ident, $scal:ident, $gemv:ident, $trmv:ident, $trsv:ident, $ger:ident, $gemm:ident,
+         $symm:ident, $trmm:ident, $trsm:ident, $syrk:ident, $syr2k:ident) => (
+            impl BlasVector<$elem> for $vector {
+                type Real = $real;
+
+                fn dot(x: &$vector, y: &$vector) -> GSLResult<$elem> {
+                    let mut result = $zero;
+
+                    GSLResult::from(level1::$dot(x, y, &mut result))?;
+                    Ok(result)
+                }
+
+                fn nrm2(x: &$vector) -> $real {
+                    level1::$nrm2(x)
+                }
+
+                fn asum(x: &$vector) -> $real {
+                    level1::$asum(x)
+                }
+
+                fn iamax(x: &$vector) -> u32 {
+                    level1::$iamax(x)
+                }
+
+                fn swap(x: &mut $vector, y: &mut $vector) -> GSLResult<()> {
+                    GSLResult::from(level1::$swap(x, y))
+                }
+
+                fn copy(x: &$vector, y: &mut $vector) -> GSLResult<()> {
+                    GSLResult::from(unsafe { ::ffi::$copy(::ffi::FFI::unwrap(x), ::ffi::FFI::unwrap(y)) })
+                }
+
+                fn axpy(alpha: $elem, x: &$vector, y: &mut $vector) -> GSLResult<()> {
+                    GSLResult::from(level1::$axpy(arg!($pass, alpha), x, y))
+                }
+
+                fn scal(alpha: $elem, x: &mut $vector) {
+                    level1::$scal(arg!($pass, alpha), x)
+                }
+            }
+
+            impl BlasMatrix<$elem> for $matrix {
+                type Vector = $vector;
+
+                fn gemv(transA: ::cblas::Transpose, alpha: $elem, A: &$matrix, x: &$vector, beta: $elem,
+                    y: &mut $vector) -> GSLResult<()> {
+                    GSLResult::from(level2::$gemv(transA, arg!($pass, alpha), A, x, arg!($pass, beta), y))
+                }
+
+                fn trmv(uplo: ::cblas::Uplo, transA: ::cblas::Transpose, diag: ::cblas::Diag, A: &$matrix,
+                    x: &mut $vector) -> GSLResult<()> {
+                    GSLResult::from(level2::$trmv(uplo, transA, diag, A, x))
+                }
+
+                fn trsv(uplo: ::cblas::Uplo, transA: ::cblas::Transpose, diag: ::cblas::Diag, A: &$matrix,
+                    x: &mut $vector) -> GSLResult<()> {
+                    GSLResult::from(level2::$trsv(uplo, transA, diag, A, x))
+                }
+
+                fn ger(alpha: $elem, x: &$vector, y: &$vector, A: &mut $matrix) -> GSLResult<()> {
+                    GSLResult::from(level2::$ger(arg!($pass, alpha), x, y, A))
+                }
+
+                fn gemm(transA: ::cblas::Transpose, transB: ::cblas::Transpose, alpha: $elem, A: &$matrix, B: &$matrix, beta: $elem,
+                    C: &mut $matrix) -> GSLResult<()> {
+                    GSLResult::from(level3::$gemm(transA, transB, arg!($pass, alpha), A, B, arg!($pass, beta), C))
+                }
+
+                fn symm(side: ::cblas::Side, uplo: ::cblas::Uplo, alpha: $elem, A: &$matrix, B: &$matrix, beta: $elem,
+                    C: &mut $matrix) -> GSLResult<()> {
+                    GSLResult::from(level3::$symm(side, uplo, arg!($pass, alpha), A, B, arg!($pass, beta), C))
+                }
+
+                fn trmm(side: ::cblas::Side, uplo: ::cblas::Uplo, transA: ::cblas::Transpose, diag: ::cblas::Diag, alpha: $elem,
+                    A: &$matrix, B: &mut $matrix) -> GSLResult<()> {
+                    GSLResult::from(level3::$trmm(side, uplo, transA, diag, arg!($pass, alpha), A, B))
+                }
+
+                fn trsm(side: ::cblas::Side, uplo: ::cblas::Uplo, transA: ::cblas::Transpose, diag: ::cblas::Diag, alpha: $elem,
+                    A: &$matrix, B: &mut $matrix) -> GSLResult<()> {
+                    GSLResult::from(level3::$trsm(side, uplo, transA, diag, arg!($pass, alpha), A, B))
+                }
+
+                fn syrk(uplo: ::cblas::Uplo, trans: ::cblas::Transpose, alpha: $elem, A: &$matrix, beta: $elem,
+                    C: &mut $matrix) -> GSLResult<()> {
+                    GSLResult::from(level3::$syrk(uplo, trans, arg!($pass, alpha), A, arg!($pass, beta), C))
+                }
+
+                fn syr2k(uplo: ::cblas::Uplo, trans: ::cblas::Transpose, alpha: $elem, A: &$matrix, B: &$matrix, beta: $elem,
+                    C: &mut $matrix) -> GSLResult<()> {
+                    GSLResult::from(level3::$syr2k(uplo, trans, arg!($pass, alpha), A, B, arg!($pass, beta), C))
+                }
+            }
+        );
+    }
+
+    impl_blas!(VectorF32, MatrixF32, f32, f32, 0f32, val, sdot, snrm2, sasum, isamax, sswap, gsl_blas_scopy, saxpy, sscal, sgemv,
+        strmv, strsv, sger, sgemm, ssymm, strmm, strsm, ssyrk, ssyr2k);
+    impl_blas!(VectorF64, MatrixF64, f64, f64, 0f64, val, ddot, dnrm2, dasum, idamax, dswap, gsl_blas_dcopy, daxpy, dscal, dgemv,
+        dtrmv, dtrsv, dger, dgemm, dsymm, dtrmm, dtrsm, dsyrk, dsyr2k);
+    impl_blas!(VectorComplexF32, MatrixComplexF32, ComplexF32, f32, ComplexF32 { data: [0f32, 0f32] }, ref, cdotu, scnrm2, scasum,
+        icamax, cswap, gsl_blas_ccopy, caxpy, cscal, cgemv, ctrmv, ctrsv, cgeru, cgemm, csymm, ctrmm, ctrsm, csyrk, csyr2k);
+    impl_blas!(VectorComplexF64, MatrixComplexF64, ComplexF64, f64, ComplexF64::rect(0f64, 0f64), ref, zdotu, dznrm2, dzasum,
+        izamax, zswap, gsl_blas_zcopy, zaxpy, zscal, zgemv, ztrmv, ztrsv, zgeru, zgemm, zsymm, ztrmm, ztrsm, zsyrk, zsyr2k);
+
+    /// This function computes the scalar product x^T y for the vectors x and y.
+    pub fn dot<T, V: BlasVector<T>>(x: &V, y: &V) -> GSLResult<T> {
+        V::dot(x, y)
+    }
+
+    /// This function computes the Euclidean norm ||x||_2 of the vector x.
+    pub fn nrm2<T, V: BlasVector<T>>(x: &V) -> V::Real {
+        V::nrm2(x)
+    }
+
+    /// This function computes the absolute sum of the elements of the vector x. For complex vectors, this is the sum of the magnitudes
+    /// of the real and imaginary parts.
+    pub fn asum<T, V: BlasVector<T>>(x: &V) -> V::Real {
+        V::asum(x)
+    }
+
+    /// This function returns the index of the largest element of the vector x, determined in the same way as for `asum`. If the
+    /// largest value occurs several times then the index of the first occurrence is returned.
+    pub fn iamax<T, V: BlasVector<T>>(x: &V) -> u32 {
+        V::iamax(x)
+    }
+
+    /// This function exchanges the elements of the vectors x and y.
+    pub fn swap<T, V: BlasVector<T>>(x: &mut V, y: &mut V) -> GSLResult<()> {
+        V::swap(x, y)
+    }
+
+    /// This function copies the elements of the vector x into the vector y.
+    pub fn copy<T, V: BlasVector<T>>(x: &V, y: &mut V) -> GSLResult<()> {
+        V::copy(x, y)
+    }
+
+    /// This function computes the sum y = \alpha x + y for the vectors x and y.
+    pub fn axpy<T, V: BlasVector<T>>(alpha: T, x: &V, y: &mut V) -> GSLResult<()> {
+        V::axpy(alpha, x, y)
+    }
+
+    /// This function rescales the vector x by the multiplicative factor alpha.
+    pub fn scal<T, V: BlasVector<T>>(alpha: T, x: &mut V) {
+        V::scal(alpha, x)
+    }
+
+    /// This function computes the matrix-vector product and sum y = \alpha op(A) x + \beta y, where op(A) = A, A^T, A^H for
+    /// TransA = CblasNoTrans, CblasTrans, CblasConjTrans.
+    pub fn gemv<T, M: BlasMatrix<T>>(transA: ::cblas::Transpose, alpha: T, A: &M, x: &M::Vector, beta: T,
+        y: &mut M::Vector) -> GSLResult<()> {
+        M::gemv(transA, alpha, A, x, beta, y)
+    }
+
+    /// This function computes the matrix-vector product x = op(A) x for the triangular matrix A, where op(A) = A, A^T, A^H for
+    /// TransA = CblasNoTrans, CblasTrans, CblasConjTrans. When Uplo is CblasUpper then the upper triangle of A is used, and when Uplo is
+    /// CblasLower then the lower triangle of A is used. If Diag is CblasNonUnit then the diagonal of the matrix is used, but if Diag is
+    /// CblasUnit then the diagonal elements of the matrix A are taken as unity and are not referenced.
+    pub fn trmv<T, M: BlasMatrix<T>>(uplo: ::cblas::Uplo, transA: ::cblas::Transpose, diag: ::cblas::Diag, A: &M,
+        x: &mut M::Vector) -> GSLResult<()> {
+        M::trmv(uplo, transA, diag, A, x)
+    }
+
+    /// This function computes inv(op(A)) x for x, where op(A) = A, A^T, A^H for TransA = CblasNoTrans, CblasTrans, CblasConjTrans.
+    /// The triangle of A and the use of its diagonal are given by Uplo and Diag as for `trmv`.
+    pub fn trsv<T, M: BlasMatrix<T>>(uplo: ::cblas::Uplo, transA: ::cblas::Transpose, diag: ::cblas::Diag, A: &M,
+        x: &mut M::Vector) -> GSLResult<()> {
+        M::trsv(uplo, transA, diag, A, x)
+    }
+
+    /// This function computes the rank-1 update A = \alpha x y^T + A of the matrix A. For complex matrices this is the unconjugated
+    /// update of `cgeru` and `zgeru`.
+    pub fn ger<T, M: BlasMatrix<T>>(alpha: T, x: &M::Vector, y: &M::Vector, A: &mut M) -> GSLResult<()> {
+        M::ger(alpha, x, y, A)
+    }
+
+    /// This function computes the matrix-matrix product and sum C = \alpha op(A) op(B) + \beta C where op(A) = A, A^T, A^H for
+    /// TransA = CblasNoTrans, CblasTrans, CblasConjTrans and similarly for the parameter TransB.
+    pub fn gemm<T, M: BlasMatrix<T>>(transA: ::cblas::Transpose, transB: ::cblas::Transpose, alpha: T, A: &M, B: &M, beta: T,
+        C: &mut M) -> GSLResult<()> {
+        M::gemm(transA, transB, alpha, A, B, beta, C)
+    }
+
+    /// This function computes the matrix-matrix product and sum C = \alpha A B + \beta C for Side is CblasLeft and
+    /// C = \alpha B A + \beta C for Side is CblasRight, where the matrix A is symmetric. When Uplo is CblasUpper then the upper
+    /// triangle and diagonal of A are used, and when Uplo is CblasLower then the lower triangle and diagonal of A are used.
+    pub fn symm<T, M: BlasMatrix<T>>(side: ::cblas::Side, uplo: ::cblas::Uplo, alpha: T, A: &M, B: &M, beta: T,
+        C: &mut M) -> GSLResult<()> {
+        M::symm(side, uplo, alpha, A, B, beta, C)
+    }
+
+    /// This function computes the matrix-matrix product B = \alpha op(A) B for Side is CblasLeft and B = \alpha B op(A) for Side is
+    /// CblasRight. The matrix A is triangular and op(A) = A, A^T, A^H for TransA = CblasNoTrans, CblasTrans, CblasConjTrans. The
+    /// triangle of A and the use of its diagonal are given by Uplo and Diag as for `trmv`.
+    pub fn trmm<T, M: BlasMatrix<T>>(side: ::cblas::Side, uplo: ::cblas::Uplo, transA: ::cblas::Transpose, diag: ::cblas::Diag,
+        alpha: T, A: &M, B: &mut M) -> GSLResult<()> {
+        M::trmm(side, uplo, transA, diag, alpha, A, B)
+    }
+
+    /// This function computes the inverse-matrix matrix product B = \alpha op(inv(A))B for Side is CblasLeft and
+    /// B = \alpha B op(inv(A)) for Side is CblasRight. The matrix A is triangular and op(A) = A, A^T, A^H for TransA = CblasNoTrans,
+    /// CblasTrans, CblasConjTrans. The triangle of A and the use of its diagonal are given by Uplo and Diag as for `trmv`.
+    pub fn trsm<T, M: BlasMatrix<T>>(side: ::cblas::Side, uplo: ::cblas::Uplo, transA: ::cblas::Transpose, diag: ::cblas::Diag,
+        alpha: T, A: &M, B: &mut M) -> GSLResult<()> {
+        M::trsm(side, uplo, transA, diag, alpha, A, B)
+    }
+
+    /// This function computes a rank-k update of the symmetric matrix C, C = \alpha A A^T + \beta C when Trans is CblasNoTrans and
+    /// C = \alpha A^T A + \beta C when Trans is CblasTrans. Since the matrix C is symmetric only its upper half or lower half need to
+    /// be stored, as given by Uplo.
+    pub fn syrk<T, M: BlasMatrix<T>>(uplo: ::cblas::Uplo, trans: ::cblas::Transpose, alpha: T, A: &M, beta: T,
+        C: &mut M) -> GSLResult<()> {
+        M::syrk(uplo, trans, alpha, A, beta, C)
+    }
+
+    /// This function computes a rank-2k update of the symmetric matrix C, C = \alpha A B^T + \alpha B A^T + \beta C when Trans is
+    /// CblasNoTrans and C = \alpha A^T B + \alpha B^T A + \beta C when Trans is CblasTrans. Since the matrix C is symmetric only its
+    /// upper half or lower half need to be stored, as given by Uplo.
+    pub fn syr2k<T, M: BlasMatrix<T>>(uplo: ::cblas::Uplo, trans: ::cblas::Transpose, alpha: T, A: &M, B: &M, beta: T,
+        C: &mut M) -> GSLResult<()> {
+        M::syr2k(uplo, trans, alpha, A, B, beta, C)
+    }
+}
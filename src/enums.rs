@@ -173,3 +173,82 @@ pub enum WaveletDirection {
     Forward = 1,
     Backward = -1,
 }
+
+#[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
+#[repr(C)]
+/// Storage format of a sparse matrix
+pub enum SpMatrixType {
+    /// triplet (coordinate) format, used to assemble the matrix
+    Triplet = 0,
+    /// compressed column storage
+    CCS = 1,
+    /// compressed row storage
+    CRS = 2,
+}
+
+#[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
+#[repr(C)]
+/// Handling of the end points of a signal for moving window statistics and filters
+pub enum MovStatEnd {
+    /// the signal is padded with zeros at both ends
+    PadZero = 0,
+    /// the signal is padded with its first value on the left and its last value on the right
+    PadValue = 1,
+    /// the window is truncated near the ends so that only existing samples are used
+    Truncate = 2,
+}
+
+#[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
+#[repr(C)]
+/// Handling of the end points of a signal for digital filters
+pub enum FilterEnd {
+    /// the signal is padded with zeros at both ends
+    PadZero = 0,
+    /// the signal is padded with its first value on the left and its last value on the right
+    PadValue = 1,
+    /// the window is truncated near the ends so that only existing samples are used
+    Truncate = 2,
+}
+
+#[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
+#[repr(C)]
+/// Robust scale estimate used by the impulse detection filter
+pub enum FilterScale {
+    /// median absolute deviation
+    MAD = 0,
+    /// interquartile range
+    IQR = 1,
+    /// S_n statistic
+    SN = 2,
+    /// Q_n statistic
+    QN = 3,
+}
+
+#[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
+/// Size of the output of a convolution or a correlation
+pub enum ConvolutionMode {
+    /// the full discrete convolution, of length n + m - 1
+    Full,
+    /// the central part of the full convolution, of the length of the longest input
+    Same,
+    /// only the terms which do not depend on zero padding, of length max(n, m) - min(n, m) + 1
+    Valid,
+}
+
+#[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
+/// Tapering window applied to the segments of a spectral estimate
+pub enum WindowType {
+    Hann,
+    Hamming,
+    Blackman,
+}
+
+#[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
+#[repr(C)]
+/// Finite difference scheme used to approximate the Jacobian of a nonlinear least-squares system
+pub enum MultifitNlinearFdType {
+    /// forward differences, using p additional function evaluations
+    Forward,
+    /// centered differences, more accurate but using 2p additional function evaluations
+    Central,
+}
@@ -4,7 +4,7 @@
 
 #![allow(improper_ctypes)]
 
-use libc::{c_double, c_int, c_uint, c_float, c_void, size_t, c_ulong, c_char, FILE};
+use libc::{c_double, c_int, c_uint, c_long, c_uchar, c_float, c_void, size_t, c_ulong, c_char, FILE};
 use enums;
 use cblas;
 
@@ -16,6 +16,7 @@ pub type CBLAS_DIAG_t = cblas::Diag;
 pub type CBLAS_SIDE_t = cblas::Side;
 pub type gsl_complex_packed_ptr = *mut c_double;
 pub type gsl_complex_packed_array = *mut c_double;
+pub type gsl_complex_packed_array_float = *mut c_float;
 pub type coord = c_int;
 
 pub trait FFI<T> {
@@ -76,6 +77,10 @@ extern "C" {
     pub static gsl_interp_cspline_periodic : *const gsl_interp_type;
     pub static gsl_interp_akima : *const gsl_interp_type;
     pub static gsl_interp_akima_periodic : *const gsl_interp_type;
+    pub static gsl_interp_steffen : *const gsl_interp_type;
+
+    pub static gsl_interp2d_bilinear : *const gsl_interp2d_type;
+    pub static gsl_interp2d_bicubic : *const gsl_interp2d_type;
 
     pub static gsl_odeiv2_step_rk2 : *const gsl_odeiv2_step_type;
     pub static gsl_odeiv2_step_rk4 : *const gsl_odeiv2_step_type;
@@ -104,6 +109,49 @@ extern "C" {
     pub static gsl_wavelet_bspline : *const gsl_wavelet_type;
     pub static gsl_wavelet_bspline_centered : *const gsl_wavelet_type;
 
+    pub static gsl_multiroot_fsolver_dnewton : *const gsl_multiroot_fsolver_type;
+    pub static gsl_multiroot_fsolver_broyden : *const gsl_multiroot_fsolver_type;
+    pub static gsl_multiroot_fsolver_hybrid : *const gsl_multiroot_fsolver_type;
+    pub static gsl_multiroot_fsolver_hybrids : *const gsl_multiroot_fsolver_type;
+
+    pub static gsl_multiroot_fdfsolver_newton : *const gsl_multiroot_fdfsolver_type;
+    pub static gsl_multiroot_fdfsolver_gnewton : *const gsl_multiroot_fdfsolver_type;
+    pub static gsl_multiroot_fdfsolver_hybridj : *const gsl_multiroot_fdfsolver_type;
+    pub static gsl_multiroot_fdfsolver_hybridsj : *const gsl_multiroot_fdfsolver_type;
+
+    pub static gsl_splinalg_itersolve_gmres : *const gsl_splinalg_itersolve_type;
+    pub static gsl_multifit_robust_default : *const gsl_multifit_robust_type;
+    pub static gsl_multifit_robust_bisquare : *const gsl_multifit_robust_type;
+    pub static gsl_multifit_robust_cauchy : *const gsl_multifit_robust_type;
+    pub static gsl_multifit_robust_fair : *const gsl_multifit_robust_type;
+    pub static gsl_multifit_robust_huber : *const gsl_multifit_robust_type;
+    pub static gsl_multifit_robust_ols : *const gsl_multifit_robust_type;
+    pub static gsl_multifit_robust_welsch : *const gsl_multifit_robust_type;
+    pub static gsl_multilarge_linear_normal : *const gsl_multilarge_linear_type;
+    pub static gsl_multilarge_linear_tsqr : *const gsl_multilarge_linear_type;
+    pub static gsl_multifit_nlinear_trust : *const gsl_multifit_nlinear_type;
+    pub static gsl_multifit_nlinear_trs_lm : *const gsl_multifit_nlinear_trs;
+    pub static gsl_multifit_nlinear_trs_lmaccel : *const gsl_multifit_nlinear_trs;
+    pub static gsl_multifit_nlinear_trs_dogleg : *const gsl_multifit_nlinear_trs;
+    pub static gsl_multifit_nlinear_trs_ddogleg : *const gsl_multifit_nlinear_trs;
+    pub static gsl_multifit_nlinear_trs_subspace2D : *const gsl_multifit_nlinear_trs;
+    pub static gsl_multifit_nlinear_scale_levenberg : *const gsl_multifit_nlinear_scale;
+    pub static gsl_multifit_nlinear_scale_marquardt : *const gsl_multifit_nlinear_scale;
+    pub static gsl_multifit_nlinear_scale_more : *const gsl_multifit_nlinear_scale;
+    pub static gsl_multifit_nlinear_solver_cholesky : *const gsl_multifit_nlinear_solver;
+    pub static gsl_multifit_nlinear_solver_qr : *const gsl_multifit_nlinear_solver;
+    pub static gsl_multifit_nlinear_solver_svd : *const gsl_multifit_nlinear_solver;
+
+    pub static gsl_multimin_fminimizer_nmsimplex : *const gsl_multimin_fminimizer_type;
+    pub static gsl_multimin_fminimizer_nmsimplex2 : *const gsl_multimin_fminimizer_type;
+    pub static gsl_multimin_fminimizer_nmsimplex2rand : *const gsl_multimin_fminimizer_type;
+
+    pub static gsl_multimin_fdfminimizer_conjugate_fr : *const gsl_multimin_fdfminimizer_type;
+    pub static gsl_multimin_fdfminimizer_conjugate_pr : *const gsl_multimin_fdfminimizer_type;
+    pub static gsl_multimin_fdfminimizer_vector_bfgs : *const gsl_multimin_fdfminimizer_type;
+    pub static gsl_multimin_fdfminimizer_vector_bfgs2 : *const gsl_multimin_fdfminimizer_type;
+    pub static gsl_multimin_fdfminimizer_steepest_descent : *const gsl_multimin_fdfminimizer_type;
+
     // Airy functions
     pub fn gsl_sf_airy_Ai(x: c_double, mode: ::Mode) -> c_double;
     pub fn gsl_sf_airy_Ai_e(x: c_double, mode: ::Mode, result: *mut gsl_sf_result) -> enums::Value;
@@ -979,6 +1027,11 @@ extern "C" {
     //pub fn gsl_vector_complex_imag(v: *mut gsl_vector_complex) -> gsl_vector_view;
     pub fn gsl_vector_view_array(base: *mut c_double, n: size_t) -> gsl_vector_view;
     pub fn gsl_vector_view_array_with_stride(base: *mut c_double, stride: size_t, n: size_t) -> gsl_vector_view;
+    pub fn gsl_vector_float_subvector(v: *mut gsl_vector_float, offset: size_t, n: size_t) -> gsl_vector_float_view;
+    pub fn gsl_vector_float_subvector_with_stride(v: *mut gsl_vector_float, offset: size_t, stride: size_t,
+        n: size_t) -> gsl_vector_float_view;
+    pub fn gsl_vector_float_view_array(base: *mut c_float, n: size_t) -> gsl_vector_float_view;
+    pub fn gsl_vector_float_view_array_with_stride(base: *mut c_float, stride: size_t, n: size_t) -> gsl_vector_float_view;
 
     // VectorComplex functions
     pub fn gsl_vector_complex_alloc(size: size_t) -> *mut gsl_vector_complex;
@@ -1101,6 +1154,11 @@ extern "C" {
 
     // Matrix views
     pub fn gsl_matrix_submatrix(m: *mut gsl_matrix, k1: size_t, k2: size_t, n1: size_t, n2: size_t) -> gsl_matrix_view;
+    pub fn gsl_matrix_row(m: *mut gsl_matrix, i: size_t) -> gsl_vector_view;
+    pub fn gsl_matrix_column(m: *mut gsl_matrix, j: size_t) -> gsl_vector_view;
+    pub fn gsl_matrix_diagonal(m: *mut gsl_matrix) -> gsl_vector_view;
+    pub fn gsl_matrix_subdiagonal(m: *mut gsl_matrix, k: size_t) -> gsl_vector_view;
+    pub fn gsl_matrix_superdiagonal(m: *mut gsl_matrix, k: size_t) -> gsl_vector_view;
     pub fn gsl_matrix_view_array(base: *mut c_double, n1: size_t, n2: size_t) -> gsl_matrix_view;
     pub fn gsl_matrix_view_array_with_tda(base: *mut c_double, n1: size_t, n2: size_t, tda: size_t) -> gsl_matrix_view;
     pub fn gsl_matrix_view_vector(v: *mut gsl_vector, n1: size_t, n2: size_t) -> gsl_matrix_view;
@@ -1144,6 +1202,299 @@ extern "C" {
     pub fn gsl_matrix_float_isnonneg(m: *const gsl_matrix_float) -> c_int;
     pub fn gsl_matrix_float_equal(u: *const gsl_matrix_float, v: *const gsl_matrix_float) -> c_int;
 
+    // VectorInt functions
+    pub fn gsl_vector_int_alloc(size: size_t) -> *mut gsl_vector_int;
+    pub fn gsl_vector_int_calloc(size: size_t) -> *mut gsl_vector_int;
+    pub fn gsl_vector_int_free(vector: *mut gsl_vector_int);
+    pub fn gsl_vector_int_get(vector: *const gsl_vector_int, i: size_t) -> c_int;
+    pub fn gsl_vector_int_set(vector: *mut gsl_vector_int, i: size_t, x: c_int);
+    pub fn gsl_vector_int_set_all(vector: *mut gsl_vector_int, x: c_int);
+    pub fn gsl_vector_int_set_zero(vector: *mut gsl_vector_int);
+    pub fn gsl_vector_int_set_basis(vector: *mut gsl_vector_int, i: size_t);
+    pub fn gsl_vector_int_memcpy(dest: *mut gsl_vector_int, src: *const gsl_vector_int) -> enums::Value;
+    pub fn gsl_vector_int_swap(v: *mut gsl_vector_int, w: *mut gsl_vector_int) -> enums::Value;
+    pub fn gsl_vector_int_swap_elements(vector: *mut gsl_vector_int, i: size_t, j: size_t) -> enums::Value;
+    pub fn gsl_vector_int_reverse(vector: *mut gsl_vector_int) -> enums::Value;
+    pub fn gsl_vector_int_add(dest: *mut gsl_vector_int, src: *const gsl_vector_int) -> enums::Value;
+    pub fn gsl_vector_int_sub(dest: *mut gsl_vector_int, src: *const gsl_vector_int) -> enums::Value;
+    pub fn gsl_vector_int_mul(dest: *mut gsl_vector_int, src: *const gsl_vector_int) -> enums::Value;
+    pub fn gsl_vector_int_div(dest: *mut gsl_vector_int, src: *const gsl_vector_int) -> enums::Value;
+    // scale and add_constant take a double for all the integer types, as declared by GSL before 2.7
+    pub fn gsl_vector_int_scale(dest: *mut gsl_vector_int, x: c_double) -> enums::Value;
+    pub fn gsl_vector_int_add_constant(dest: *mut gsl_vector_int, x: c_double) -> enums::Value;
+    pub fn gsl_vector_int_max(vector: *const gsl_vector_int) -> c_int;
+    pub fn gsl_vector_int_min(vector: *const gsl_vector_int) -> c_int;
+    pub fn gsl_vector_int_minmax(vector: *const gsl_vector_int, min_out: *mut c_int, max_out: *mut c_int);
+    pub fn gsl_vector_int_max_index(vector: *const gsl_vector_int) -> size_t;
+    pub fn gsl_vector_int_min_index(vector: *const gsl_vector_int) -> size_t;
+    pub fn gsl_vector_int_minmax_index(vector: *const gsl_vector_int, imin: *mut size_t, imax: *mut size_t);
+    pub fn gsl_vector_int_isnull(vector: *const gsl_vector_int) -> c_int;
+    pub fn gsl_vector_int_ispos(vector: *const gsl_vector_int) -> c_int;
+    pub fn gsl_vector_int_isneg(vector: *const gsl_vector_int) -> c_int;
+    pub fn gsl_vector_int_isnonneg(vector: *const gsl_vector_int) -> c_int;
+    pub fn gsl_vector_int_equal(u: *const gsl_vector_int, v: *const gsl_vector_int) -> c_int;
+    pub fn gsl_permute_vector_int(p: *const gsl_permutation, v: *mut gsl_vector_int) -> enums::Value;
+    pub fn gsl_permute_vector_int_inverse(p: *const gsl_permutation, v: *mut gsl_vector_int) -> enums::Value;
+    pub fn gsl_sort_vector_int(v: *mut gsl_vector_int);
+    pub fn gsl_sort_vector_int_index(p: *mut gsl_permutation, v: *const gsl_vector_int) -> enums::Value;
+
+    // VectorUInt functions
+    pub fn gsl_vector_uint_alloc(size: size_t) -> *mut gsl_vector_uint;
+    pub fn gsl_vector_uint_calloc(size: size_t) -> *mut gsl_vector_uint;
+    pub fn gsl_vector_uint_free(vector: *mut gsl_vector_uint);
+    pub fn gsl_vector_uint_get(vector: *const gsl_vector_uint, i: size_t) -> c_uint;
+    pub fn gsl_vector_uint_set(vector: *mut gsl_vector_uint, i: size_t, x: c_uint);
+    pub fn gsl_vector_uint_set_all(vector: *mut gsl_vector_uint, x: c_uint);
+    pub fn gsl_vector_uint_set_zero(vector: *mut gsl_vector_uint);
+    pub fn gsl_vector_uint_set_basis(vector: *mut gsl_vector_uint, i: size_t);
+    pub fn gsl_vector_uint_memcpy(dest: *mut gsl_vector_uint, src: *const gsl_vector_uint) -> enums::Value;
+    pub fn gsl_vector_uint_swap(v: *mut gsl_vector_uint, w: *mut gsl_vector_uint) -> enums::Value;
+    pub fn gsl_vector_uint_swap_elements(vector: *mut gsl_vector_uint, i: size_t, j: size_t) -> enums::Value;
+    pub fn gsl_vector_uint_reverse(vector: *mut gsl_vector_uint) -> enums::Value;
+    pub fn gsl_vector_uint_add(dest: *mut gsl_vector_uint, src: *const gsl_vector_uint) -> enums::Value;
+    pub fn gsl_vector_uint_sub(dest: *mut gsl_vector_uint, src: *const gsl_vector_uint) -> enums::Value;
+    pub fn gsl_vector_uint_mul(dest: *mut gsl_vector_uint, src: *const gsl_vector_uint) -> enums::Value;
+    pub fn gsl_vector_uint_div(dest: *mut gsl_vector_uint, src: *const gsl_vector_uint) -> enums::Value;
+    pub fn gsl_vector_uint_scale(dest: *mut gsl_vector_uint, x: c_double) -> enums::Value;
+    pub fn gsl_vector_uint_add_constant(dest: *mut gsl_vector_uint, x: c_double) -> enums::Value;
+    pub fn gsl_vector_uint_max(vector: *const gsl_vector_uint) -> c_uint;
+    pub fn gsl_vector_uint_min(vector: *const gsl_vector_uint) -> c_uint;
+    pub fn gsl_vector_uint_minmax(vector: *const gsl_vector_uint, min_out: *mut c_uint, max_out: *mut c_uint);
+    pub fn gsl_vector_uint_max_index(vector: *const gsl_vector_uint) -> size_t;
+    pub fn gsl_vector_uint_min_index(vector: *const gsl_vector_uint) -> size_t;
+    pub fn gsl_vector_uint_minmax_index(vector: *const gsl_vector_uint, imin: *mut size_t, imax: *mut size_t);
+    pub fn gsl_vector_uint_isnull(vector: *const gsl_vector_uint) -> c_int;
+    pub fn gsl_vector_uint_ispos(vector: *const gsl_vector_uint) -> c_int;
+    pub fn gsl_vector_uint_isneg(vector: *const gsl_vector_uint) -> c_int;
+    pub fn gsl_vector_uint_isnonneg(vector: *const gsl_vector_uint) -> c_int;
+    pub fn gsl_vector_uint_equal(u: *const gsl_vector_uint, v: *const gsl_vector_uint) -> c_int;
+    pub fn gsl_permute_vector_uint(p: *const gsl_permutation, v: *mut gsl_vector_uint) -> enums::Value;
+    pub fn gsl_permute_vector_uint_inverse(p: *const gsl_permutation, v: *mut gsl_vector_uint) -> enums::Value;
+    pub fn gsl_sort_vector_uint(v: *mut gsl_vector_uint);
+    pub fn gsl_sort_vector_uint_index(p: *mut gsl_permutation, v: *const gsl_vector_uint) -> enums::Value;
+
+    // VectorLong functions
+    pub fn gsl_vector_long_alloc(size: size_t) -> *mut gsl_vector_long;
+    pub fn gsl_vector_long_calloc(size: size_t) -> *mut gsl_vector_long;
+    pub fn gsl_vector_long_free(vector: *mut gsl_vector_long);
+    pub fn gsl_vector_long_get(vector: *const gsl_vector_long, i: size_t) -> c_long;
+    pub fn gsl_vector_long_set(vector: *mut gsl_vector_long, i: size_t, x: c_long);
+    pub fn gsl_vector_long_set_all(vector: *mut gsl_vector_long, x: c_long);
+    pub fn gsl_vector_long_set_zero(vector: *mut gsl_vector_long);
+    pub fn gsl_vector_long_set_basis(vector: *mut gsl_vector_long, i: size_t);
+    pub fn gsl_vector_long_memcpy(dest: *mut gsl_vector_long, src: *const gsl_vector_long) -> enums::Value;
+    pub fn gsl_vector_long_swap(v: *mut gsl_vector_long, w: *mut gsl_vector_long) -> enums::Value;
+    pub fn gsl_vector_long_swap_elements(vector: *mut gsl_vector_long, i: size_t, j: size_t) -> enums::Value;
+    pub fn gsl_vector_long_reverse(vector: *mut gsl_vector_long) -> enums::Value;
+    pub fn gsl_vector_long_add(dest: *mut gsl_vector_long, src: *const gsl_vector_long) -> enums::Value;
+    pub fn gsl_vector_long_sub(dest: *mut gsl_vector_long, src: *const gsl_vector_long) -> enums::Value;
+    pub fn gsl_vector_long_mul(dest: *mut gsl_vector_long, src: *const gsl_vector_long) -> enums::Value;
+    pub fn gsl_vector_long_div(dest: *mut gsl_vector_long, src: *const gsl_vector_long) -> enums::Value;
+    pub fn gsl_vector_long_scale(dest: *mut gsl_vector_long, x: c_double) -> enums::Value;
+    pub fn gsl_vector_long_add_constant(dest: *mut gsl_vector_long, x: c_double) -> enums::Value;
+    pub fn gsl_vector_long_max(vector: *const gsl_vector_long) -> c_long;
+    pub fn gsl_vector_long_min(vector: *const gsl_vector_long) -> c_long;
+    pub fn gsl_vector_long_minmax(vector: *const gsl_vector_long, min_out: *mut c_long, max_out: *mut c_long);
+    pub fn gsl_vector_long_max_index(vector: *const gsl_vector_long) -> size_t;
+    pub fn gsl_vector_long_min_index(vector: *const gsl_vector_long) -> size_t;
+    pub fn gsl_vector_long_minmax_index(vector: *const gsl_vector_long, imin: *mut size_t, imax: *mut size_t);
+    pub fn gsl_vector_long_isnull(vector: *const gsl_vector_long) -> c_int;
+    pub fn gsl_vector_long_ispos(vector: *const gsl_vector_long) -> c_int;
+    pub fn gsl_vector_long_isneg(vector: *const gsl_vector_long) -> c_int;
+    pub fn gsl_vector_long_isnonneg(vector: *const gsl_vector_long) -> c_int;
+    pub fn gsl_vector_long_equal(u: *const gsl_vector_long, v: *const gsl_vector_long) -> c_int;
+    pub fn gsl_permute_vector_long(p: *const gsl_permutation, v: *mut gsl_vector_long) -> enums::Value;
+    pub fn gsl_permute_vector_long_inverse(p: *const gsl_permutation, v: *mut gsl_vector_long) -> enums::Value;
+    pub fn gsl_sort_vector_long(v: *mut gsl_vector_long);
+    pub fn gsl_sort_vector_long_index(p: *mut gsl_permutation, v: *const gsl_vector_long) -> enums::Value;
+
+    // VectorUChar functions
+    pub fn gsl_vector_uchar_alloc(size: size_t) -> *mut gsl_vector_uchar;
+    pub fn gsl_vector_uchar_calloc(size: size_t) -> *mut gsl_vector_uchar;
+    pub fn gsl_vector_uchar_free(vector: *mut gsl_vector_uchar);
+    pub fn gsl_vector_uchar_get(vector: *const gsl_vector_uchar, i: size_t) -> c_uchar;
+    pub fn gsl_vector_uchar_set(vector: *mut gsl_vector_uchar, i: size_t, x: c_uchar);
+    pub fn gsl_vector_uchar_set_all(vector: *mut gsl_vector_uchar, x: c_uchar);
+    pub fn gsl_vector_uchar_set_zero(vector: *mut gsl_vector_uchar);
+    pub fn gsl_vector_uchar_set_basis(vector: *mut gsl_vector_uchar, i: size_t);
+    pub fn gsl_vector_uchar_memcpy(dest: *mut gsl_vector_uchar, src: *const gsl_vector_uchar) -> enums::Value;
+    pub fn gsl_vector_uchar_swap(v: *mut gsl_vector_uchar, w: *mut gsl_vector_uchar) -> enums::Value;
+    pub fn gsl_vector_uchar_swap_elements(vector: *mut gsl_vector_uchar, i: size_t, j: size_t) -> enums::Value;
+    pub fn gsl_vector_uchar_reverse(vector: *mut gsl_vector_uchar) -> enums::Value;
+    pub fn gsl_vector_uchar_add(dest: *mut gsl_vector_uchar, src: *const gsl_vector_uchar) -> enums::Value;
+    pub fn gsl_vector_uchar_sub(dest: *mut gsl_vector_uchar, src: *const gsl_vector_uchar) -> enums::Value;
+    pub fn gsl_vector_uchar_mul(dest: *mut gsl_vector_uchar, src: *const gsl_vector_uchar) -> enums::Value;
+    pub fn gsl_vector_uchar_div(dest: *mut gsl_vector_uchar, src: *const gsl_vector_uchar) -> enums::Value;
+    pub fn gsl_vector_uchar_scale(dest: *mut gsl_vector_uchar, x: c_double) -> enums::Value;
+    pub fn gsl_vector_uchar_add_constant(dest: *mut gsl_vector_uchar, x: c_double) -> enums::Value;
+    pub fn gsl_vector_uchar_max(vector: *const gsl_vector_uchar) -> c_uchar;
+    pub fn gsl_vector_uchar_min(vector: *const gsl_vector_uchar) -> c_uchar;
+    pub fn gsl_vector_uchar_minmax(vector: *const gsl_vector_uchar, min_out: *mut c_uchar, max_out: *mut c_uchar);
+    pub fn gsl_vector_uchar_max_index(vector: *const gsl_vector_uchar) -> size_t;
+    pub fn gsl_vector_uchar_min_index(vector: *const gsl_vector_uchar) -> size_t;
+    pub fn gsl_vector_uchar_minmax_index(vector: *const gsl_vector_uchar, imin: *mut size_t, imax: *mut size_t);
+    pub fn gsl_vector_uchar_isnull(vector: *const gsl_vector_uchar) -> c_int;
+    pub fn gsl_vector_uchar_ispos(vector: *const gsl_vector_uchar) -> c_int;
+    pub fn gsl_vector_uchar_isneg(vector: *const gsl_vector_uchar) -> c_int;
+    pub fn gsl_vector_uchar_isnonneg(vector: *const gsl_vector_uchar) -> c_int;
+    pub fn gsl_vector_uchar_equal(u: *const gsl_vector_uchar, v: *const gsl_vector_uchar) -> c_int;
+    pub fn gsl_permute_vector_uchar(p: *const gsl_permutation, v: *mut gsl_vector_uchar) -> enums::Value;
+    pub fn gsl_permute_vector_uchar_inverse(p: *const gsl_permutation, v: *mut gsl_vector_uchar) -> enums::Value;
+    pub fn gsl_sort_vector_uchar(v: *mut gsl_vector_uchar);
+    pub fn gsl_sort_vector_uchar_index(p: *mut gsl_permutation, v: *const gsl_vector_uchar) -> enums::Value;
+
+    // MatrixInt functions
+    pub fn gsl_matrix_int_alloc(size1: size_t, size2: size_t) -> *mut gsl_matrix_int;
+    pub fn gsl_matrix_int_calloc(size1: size_t, size2: size_t) -> *mut gsl_matrix_int;
+    pub fn gsl_matrix_int_free(m: *mut gsl_matrix_int);
+    pub fn gsl_matrix_int_get(m: *const gsl_matrix_int, i: size_t, j: size_t) -> c_int;
+    pub fn gsl_matrix_int_set(m: *mut gsl_matrix_int, i: size_t, j: size_t, x: c_int);
+    pub fn gsl_matrix_int_set_all(m: *mut gsl_matrix_int, x: c_int);
+    pub fn gsl_matrix_int_set_zero(m: *mut gsl_matrix_int);
+    pub fn gsl_matrix_int_set_identity(m: *mut gsl_matrix_int);
+    pub fn gsl_matrix_int_memcpy(dest: *mut gsl_matrix_int, src: *const gsl_matrix_int) -> enums::Value;
+    pub fn gsl_matrix_int_swap(m: *mut gsl_matrix_int, w: *mut gsl_matrix_int) -> enums::Value;
+    pub fn gsl_matrix_int_get_row(vector: *mut gsl_vector_int, m: *const gsl_matrix_int, i: size_t) -> enums::Value;
+    pub fn gsl_matrix_int_get_col(vector: *mut gsl_vector_int, m: *const gsl_matrix_int, j: size_t) -> enums::Value;
+    pub fn gsl_matrix_int_set_row(m: *mut gsl_matrix_int, i: size_t, v: *const gsl_vector_int) -> enums::Value;
+    pub fn gsl_matrix_int_set_col(m: *mut gsl_matrix_int, j: size_t, v: *const gsl_vector_int) -> enums::Value;
+    pub fn gsl_matrix_int_swap_rows(m: *mut gsl_matrix_int, i: size_t, j: size_t) -> enums::Value;
+    pub fn gsl_matrix_int_swap_columns(m: *mut gsl_matrix_int, i: size_t, j: size_t) -> enums::Value;
+    pub fn gsl_matrix_int_swap_rowcol(m: *mut gsl_matrix_int, i: size_t, j: size_t) -> enums::Value;
+    pub fn gsl_matrix_int_transpose_memcpy(dest: *mut gsl_matrix_int, src: *const gsl_matrix_int) -> enums::Value;
+    pub fn gsl_matrix_int_transpose(m: *mut gsl_matrix_int) -> enums::Value;
+    pub fn gsl_matrix_int_add(dest: *mut gsl_matrix_int, src: *const gsl_matrix_int) -> enums::Value;
+    pub fn gsl_matrix_int_sub(dest: *mut gsl_matrix_int, src: *const gsl_matrix_int) -> enums::Value;
+    pub fn gsl_matrix_int_mul_elements(dest: *mut gsl_matrix_int, src: *const gsl_matrix_int) -> enums::Value;
+    pub fn gsl_matrix_int_div_elements(dest: *mut gsl_matrix_int, src: *const gsl_matrix_int) -> enums::Value;
+    // scale and add_constant take a double for all the integer types, as declared by GSL before 2.7
+    pub fn gsl_matrix_int_scale(dest: *mut gsl_matrix_int, x: c_double) -> enums::Value;
+    pub fn gsl_matrix_int_add_constant(dest: *mut gsl_matrix_int, x: c_double) -> enums::Value;
+    pub fn gsl_matrix_int_max(m: *const gsl_matrix_int) -> c_int;
+    pub fn gsl_matrix_int_min(m: *const gsl_matrix_int) -> c_int;
+    pub fn gsl_matrix_int_minmax(m: *const gsl_matrix_int, min_out: *mut c_int, max_out: *mut c_int);
+    pub fn gsl_matrix_int_max_index(m: *const gsl_matrix_int, imax: *mut size_t, jmax: *mut size_t);
+    pub fn gsl_matrix_int_min_index(m: *const gsl_matrix_int, imin: *mut size_t, jmin: *mut size_t);
+    pub fn gsl_matrix_int_minmax_index(m: *const gsl_matrix_int, imin: *mut size_t, jmin: *mut size_t, imax: *mut size_t, jmax: *mut size_t);
+    pub fn gsl_matrix_int_isnull(m: *const gsl_matrix_int) -> c_int;
+    pub fn gsl_matrix_int_ispos(m: *const gsl_matrix_int) -> c_int;
+    pub fn gsl_matrix_int_isneg(m: *const gsl_matrix_int) -> c_int;
+    pub fn gsl_matrix_int_isnonneg(m: *const gsl_matrix_int) -> c_int;
+    pub fn gsl_matrix_int_equal(u: *const gsl_matrix_int, v: *const gsl_matrix_int) -> c_int;
+
+    // MatrixUInt functions
+    pub fn gsl_matrix_uint_alloc(size1: size_t, size2: size_t) -> *mut gsl_matrix_uint;
+    pub fn gsl_matrix_uint_calloc(size1: size_t, size2: size_t) -> *mut gsl_matrix_uint;
+    pub fn gsl_matrix_uint_free(m: *mut gsl_matrix_uint);
+    pub fn gsl_matrix_uint_get(m: *const gsl_matrix_uint, i: size_t, j: size_t) -> c_uint;
+    pub fn gsl_matrix_uint_set(m: *mut gsl_matrix_uint, i: size_t, j: size_t, x: c_uint);
+    pub fn gsl_matrix_uint_set_all(m: *mut gsl_matrix_uint, x: c_uint);
+    pub fn gsl_matrix_uint_set_zero(m: *mut gsl_matrix_uint);
+    pub fn gsl_matrix_uint_set_identity(m: *mut gsl_matrix_uint);
+    pub fn gsl_matrix_uint_memcpy(dest: *mut gsl_matrix_uint, src: *const gsl_matrix_uint) -> enums::Value;
+    pub fn gsl_matrix_uint_swap(m: *mut gsl_matrix_uint, w: *mut gsl_matrix_uint) -> enums::Value;
+    pub fn gsl_matrix_uint_get_row(vector: *mut gsl_vector_uint, m: *const gsl_matrix_uint, i: size_t) -> enums::Value;
+    pub fn gsl_matrix_uint_get_col(vector: *mut gsl_vector_uint, m: *const gsl_matrix_uint, j: size_t) -> enums::Value;
+    pub fn gsl_matrix_uint_set_row(m: *mut gsl_matrix_uint, i: size_t, v: *const gsl_vector_uint) -> enums::Value;
+    pub fn gsl_matrix_uint_set_col(m: *mut gsl_matrix_uint, j: size_t, v: *const gsl_vector_uint) -> enums::Value;
+    pub fn gsl_matrix_uint_swap_rows(m: *mut gsl_matrix_uint, i: size_t, j: size_t) -> enums::Value;
+    pub fn gsl_matrix_uint_swap_columns(m: *mut gsl_matrix_uint, i: size_t, j: size_t) -> enums::Value;
+    pub fn gsl_matrix_uint_swap_rowcol(m: *mut gsl_matrix_uint, i: size_t, j: size_t) -> enums::Value;
+    pub fn gsl_matrix_uint_transpose_memcpy(dest: *mut gsl_matrix_uint, src: *const gsl_matrix_uint) -> enums::Value;
+    pub fn gsl_matrix_uint_transpose(m: *mut gsl_matrix_uint) -> enums::Value;
+    pub fn gsl_matrix_uint_add(dest: *mut gsl_matrix_uint, src: *const gsl_matrix_uint) -> enums::Value;
+    pub fn gsl_matrix_uint_sub(dest: *mut gsl_matrix_uint, src: *const gsl_matrix_uint) -> enums::Value;
+    pub fn gsl_matrix_uint_mul_elements(dest: *mut gsl_matrix_uint, src: *const gsl_matrix_uint) -> enums::Value;
+    pub fn gsl_matrix_uint_div_elements(dest: *mut gsl_matrix_uint, src: *const gsl_matrix_uint) -> enums::Value;
+    pub fn gsl_matrix_uint_scale(dest: *mut gsl_matrix_uint, x: c_double) -> enums::Value;
+    pub fn gsl_matrix_uint_add_constant(dest: *mut gsl_matrix_uint, x: c_double) -> enums::Value;
+    pub fn gsl_matrix_uint_max(m: *const gsl_matrix_uint) -> c_uint;
+    pub fn gsl_matrix_uint_min(m: *const gsl_matrix_uint) -> c_uint;
+    pub fn gsl_matrix_uint_minmax(m: *const gsl_matrix_uint, min_out: *mut c_uint, max_out: *mut c_uint);
+    pub fn gsl_matrix_uint_max_index(m: *const gsl_matrix_uint, imax: *mut size_t, jmax: *mut size_t);
+    pub fn gsl_matrix_uint_min_index(m: *const gsl_matrix_uint, imin: *mut size_t, jmin: *mut size_t);
+    pub fn gsl_matrix_uint_minmax_index(m: *const gsl_matrix_uint, imin: *mut size_t, jmin: *mut size_t, imax: *mut size_t, jmax: *mut size_t);
+    pub fn gsl_matrix_uint_isnull(m: *const gsl_matrix_uint) -> c_int;
+    pub fn gsl_matrix_uint_ispos(m: *const gsl_matrix_uint) -> c_int;
+    pub fn gsl_matrix_uint_isneg(m: *const gsl_matrix_uint) -> c_int;
+    pub fn gsl_matrix_uint_isnonneg(m: *const gsl_matrix_uint) -> c_int;
+    pub fn gsl_matrix_uint_equal(u: *const gsl_matrix_uint, v: *const gsl_matrix_uint) -> c_int;
+
+    // MatrixLong functions
+    pub fn gsl_matrix_long_alloc(size1: size_t, size2: size_t) -> *mut gsl_matrix_long;
+    pub fn gsl_matrix_long_calloc(size1: size_t, size2: size_t) -> *mut gsl_matrix_long;
+    pub fn gsl_matrix_long_free(m: *mut gsl_matrix_long);
+    pub fn gsl_matrix_long_get(m: *const gsl_matrix_long, i: size_t, j: size_t) -> c_long;
+    pub fn gsl_matrix_long_set(m: *mut gsl_matrix_long, i: size_t, j: size_t, x: c_long);
+    pub fn gsl_matrix_long_set_all(m: *mut gsl_matrix_long, x: c_long);
+    pub fn gsl_matrix_long_set_zero(m: *mut gsl_matrix_long);
+    pub fn gsl_matrix_long_set_identity(m: *mut gsl_matrix_long);
+    pub fn gsl_matrix_long_memcpy(dest: *mut gsl_matrix_long, src: *const gsl_matrix_long) -> enums::Value;
+    pub fn gsl_matrix_long_swap(m: *mut gsl_matrix_long, w: *mut gsl_matrix_long) -> enums::Value;
+    pub fn gsl_matrix_long_get_row(vector: *mut gsl_vector_long, m: *const gsl_matrix_long, i: size_t) -> enums::Value;
+    pub fn gsl_matrix_long_get_col(vector: *mut gsl_vector_long, m: *const gsl_matrix_long, j: size_t) -> enums::Value;
+    pub fn gsl_matrix_long_set_row(m: *mut gsl_matrix_long, i: size_t, v: *const gsl_vector_long) -> enums::Value;
+    pub fn gsl_matrix_long_set_col(m: *mut gsl_matrix_long, j: size_t, v: *const gsl_vector_long) -> enums::Value;
+    pub fn gsl_matrix_long_swap_rows(m: *mut gsl_matrix_long, i: size_t, j: size_t) -> enums::Value;
+    pub fn gsl_matrix_long_swap_columns(m: *mut gsl_matrix_long, i: size_t, j: size_t) -> enums::Value;
+    pub fn gsl_matrix_long_swap_rowcol(m: *mut gsl_matrix_long, i: size_t, j: size_t) -> enums::Value;
+    pub fn gsl_matrix_long_transpose_memcpy(dest: *mut gsl_matrix_long, src: *const gsl_matrix_long) -> enums::Value;
+    pub fn gsl_matrix_long_transpose(m: *mut gsl_matrix_long) -> enums::Value;
+    pub fn gsl_matrix_long_add(dest: *mut gsl_matrix_long, src: *const gsl_matrix_long) -> enums::Value;
+    pub fn gsl_matrix_long_sub(dest: *mut gsl_matrix_long, src: *const gsl_matrix_long) -> enums::Value;
+    pub fn gsl_matrix_long_mul_elements(dest: *mut gsl_matrix_long, src: *const gsl_matrix_long) -> enums::Value;
+    pub fn gsl_matrix_long_div_elements(dest: *mut gsl_matrix_long, src: *const gsl_matrix_long) -> enums::Value;
+    pub fn gsl_matrix_long_scale(dest: *mut gsl_matrix_long, x: c_double) -> enums::Value;
+    pub fn gsl_matrix_long_add_constant(dest: *mut gsl_matrix_long, x: c_double) -> enums::Value;
+    pub fn gsl_matrix_long_max(m: *const gsl_matrix_long) -> c_long;
+    pub fn gsl_matrix_long_min(m: *const gsl_matrix_long) -> c_long;
+    pub fn gsl_matrix_long_minmax(m: *const gsl_matrix_long, min_out: *mut c_long, max_out: *mut c_long);
+    pub fn gsl_matrix_long_max_index(m: *const gsl_matrix_long, imax: *mut size_t, jmax: *mut size_t);
+    pub fn gsl_matrix_long_min_index(m: *const gsl_matrix_long, imin: *mut size_t, jmin: *mut size_t);
+    pub fn gsl_matrix_long_minmax_index(m: *const gsl_matrix_long, imin: *mut size_t, jmin: *mut size_t, imax: *mut size_t, jmax: *mut size_t);
+    pub fn gsl_matrix_long_isnull(m: *const gsl_matrix_long) -> c_int;
+    pub fn gsl_matrix_long_ispos(m: *const gsl_matrix_long) -> c_int;
+    pub fn gsl_matrix_long_isneg(m: *const gsl_matrix_long) -> c_int;
+    pub fn gsl_matrix_long_isnonneg(m: *const gsl_matrix_long) -> c_int;
+    pub fn gsl_matrix_long_equal(u: *const gsl_matrix_long, v: *const gsl_matrix_long) -> c_int;
+
+    // MatrixUChar functions
+    pub fn gsl_matrix_uchar_alloc(size1: size_t, size2: size_t) -> *mut gsl_matrix_uchar;
+    pub fn gsl_matrix_uchar_calloc(size1: size_t, size2: size_t) -> *mut gsl_matrix_uchar;
+    pub fn gsl_matrix_uchar_free(m: *mut gsl_matrix_uchar);
+    pub fn gsl_matrix_uchar_get(m: *const gsl_matrix_uchar, i: size_t, j: size_t) -> c_uchar;
+    pub fn gsl_matrix_uchar_set(m: *mut gsl_matrix_uchar, i: size_t, j: size_t, x: c_uchar);
+    pub fn gsl_matrix_uchar_set_all(m: *mut gsl_matrix_uchar, x: c_uchar);
+    pub fn gsl_matrix_uchar_set_zero(m: *mut gsl_matrix_uchar);
+    pub fn gsl_matrix_uchar_set_identity(m: *mut gsl_matrix_uchar);
+    pub fn gsl_matrix_uchar_memcpy(dest: *mut gsl_matrix_uchar, src: *const gsl_matrix_uchar) -> enums::Value;
+    pub fn gsl_matrix_uchar_swap(m: *mut gsl_matrix_uchar, w: *mut gsl_matrix_uchar) -> enums::Value;
+    pub fn gsl_matrix_uchar_get_row(vector: *mut gsl_vector_uchar, m: *const gsl_matrix_uchar, i: size_t) -> enums::Value;
+    pub fn gsl_matrix_uchar_get_col(vector: *mut gsl_vector_uchar, m: *const gsl_matrix_uchar, j: size_t) -> enums::Value;
+    pub fn gsl_matrix_uchar_set_row(m: *mut gsl_matrix_uchar, i: size_t, v: *const gsl_vector_uchar) -> enums::Value;
+    pub fn gsl_matrix_uchar_set_col(m: *mut gsl_matrix_uchar, j: size_t, v: *const gsl_vector_uchar) -> enums::Value;
+    pub fn gsl_matrix_uchar_swap_rows(m: *mut gsl_matrix_uchar, i: size_t, j: size_t) -> enums::Value;
+    pub fn gsl_matrix_uchar_swap_columns(m: *mut gsl_matrix_uchar, i: size_t, j: size_t) -> enums::Value;
+    pub fn gsl_matrix_uchar_swap_rowcol(m: *mut gsl_matrix_uchar, i: size_t, j: size_t) -> enums::Value;
+    pub fn gsl_matrix_uchar_transpose_memcpy(dest: *mut gsl_matrix_uchar, src: *const gsl_matrix_uchar) -> enums::Value;
+    pub fn gsl_matrix_uchar_transpose(m: *mut gsl_matrix_uchar) -> enums::Value;
+    pub fn gsl_matrix_uchar_add(dest: *mut gsl_matrix_uchar, src: *const gsl_matrix_uchar) -> enums::Value;
+    pub fn gsl_matrix_uchar_sub(dest: *mut gsl_matrix_uchar, src: *const gsl_matrix_uchar) -> enums::Value;
+    pub fn gsl_matrix_uchar_mul_elements(dest: *mut gsl_matrix_uchar, src: *const gsl_matrix_uchar) -> enums::Value;
+    pub fn gsl_matrix_uchar_div_elements(dest: *mut gsl_matrix_uchar, src: *const gsl_matrix_uchar) -> enums::Value;
+    pub fn gsl_matrix_uchar_scale(dest: *mut gsl_matrix_uchar, x: c_double) -> enums::Value;
+    pub fn gsl_matrix_uchar_add_constant(dest: *mut gsl_matrix_uchar, x: c_double) -> enums::Value;
+    pub fn gsl_matrix_uchar_max(m: *const gsl_matrix_uchar) -> c_uchar;
+    pub fn gsl_matrix_uchar_min(m: *const gsl_matrix_uchar) -> c_uchar;
+    pub fn gsl_matrix_uchar_minmax(m: *const gsl_matrix_uchar, min_out: *mut c_uchar, max_out: *mut c_uchar);
+    pub fn gsl_matrix_uchar_max_index(m: *const gsl_matrix_uchar, imax: *mut size_t, jmax: *mut size_t);
+    pub fn gsl_matrix_uchar_min_index(m: *const gsl_matrix_uchar, imin: *mut size_t, jmin: *mut size_t);
+    pub fn gsl_matrix_uchar_minmax_index(m: *const gsl_matrix_uchar, imin: *mut size_t, jmin: *mut size_t, imax: *mut size_t, jmax: *mut size_t);
+    pub fn gsl_matrix_uchar_isnull(m: *const gsl_matrix_uchar) -> c_int;
+    pub fn gsl_matrix_uchar_ispos(m: *const gsl_matrix_uchar) -> c_int;
+    pub fn gsl_matrix_uchar_isneg(m: *const gsl_matrix_uchar) -> c_int;
+    pub fn gsl_matrix_uchar_isnonneg(m: *const gsl_matrix_uchar) -> c_int;
+    pub fn gsl_matrix_uchar_equal(u: *const gsl_matrix_uchar, v: *const gsl_matrix_uchar) -> c_int;
     // MatrixComplex functions
     pub fn gsl_matrix_complex_alloc(size1: size_t, size2: size_t) -> *mut gsl_matrix_complex;
     pub fn gsl_matrix_complex_calloc(size1: size_t, size2: size_t) -> *mut gsl_matrix_complex;
@@ -2003,6 +2354,72 @@ extern "C" {
     pub fn gsl_fft_halfcomplex_radix2_backward(data: *mut c_double, stride: size_t, n: size_t) -> enums::Value;
     pub fn gsl_fft_halfcomplex_radix2_unpack(halfcomplex_coefficient: *mut c_double, complex_coefficient: gsl_complex_packed_array,
         stride: size_t, n: size_t) -> enums::Value;
+    // Mixed-radix FFT routines for real data
+    pub fn gsl_fft_real_wavetable_alloc(n: size_t) -> *mut gsl_fft_real_wavetable;
+    pub fn gsl_fft_real_wavetable_free(wavetable: *mut gsl_fft_real_wavetable);
+    pub fn gsl_fft_halfcomplex_wavetable_alloc(n: size_t) -> *mut gsl_fft_halfcomplex_wavetable;
+    pub fn gsl_fft_halfcomplex_wavetable_free(wavetable: *mut gsl_fft_halfcomplex_wavetable);
+    pub fn gsl_fft_real_workspace_alloc(n: size_t) -> *mut gsl_fft_real_workspace;
+    pub fn gsl_fft_real_workspace_free(workspace: *mut gsl_fft_real_workspace);
+    pub fn gsl_fft_real_transform(data: *mut c_double, stride: size_t, n: size_t, wavetable: *const gsl_fft_real_wavetable,
+        work: *mut gsl_fft_real_workspace) -> enums::Value;
+    pub fn gsl_fft_halfcomplex_transform(data: *mut c_double, stride: size_t, n: size_t, wavetable: *const gsl_fft_halfcomplex_wavetable,
+        work: *mut gsl_fft_real_workspace) -> enums::Value;
+    pub fn gsl_fft_halfcomplex_backward(data: *mut c_double, stride: size_t, n: size_t, wavetable: *const gsl_fft_halfcomplex_wavetable,
+        work: *mut gsl_fft_real_workspace) -> enums::Value;
+    pub fn gsl_fft_halfcomplex_inverse(data: *mut c_double, stride: size_t, n: size_t, wavetable: *const gsl_fft_halfcomplex_wavetable,
+        work: *mut gsl_fft_real_workspace) -> enums::Value;
+    pub fn gsl_fft_real_unpack(real_coefficient: *const c_double, complex_coefficient: gsl_complex_packed_array, stride: size_t,
+        n: size_t) -> enums::Value;
+    pub fn gsl_fft_halfcomplex_unpack(halfcomplex_coefficient: *const c_double, complex_coefficient: gsl_complex_packed_array,
+        stride: size_t, n: size_t) -> enums::Value;
+    // Single precision FFT routines for complex data
+    pub fn gsl_fft_complex_float_radix2_forward(data: gsl_complex_packed_array_float, stride: size_t, n: size_t) -> enums::Value;
+    pub fn gsl_fft_complex_float_radix2_transform(data: gsl_complex_packed_array_float, stride: size_t, n: size_t,
+        sign: ::FftDirection) -> enums::Value;
+    pub fn gsl_fft_complex_float_radix2_backward(data: gsl_complex_packed_array_float, stride: size_t, n: size_t) -> enums::Value;
+    pub fn gsl_fft_complex_float_radix2_inverse(data: gsl_complex_packed_array_float, stride: size_t, n: size_t) -> enums::Value;
+    pub fn gsl_fft_complex_float_radix2_dif_forward(data: gsl_complex_packed_array_float, stride: size_t, n: size_t) -> enums::Value;
+    pub fn gsl_fft_complex_float_radix2_dif_transform(data: gsl_complex_packed_array_float, stride: size_t, n: size_t,
+        sign: ::FftDirection) -> enums::Value;
+    pub fn gsl_fft_complex_float_radix2_dif_backward(data: gsl_complex_packed_array_float, stride: size_t, n: size_t) -> enums::Value;
+    pub fn gsl_fft_complex_float_radix2_dif_inverse(data: gsl_complex_packed_array_float, stride: size_t, n: size_t) -> enums::Value;
+    pub fn gsl_fft_complex_wavetable_float_alloc(n: size_t) -> *mut gsl_fft_complex_wavetable_float;
+    pub fn gsl_fft_complex_wavetable_float_free(w: *mut gsl_fft_complex_wavetable_float);
+    pub fn gsl_fft_complex_workspace_float_alloc(n: size_t) -> *mut gsl_fft_complex_workspace_float;
+    pub fn gsl_fft_complex_workspace_float_free(w: *mut gsl_fft_complex_workspace_float);
+    pub fn gsl_fft_complex_float_forward(data: gsl_complex_packed_array_float, stride: size_t, n: size_t,
+        wavetable: *const gsl_fft_complex_wavetable_float, work: *mut gsl_fft_complex_workspace_float) -> enums::Value;
+    pub fn gsl_fft_complex_float_transform(data: gsl_complex_packed_array_float, stride: size_t, n: size_t,
+        wavetable: *const gsl_fft_complex_wavetable_float, work: *mut gsl_fft_complex_workspace_float, sign: ::FftDirection) -> enums::Value;
+    pub fn gsl_fft_complex_float_backward(data: gsl_complex_packed_array_float, stride: size_t, n: size_t,
+        wavetable: *const gsl_fft_complex_wavetable_float, work: *mut gsl_fft_complex_workspace_float) -> enums::Value;
+    pub fn gsl_fft_complex_float_inverse(data: gsl_complex_packed_array_float, stride: size_t, n: size_t,
+        wavetable: *const gsl_fft_complex_wavetable_float, work: *mut gsl_fft_complex_workspace_float) -> enums::Value;
+    // Single precision FFT routines for real data
+    pub fn gsl_fft_real_float_radix2_transform(data: *mut c_float, stride: size_t, n: size_t) -> enums::Value;
+    pub fn gsl_fft_halfcomplex_float_radix2_inverse(data: *mut c_float, stride: size_t, n: size_t) -> enums::Value;
+    pub fn gsl_fft_halfcomplex_float_radix2_backward(data: *mut c_float, stride: size_t, n: size_t) -> enums::Value;
+    pub fn gsl_fft_halfcomplex_float_radix2_unpack(halfcomplex_coefficient: *const c_float,
+        complex_coefficient: gsl_complex_packed_array_float, stride: size_t, n: size_t) -> enums::Value;
+    pub fn gsl_fft_real_wavetable_float_alloc(n: size_t) -> *mut gsl_fft_real_wavetable_float;
+    pub fn gsl_fft_real_wavetable_float_free(wavetable: *mut gsl_fft_real_wavetable_float);
+    pub fn gsl_fft_halfcomplex_wavetable_float_alloc(n: size_t) -> *mut gsl_fft_halfcomplex_wavetable_float;
+    pub fn gsl_fft_halfcomplex_wavetable_float_free(wavetable: *mut gsl_fft_halfcomplex_wavetable_float);
+    pub fn gsl_fft_real_workspace_float_alloc(n: size_t) -> *mut gsl_fft_real_workspace_float;
+    pub fn gsl_fft_real_workspace_float_free(workspace: *mut gsl_fft_real_workspace_float);
+    pub fn gsl_fft_real_float_transform(data: *mut c_float, stride: size_t, n: size_t, wavetable: *const gsl_fft_real_wavetable_float,
+        work: *mut gsl_fft_real_workspace_float) -> enums::Value;
+    pub fn gsl_fft_halfcomplex_float_transform(data: *mut c_float, stride: size_t, n: size_t,
+        wavetable: *const gsl_fft_halfcomplex_wavetable_float, work: *mut gsl_fft_real_workspace_float) -> enums::Value;
+    pub fn gsl_fft_halfcomplex_float_backward(data: *mut c_float, stride: size_t, n: size_t,
+        wavetable: *const gsl_fft_halfcomplex_wavetable_float, work: *mut gsl_fft_real_workspace_float) -> enums::Value;
+    pub fn gsl_fft_halfcomplex_float_inverse(data: *mut c_float, stride: size_t, n: size_t,
+        wavetable: *const gsl_fft_halfcomplex_wavetable_float, work: *mut gsl_fft_real_workspace_float) -> enums::Value;
+    pub fn gsl_fft_real_float_unpack(real_coefficient: *const c_float, complex_coefficient: gsl_complex_packed_array_float, stride: size_t,
+        n: size_t) -> enums::Value;
+    pub fn gsl_fft_halfcomplex_float_unpack(halfcomplex_coefficient: *const c_float, complex_coefficient: gsl_complex_packed_array_float,
+        stride: size_t, n: size_t) -> enums::Value;
 
     // Histograms
     // Histogram allocation
@@ -2142,6 +2559,80 @@ extern "C" {
     pub fn gsl_spline_eval_deriv2_e(spline: *const gsl_spline, x: c_double, acc: *mut ::InterpAccel, d2: *mut c_double) -> enums::Value;
     pub fn gsl_spline_eval_integ(spline: *const gsl_spline, a: c_double, b: c_double, acc: *mut ::InterpAccel) -> c_double;
     pub fn gsl_spline_eval_integ_e(spline: *const gsl_spline, a: c_double, b: c_double, acc: *mut ::InterpAccel, result: *mut c_double) -> enums::Value;
+    // 2D Interpolation
+    pub fn gsl_interp2d_alloc(t: *const gsl_interp2d_type, xsize: size_t, ysize: size_t) -> *mut gsl_interp2d;
+    pub fn gsl_interp2d_init(interp: *mut gsl_interp2d, xa: *const c_double, ya: *const c_double, za: *const c_double, xsize: size_t,
+        ysize: size_t) -> enums::Value;
+    pub fn gsl_interp2d_free(interp: *mut gsl_interp2d);
+    pub fn gsl_interp2d_min_size(interp: *const gsl_interp2d) -> c_uint;
+    pub fn gsl_interp2d_type_min_size(t: *const gsl_interp2d_type) -> c_uint;
+    pub fn gsl_interp2d_name(interp: *const gsl_interp2d) -> *const c_char;
+    pub fn gsl_interp2d_set(interp: *const gsl_interp2d, za: *mut c_double, i: size_t, j: size_t, z: c_double) -> enums::Value;
+    pub fn gsl_interp2d_get(interp: *const gsl_interp2d, za: *const c_double, i: size_t, j: size_t) -> c_double;
+    pub fn gsl_interp2d_idx(interp: *const gsl_interp2d, i: size_t, j: size_t) -> size_t;
+    pub fn gsl_interp2d_eval(interp: *const gsl_interp2d, xarr: *const c_double, yarr: *const c_double, zarr: *const c_double, x: c_double,
+        y: c_double, xa: *mut ::InterpAccel, ya: *mut ::InterpAccel) -> c_double;
+    pub fn gsl_interp2d_eval_extrap(interp: *const gsl_interp2d, xarr: *const c_double, yarr: *const c_double, zarr: *const c_double,
+        x: c_double, y: c_double, xa: *mut ::InterpAccel, ya: *mut ::InterpAccel) -> c_double;
+    pub fn gsl_interp2d_eval_e(interp: *const gsl_interp2d, xarr: *const c_double, yarr: *const c_double, zarr: *const c_double, x: c_double,
+        y: c_double, xa: *mut ::InterpAccel, ya: *mut ::InterpAccel, z: *mut c_double) -> enums::Value;
+    pub fn gsl_interp2d_eval_e_extrap(interp: *const gsl_interp2d, xarr: *const c_double, yarr: *const c_double, zarr: *const c_double,
+        x: c_double, y: c_double, xa: *mut ::InterpAccel, ya: *mut ::InterpAccel, z: *mut c_double) -> enums::Value;
+    pub fn gsl_interp2d_eval_deriv_x(interp: *const gsl_interp2d, xarr: *const c_double, yarr: *const c_double, zarr: *const c_double,
+        x: c_double, y: c_double, xa: *mut ::InterpAccel, ya: *mut ::InterpAccel) -> c_double;
+    pub fn gsl_interp2d_eval_deriv_x_e(interp: *const gsl_interp2d, xarr: *const c_double, yarr: *const c_double, zarr: *const c_double,
+        x: c_double, y: c_double, xa: *mut ::InterpAccel, ya: *mut ::InterpAccel, z: *mut c_double) -> enums::Value;
+    pub fn gsl_interp2d_eval_deriv_y(interp: *const gsl_interp2d, xarr: *const c_double, yarr: *const c_double, zarr: *const c_double,
+        x: c_double, y: c_double, xa: *mut ::InterpAccel, ya: *mut ::InterpAccel) -> c_double;
+    pub fn gsl_interp2d_eval_deriv_y_e(interp: *const gsl_interp2d, xarr: *const c_double, yarr: *const c_double, zarr: *const c_double,
+        x: c_double, y: c_double, xa: *mut ::InterpAccel, ya: *mut ::InterpAccel, z: *mut c_double) -> enums::Value;
+    pub fn gsl_interp2d_eval_deriv_xx(interp: *const gsl_interp2d, xarr: *const c_double, yarr: *const c_double, zarr: *const c_double,
+        x: c_double, y: c_double, xa: *mut ::InterpAccel, ya: *mut ::InterpAccel) -> c_double;
+    pub fn gsl_interp2d_eval_deriv_xx_e(interp: *const gsl_interp2d, xarr: *const c_double, yarr: *const c_double, zarr: *const c_double,
+        x: c_double, y: c_double, xa: *mut ::InterpAccel, ya: *mut ::InterpAccel, z: *mut c_double) -> enums::Value;
+    pub fn gsl_interp2d_eval_deriv_yy(interp: *const gsl_interp2d, xarr: *const c_double, yarr: *const c_double, zarr: *const c_double,
+        x: c_double, y: c_double, xa: *mut ::InterpAccel, ya: *mut ::InterpAccel) -> c_double;
+    pub fn gsl_interp2d_eval_deriv_yy_e(interp: *const gsl_interp2d, xarr: *const c_double, yarr: *const c_double, zarr: *const c_double,
+        x: c_double, y: c_double, xa: *mut ::InterpAccel, ya: *mut ::InterpAccel, z: *mut c_double) -> enums::Value;
+    pub fn gsl_interp2d_eval_deriv_xy(interp: *const gsl_interp2d, xarr: *const c_double, yarr: *const c_double, zarr: *const c_double,
+        x: c_double, y: c_double, xa: *mut ::InterpAccel, ya: *mut ::InterpAccel) -> c_double;
+    pub fn gsl_interp2d_eval_deriv_xy_e(interp: *const gsl_interp2d, xarr: *const c_double, yarr: *const c_double, zarr: *const c_double,
+        x: c_double, y: c_double, xa: *mut ::InterpAccel, ya: *mut ::InterpAccel, z: *mut c_double) -> enums::Value;
+    // 2D Higher-level Interface
+    pub fn gsl_spline2d_alloc(t: *const gsl_interp2d_type, xsize: size_t, ysize: size_t) -> *mut gsl_spline2d;
+    pub fn gsl_spline2d_init(interp: *mut gsl_spline2d, xa: *const c_double, ya: *const c_double, za: *const c_double, xsize: size_t,
+        ysize: size_t) -> enums::Value;
+    pub fn gsl_spline2d_free(interp: *mut gsl_spline2d);
+    pub fn gsl_spline2d_min_size(interp: *const gsl_spline2d) -> c_uint;
+    pub fn gsl_spline2d_name(interp: *const gsl_spline2d) -> *const c_char;
+    pub fn gsl_spline2d_get(interp: *const gsl_spline2d, za: *const c_double, i: size_t, j: size_t) -> c_double;
+    pub fn gsl_spline2d_eval(interp: *const gsl_spline2d, x: c_double, y: c_double, xa: *mut ::InterpAccel, ya: *mut ::InterpAccel) -> c_double;
+    pub fn gsl_spline2d_eval_e(interp: *const gsl_spline2d, x: c_double, y: c_double, xa: *mut ::InterpAccel, ya: *mut ::InterpAccel,
+        z: *mut c_double) -> enums::Value;
+    pub fn gsl_spline2d_eval_extrap(interp: *const gsl_spline2d, x: c_double, y: c_double, xa: *mut ::InterpAccel,
+        ya: *mut ::InterpAccel) -> c_double;
+    pub fn gsl_spline2d_eval_extrap_e(interp: *const gsl_spline2d, x: c_double, y: c_double, xa: *mut ::InterpAccel,
+        ya: *mut ::InterpAccel, z: *mut c_double) -> enums::Value;
+    pub fn gsl_spline2d_eval_deriv_x(interp: *const gsl_spline2d, x: c_double, y: c_double, xa: *mut ::InterpAccel,
+        ya: *mut ::InterpAccel) -> c_double;
+    pub fn gsl_spline2d_eval_deriv_x_e(interp: *const gsl_spline2d, x: c_double, y: c_double, xa: *mut ::InterpAccel,
+        ya: *mut ::InterpAccel, z: *mut c_double) -> enums::Value;
+    pub fn gsl_spline2d_eval_deriv_y(interp: *const gsl_spline2d, x: c_double, y: c_double, xa: *mut ::InterpAccel,
+        ya: *mut ::InterpAccel) -> c_double;
+    pub fn gsl_spline2d_eval_deriv_y_e(interp: *const gsl_spline2d, x: c_double, y: c_double, xa: *mut ::InterpAccel,
+        ya: *mut ::InterpAccel, z: *mut c_double) -> enums::Value;
+    pub fn gsl_spline2d_eval_deriv_xx(interp: *const gsl_spline2d, x: c_double, y: c_double, xa: *mut ::InterpAccel,
+        ya: *mut ::InterpAccel) -> c_double;
+    pub fn gsl_spline2d_eval_deriv_xx_e(interp: *const gsl_spline2d, x: c_double, y: c_double, xa: *mut ::InterpAccel,
+        ya: *mut ::InterpAccel, z: *mut c_double) -> enums::Value;
+    pub fn gsl_spline2d_eval_deriv_yy(interp: *const gsl_spline2d, x: c_double, y: c_double, xa: *mut ::InterpAccel,
+        ya: *mut ::InterpAccel) -> c_double;
+    pub fn gsl_spline2d_eval_deriv_yy_e(interp: *const gsl_spline2d, x: c_double, y: c_double, xa: *mut ::InterpAccel,
+        ya: *mut ::InterpAccel, z: *mut c_double) -> enums::Value;
+    pub fn gsl_spline2d_eval_deriv_xy(interp: *const gsl_spline2d, x: c_double, y: c_double, xa: *mut ::InterpAccel,
+        ya: *mut ::InterpAccel) -> c_double;
+    pub fn gsl_spline2d_eval_deriv_xy_e(interp: *const gsl_spline2d, x: c_double, y: c_double, xa: *mut ::InterpAccel,
+        ya: *mut ::InterpAccel, z: *mut c_double) -> enums::Value;
 
     // linear algebra
     // LU Decomposition
@@ -2266,6 +2757,177 @@ extern "C" {
     pub fn gsl_min_fminimizer_name(s: *const gsl_min_fminimizer) -> *const c_char;*/
     pub fn gsl_min_test_interval(x_lower: c_double, x_upper: c_double, epsabs: c_double, epsrel: c_double) -> enums::Value;
 
+    // One dimensional Root-Finding
+    // Search Stopping Parameters
+    pub fn gsl_root_test_interval(x_lower: c_double, x_upper: c_double, epsabs: c_double, epsrel: c_double) -> enums::Value;
+    pub fn gsl_root_test_delta(x1: c_double, x0: c_double, epsabs: c_double, epsrel: c_double) -> enums::Value;
+    pub fn gsl_root_test_residual(f: c_double, epsabs: c_double) -> enums::Value;
+
+    // Multidimensional Root-Finding
+    // Initializing the Solver
+    pub fn gsl_multiroot_fsolver_alloc(t: *const gsl_multiroot_fsolver_type, n: size_t) -> *mut gsl_multiroot_fsolver;
+    pub fn gsl_multiroot_fdfsolver_alloc(t: *const gsl_multiroot_fdfsolver_type, n: size_t) -> *mut gsl_multiroot_fdfsolver;
+    pub fn gsl_multiroot_fsolver_set(s: *mut gsl_multiroot_fsolver, f: *mut gsl_multiroot_function, x: *const gsl_vector) -> enums::Value;
+    pub fn gsl_multiroot_fdfsolver_set(s: *mut gsl_multiroot_fdfsolver, fdf: *mut gsl_multiroot_function_fdf,
+        x: *const gsl_vector) -> enums::Value;
+    pub fn gsl_multiroot_fsolver_free(s: *mut gsl_multiroot_fsolver);
+    pub fn gsl_multiroot_fdfsolver_free(s: *mut gsl_multiroot_fdfsolver);
+    pub fn gsl_multiroot_fsolver_name(s: *const gsl_multiroot_fsolver) -> *const c_char;
+    pub fn gsl_multiroot_fdfsolver_name(s: *const gsl_multiroot_fdfsolver) -> *const c_char;
+    // Iteration
+    pub fn gsl_multiroot_fsolver_iterate(s: *mut gsl_multiroot_fsolver) -> enums::Value;
+    pub fn gsl_multiroot_fdfsolver_iterate(s: *mut gsl_multiroot_fdfsolver) -> enums::Value;
+    pub fn gsl_multiroot_fsolver_root(s: *const gsl_multiroot_fsolver) -> *mut gsl_vector;
+    pub fn gsl_multiroot_fdfsolver_root(s: *const gsl_multiroot_fdfsolver) -> *mut gsl_vector;
+    pub fn gsl_multiroot_fsolver_f(s: *const gsl_multiroot_fsolver) -> *mut gsl_vector;
+    pub fn gsl_multiroot_fdfsolver_f(s: *const gsl_multiroot_fdfsolver) -> *mut gsl_vector;
+    pub fn gsl_multiroot_fsolver_dx(s: *const gsl_multiroot_fsolver) -> *mut gsl_vector;
+    pub fn gsl_multiroot_fdfsolver_dx(s: *const gsl_multiroot_fdfsolver) -> *mut gsl_vector;
+    // Search Stopping Parameters
+    pub fn gsl_multiroot_test_delta(dx: *const gsl_vector, x: *const gsl_vector, epsabs: c_double, epsrel: c_double) -> enums::Value;
+    pub fn gsl_multiroot_test_residual(f: *const gsl_vector, epsabs: c_double) -> enums::Value;
+
+    // Moving Window Statistics
+    pub fn gsl_movstat_alloc(K: size_t) -> *mut gsl_movstat_workspace;
+    pub fn gsl_movstat_alloc2(H: size_t, J: size_t) -> *mut gsl_movstat_workspace;
+    pub fn gsl_movstat_free(w: *mut gsl_movstat_workspace);
+    pub fn gsl_movstat_mean(endtype: enums::MovStatEnd, x: *const gsl_vector, y: *mut gsl_vector, w: *mut gsl_movstat_workspace) -> enums::Value;
+    pub fn gsl_movstat_variance(endtype: enums::MovStatEnd, x: *const gsl_vector, y: *mut gsl_vector,
+        w: *mut gsl_movstat_workspace) -> enums::Value;
+    pub fn gsl_movstat_sd(endtype: enums::MovStatEnd, x: *const gsl_vector, y: *mut gsl_vector, w: *mut gsl_movstat_workspace) -> enums::Value;
+    pub fn gsl_movstat_median(endtype: enums::MovStatEnd, x: *const gsl_vector, y: *mut gsl_vector,
+        w: *mut gsl_movstat_workspace) -> enums::Value;
+    pub fn gsl_movstat_min(endtype: enums::MovStatEnd, x: *const gsl_vector, y: *mut gsl_vector, w: *mut gsl_movstat_workspace) -> enums::Value;
+    pub fn gsl_movstat_max(endtype: enums::MovStatEnd, x: *const gsl_vector, y: *mut gsl_vector, w: *mut gsl_movstat_workspace) -> enums::Value;
+    pub fn gsl_movstat_minmax(endtype: enums::MovStatEnd, x: *const gsl_vector, y_min: *mut gsl_vector, y_max: *mut gsl_vector,
+        w: *mut gsl_movstat_workspace) -> enums::Value;
+    pub fn gsl_movstat_sum(endtype: enums::MovStatEnd, x: *const gsl_vector, y: *mut gsl_vector, w: *mut gsl_movstat_workspace) -> enums::Value;
+    pub fn gsl_movstat_mad0(endtype: enums::MovStatEnd, x: *const gsl_vector, xmedian: *mut gsl_vector, xmad: *mut gsl_vector,
+        w: *mut gsl_movstat_workspace) -> enums::Value;
+    pub fn gsl_movstat_mad(endtype: enums::MovStatEnd, x: *const gsl_vector, xmedian: *mut gsl_vector, xmad: *mut gsl_vector,
+        w: *mut gsl_movstat_workspace) -> enums::Value;
+    pub fn gsl_movstat_qqr(endtype: enums::MovStatEnd, x: *const gsl_vector, q: c_double, xqqr: *mut gsl_vector,
+        w: *mut gsl_movstat_workspace) -> enums::Value;
+    pub fn gsl_movstat_Sn(endtype: enums::MovStatEnd, x: *const gsl_vector, xscale: *mut gsl_vector,
+        w: *mut gsl_movstat_workspace) -> enums::Value;
+    pub fn gsl_movstat_Qn(endtype: enums::MovStatEnd, x: *const gsl_vector, xscale: *mut gsl_vector,
+        w: *mut gsl_movstat_workspace) -> enums::Value;
+    pub fn gsl_movstat_apply(endtype: enums::MovStatEnd, F: *const gsl_movstat_function, x: *const gsl_vector, y: *mut gsl_vector,
+        w: *mut gsl_movstat_workspace) -> enums::Value;
+
+    // Digital Filtering
+    pub fn gsl_filter_gaussian_alloc(K: size_t) -> *mut gsl_filter_gaussian_workspace;
+    pub fn gsl_filter_gaussian_free(w: *mut gsl_filter_gaussian_workspace);
+    pub fn gsl_filter_gaussian(endtype: enums::FilterEnd, alpha: c_double, order: size_t, x: *const gsl_vector, y: *mut gsl_vector,
+        w: *mut gsl_filter_gaussian_workspace) -> enums::Value;
+    pub fn gsl_filter_gaussian_kernel(alpha: c_double, order: size_t, normalize: c_int, kernel: *mut gsl_vector) -> enums::Value;
+    pub fn gsl_filter_median_alloc(K: size_t) -> *mut gsl_filter_median_workspace;
+    pub fn gsl_filter_median_free(w: *mut gsl_filter_median_workspace);
+    pub fn gsl_filter_median(endtype: enums::FilterEnd, x: *const gsl_vector, y: *mut gsl_vector,
+        w: *mut gsl_filter_median_workspace) -> enums::Value;
+    pub fn gsl_filter_rmedian_alloc(K: size_t) -> *mut gsl_filter_rmedian_workspace;
+    pub fn gsl_filter_rmedian_free(w: *mut gsl_filter_rmedian_workspace);
+    pub fn gsl_filter_rmedian(endtype: enums::FilterEnd, x: *const gsl_vector, y: *mut gsl_vector,
+        w: *mut gsl_filter_rmedian_workspace) -> enums::Value;
+    pub fn gsl_filter_impulse_alloc(K: size_t) -> *mut gsl_filter_impulse_workspace;
+    pub fn gsl_filter_impulse_free(w: *mut gsl_filter_impulse_workspace);
+    pub fn gsl_filter_impulse(endtype: enums::FilterEnd, scale_type: enums::FilterScale, t: c_double, x: *const gsl_vector, y: *mut gsl_vector,
+        xmedian: *mut gsl_vector, xsigma: *mut gsl_vector, noutlier: *mut size_t, ioutlier: *mut gsl_vector_int,
+        w: *mut gsl_filter_impulse_workspace) -> enums::Value;
+
+    // Running Statistics
+    pub fn gsl_rstat_alloc() -> *mut gsl_rstat_workspace;
+    pub fn gsl_rstat_free(w: *mut gsl_rstat_workspace);
+    pub fn gsl_rstat_add(x: c_double, w: *mut gsl_rstat_workspace) -> enums::Value;
+    pub fn gsl_rstat_n(w: *const gsl_rstat_workspace) -> size_t;
+    pub fn gsl_rstat_min(w: *const gsl_rstat_workspace) -> c_double;
+    pub fn gsl_rstat_max(w: *const gsl_rstat_workspace) -> c_double;
+    pub fn gsl_rstat_mean(w: *const gsl_rstat_workspace) -> c_double;
+    pub fn gsl_rstat_variance(w: *const gsl_rstat_workspace) -> c_double;
+    pub fn gsl_rstat_sd(w: *const gsl_rstat_workspace) -> c_double;
+    pub fn gsl_rstat_sd_mean(w: *const gsl_rstat_workspace) -> c_double;
+    pub fn gsl_rstat_rms(w: *const gsl_rstat_workspace) -> c_double;
+    pub fn gsl_rstat_skew(w: *const gsl_rstat_workspace) -> c_double;
+    pub fn gsl_rstat_kurtosis(w: *const gsl_rstat_workspace) -> c_double;
+    pub fn gsl_rstat_median(w: *mut gsl_rstat_workspace) -> c_double;
+    pub fn gsl_rstat_reset(w: *mut gsl_rstat_workspace) -> enums::Value;
+    pub fn gsl_rstat_quantile_alloc(p: c_double) -> *mut gsl_rstat_quantile_workspace;
+    pub fn gsl_rstat_quantile_free(w: *mut gsl_rstat_quantile_workspace);
+    pub fn gsl_rstat_quantile_reset(w: *mut gsl_rstat_quantile_workspace) -> enums::Value;
+    pub fn gsl_rstat_quantile_add(x: c_double, w: *mut gsl_rstat_quantile_workspace) -> enums::Value;
+    pub fn gsl_rstat_quantile_get(w: *mut gsl_rstat_quantile_workspace) -> c_double;
+
+    // Sparse Matrices
+    // Allocation
+    pub fn gsl_spmatrix_alloc(n1: size_t, n2: size_t) -> *mut gsl_spmatrix;
+    pub fn gsl_spmatrix_alloc_nzmax(n1: size_t, n2: size_t, nzmax: size_t, sptype: enums::SpMatrixType) -> *mut gsl_spmatrix;
+    pub fn gsl_spmatrix_free(m: *mut gsl_spmatrix);
+    // Accessing Matrix Elements
+    pub fn gsl_spmatrix_get(m: *const gsl_spmatrix, i: size_t, j: size_t) -> c_double;
+    pub fn gsl_spmatrix_set(m: *mut gsl_spmatrix, i: size_t, j: size_t, x: c_double) -> enums::Value;
+    // Initializing Matrix Elements
+    pub fn gsl_spmatrix_set_zero(m: *mut gsl_spmatrix) -> enums::Value;
+    // Copying Matrices
+    pub fn gsl_spmatrix_memcpy(dest: *mut gsl_spmatrix, src: *const gsl_spmatrix) -> enums::Value;
+    // Exchanging Rows and Columns
+    pub fn gsl_spmatrix_transpose_memcpy(dest: *mut gsl_spmatrix, src: *const gsl_spmatrix) -> enums::Value;
+    pub fn gsl_spmatrix_transpose(m: *mut gsl_spmatrix) -> enums::Value;
+    // Matrix Operations
+    pub fn gsl_spmatrix_scale(m: *mut gsl_spmatrix, x: c_double) -> enums::Value;
+    pub fn gsl_spmatrix_add(c: *mut gsl_spmatrix, a: *const gsl_spmatrix, b: *const gsl_spmatrix) -> enums::Value;
+    // Matrix Properties
+    pub fn gsl_spmatrix_nnz(m: *const gsl_spmatrix) -> size_t;
+    pub fn gsl_spmatrix_equal(a: *const gsl_spmatrix, b: *const gsl_spmatrix) -> c_int;
+    // Finding Maximum and Minimum Elements
+    pub fn gsl_spmatrix_minmax(m: *const gsl_spmatrix, min_out: *mut c_double, max_out: *mut c_double) -> enums::Value;
+    // Compressed Format
+    pub fn gsl_spmatrix_ccs(T: *const gsl_spmatrix) -> *mut gsl_spmatrix;
+    pub fn gsl_spmatrix_crs(T: *const gsl_spmatrix) -> *mut gsl_spmatrix;
+    // Conversion Between Sparse and Dense Matrices
+    pub fn gsl_spmatrix_d2sp(S: *mut gsl_spmatrix, A: *const gsl_matrix) -> enums::Value;
+    pub fn gsl_spmatrix_sp2d(A: *mut gsl_matrix, S: *const gsl_spmatrix) -> enums::Value;
+
+    // Sparse BLAS Support
+    pub fn gsl_spblas_dgemv(TransA: CBLAS_TRANSPOSE_t, alpha: c_double, A: *const gsl_spmatrix, x: *const gsl_vector, beta: c_double,
+        y: *mut gsl_vector) -> enums::Value;
+    pub fn gsl_spblas_dgemm(alpha: c_double, A: *const gsl_spmatrix, B: *const gsl_spmatrix, C: *mut gsl_spmatrix) -> enums::Value;
+
+    // Sparse Linear Algebra
+    // Sparse Iterative Solvers
+    pub fn gsl_splinalg_itersolve_alloc(T: *const gsl_splinalg_itersolve_type, n: size_t, m: size_t) -> *mut gsl_splinalg_itersolve;
+    pub fn gsl_splinalg_itersolve_free(w: *mut gsl_splinalg_itersolve);
+    pub fn gsl_splinalg_itersolve_name(w: *const gsl_splinalg_itersolve) -> *const c_char;
+    pub fn gsl_splinalg_itersolve_iterate(A: *const gsl_spmatrix, b: *const gsl_vector, tol: c_double, x: *mut gsl_vector,
+        w: *mut gsl_splinalg_itersolve) -> enums::Value;
+    pub fn gsl_splinalg_itersolve_normr(w: *const gsl_splinalg_itersolve) -> c_double;
+
+    // Multidimensional Minimization
+    // Initializing the Multidimensional Minimizer
+    pub fn gsl_multimin_fdfminimizer_alloc(t: *const gsl_multimin_fdfminimizer_type, n: size_t) -> *mut gsl_multimin_fdfminimizer;
+    pub fn gsl_multimin_fminimizer_alloc(t: *const gsl_multimin_fminimizer_type, n: size_t) -> *mut gsl_multimin_fminimizer;
+    pub fn gsl_multimin_fdfminimizer_set(s: *mut gsl_multimin_fdfminimizer, fdf: *mut gsl_multimin_function_fdf, x: *const gsl_vector,
+        step_size: c_double, tol: c_double) -> enums::Value;
+    pub fn gsl_multimin_fminimizer_set(s: *mut gsl_multimin_fminimizer, f: *mut gsl_multimin_function, x: *const gsl_vector,
+        step_size: *const gsl_vector) -> enums::Value;
+    pub fn gsl_multimin_fdfminimizer_free(s: *mut gsl_multimin_fdfminimizer);
+    pub fn gsl_multimin_fminimizer_free(s: *mut gsl_multimin_fminimizer);
+    pub fn gsl_multimin_fdfminimizer_name(s: *const gsl_multimin_fdfminimizer) -> *const c_char;
+    pub fn gsl_multimin_fminimizer_name(s: *const gsl_multimin_fminimizer) -> *const c_char;
+    // Iteration
+    pub fn gsl_multimin_fdfminimizer_iterate(s: *mut gsl_multimin_fdfminimizer) -> enums::Value;
+    pub fn gsl_multimin_fminimizer_iterate(s: *mut gsl_multimin_fminimizer) -> enums::Value;
+    pub fn gsl_multimin_fdfminimizer_x(s: *const gsl_multimin_fdfminimizer) -> *mut gsl_vector;
+    pub fn gsl_multimin_fminimizer_x(s: *const gsl_multimin_fminimizer) -> *mut gsl_vector;
+    pub fn gsl_multimin_fdfminimizer_minimum(s: *const gsl_multimin_fdfminimizer) -> c_double;
+    pub fn gsl_multimin_fminimizer_minimum(s: *const gsl_multimin_fminimizer) -> c_double;
+    pub fn gsl_multimin_fdfminimizer_gradient(s: *const gsl_multimin_fdfminimizer) -> *mut gsl_vector;
+    pub fn gsl_multimin_fdfminimizer_dx(s: *const gsl_multimin_fdfminimizer) -> *mut gsl_vector;
+    pub fn gsl_multimin_fminimizer_size(s: *const gsl_multimin_fminimizer) -> c_double;
+    pub fn gsl_multimin_fdfminimizer_restart(s: *mut gsl_multimin_fdfminimizer) -> enums::Value;
+    // Stopping Criteria
+    pub fn gsl_multimin_test_gradient(g: *const gsl_vector, epsabs: c_double) -> enums::Value;
+    pub fn gsl_multimin_test_size(size: c_double, epsabs: c_double) -> enums::Value;
+
     // Monte Carlo
     // PLAIN Monte Carlo
     pub fn gsl_monte_plain_alloc(dim: size_t) -> *mut gsl_monte_plain_state;
@@ -2502,6 +3164,117 @@ extern "C" {
     pub fn gsl_multifit_covar(j: *const gsl_matrix, epsrel: c_double, covar: *mut gsl_matrix) -> enums::Value;
     pub fn gsl_multifit_test_delta(dx: *const gsl_vector, x: *const gsl_vector, epsabs: c_double, epsrel: c_double) -> enums::Value;
     pub fn gsl_multifit_gradient(j: *const gsl_matrix, f: *const gsl_vector, g: *mut gsl_vector) -> enums::Value;
+
+    // Linear least-squares fitting
+    pub fn gsl_multifit_linear_alloc(n: size_t, p: size_t) -> *mut gsl_multifit_linear_workspace;
+    pub fn gsl_multifit_linear_free(work: *mut gsl_multifit_linear_workspace);
+    pub fn gsl_multifit_linear(X: *const gsl_matrix, y: *const gsl_vector, c: *mut gsl_vector, cov: *mut gsl_matrix, chisq: *mut c_double,
+        work: *mut gsl_multifit_linear_workspace) -> enums::Value;
+    pub fn gsl_multifit_linear_tsvd(X: *const gsl_matrix, y: *const gsl_vector, tol: c_double, c: *mut gsl_vector, cov: *mut gsl_matrix,
+        chisq: *mut c_double, rank: *mut size_t, work: *mut gsl_multifit_linear_workspace) -> enums::Value;
+    pub fn gsl_multifit_wlinear(X: *const gsl_matrix, w: *const gsl_vector, y: *const gsl_vector, c: *mut gsl_vector, cov: *mut gsl_matrix,
+        chisq: *mut c_double, work: *mut gsl_multifit_linear_workspace) -> enums::Value;
+    pub fn gsl_multifit_wlinear_tsvd(X: *const gsl_matrix, w: *const gsl_vector, y: *const gsl_vector, tol: c_double, c: *mut gsl_vector,
+        cov: *mut gsl_matrix, chisq: *mut c_double, rank: *mut size_t, work: *mut gsl_multifit_linear_workspace) -> enums::Value;
+    pub fn gsl_multifit_linear_rank(tol: c_double, work: *const gsl_multifit_linear_workspace) -> size_t;
+    pub fn gsl_multifit_linear_est(x: *const gsl_vector, c: *const gsl_vector, cov: *const gsl_matrix, y: *mut c_double,
+        y_err: *mut c_double) -> enums::Value;
+    pub fn gsl_multifit_linear_residuals(X: *const gsl_matrix, y: *const gsl_vector, c: *const gsl_vector, r: *mut gsl_vector) -> enums::Value;
+    // Robust linear regression
+    pub fn gsl_multifit_robust_alloc(T: *const gsl_multifit_robust_type, n: size_t, p: size_t) -> *mut gsl_multifit_robust_workspace;
+    pub fn gsl_multifit_robust_free(w: *mut gsl_multifit_robust_workspace);
+    pub fn gsl_multifit_robust_tune(tune: c_double, w: *mut gsl_multifit_robust_workspace) -> enums::Value;
+    pub fn gsl_multifit_robust_maxiter(maxiter: size_t, w: *mut gsl_multifit_robust_workspace) -> enums::Value;
+    pub fn gsl_multifit_robust_name(w: *const gsl_multifit_robust_workspace) -> *const c_char;
+    pub fn gsl_multifit_robust_statistics(w: *const gsl_multifit_robust_workspace) -> gsl_multifit_robust_stats;
+    pub fn gsl_multifit_robust_weights(r: *const gsl_vector, wts: *mut gsl_vector, w: *mut gsl_multifit_robust_workspace) -> enums::Value;
+    pub fn gsl_multifit_robust(X: *const gsl_matrix, y: *const gsl_vector, c: *mut gsl_vector, cov: *mut gsl_matrix,
+        w: *mut gsl_multifit_robust_workspace) -> enums::Value;
+    pub fn gsl_multifit_robust_est(x: *const gsl_vector, c: *const gsl_vector, cov: *const gsl_matrix, y: *mut c_double,
+        y_err: *mut c_double) -> enums::Value;
+    pub fn gsl_multifit_robust_residuals(X: *const gsl_matrix, y: *const gsl_vector, c: *const gsl_vector, r: *mut gsl_vector,
+        w: *mut gsl_multifit_robust_workspace) -> enums::Value;
+    // Large dense linear least-squares fitting
+    pub fn gsl_multilarge_linear_alloc(T: *const gsl_multilarge_linear_type, p: size_t) -> *mut gsl_multilarge_linear_workspace;
+    pub fn gsl_multilarge_linear_free(w: *mut gsl_multilarge_linear_workspace);
+    pub fn gsl_multilarge_linear_name(w: *const gsl_multilarge_linear_workspace) -> *const c_char;
+    pub fn gsl_multilarge_linear_reset(w: *mut gsl_multilarge_linear_workspace) -> enums::Value;
+    pub fn gsl_multilarge_linear_accumulate(X: *mut gsl_matrix, y: *mut gsl_vector, w: *mut gsl_multilarge_linear_workspace) -> enums::Value;
+    pub fn gsl_multilarge_linear_solve(lambda: c_double, c: *mut gsl_vector, rnorm: *mut c_double, snorm: *mut c_double,
+        w: *mut gsl_multilarge_linear_workspace) -> enums::Value;
+    pub fn gsl_multilarge_linear_rcond(rcond: *mut c_double, w: *mut gsl_multilarge_linear_workspace) -> enums::Value;
+    pub fn gsl_multilarge_linear_lcurve(reg_param: *mut gsl_vector, rho: *mut gsl_vector, eta: *mut gsl_vector,
+        w: *mut gsl_multilarge_linear_workspace) -> enums::Value;
+    pub fn gsl_multilarge_linear_stdform1(L: *const gsl_vector, X: *const gsl_matrix, y: *const gsl_vector, Xs: *mut gsl_matrix,
+        ys: *mut gsl_vector, w: *mut gsl_multilarge_linear_workspace) -> enums::Value;
+    pub fn gsl_multilarge_linear_wstdform1(L: *const gsl_vector, X: *const gsl_matrix, w: *const gsl_vector, y: *const gsl_vector,
+        Xs: *mut gsl_matrix, ys: *mut gsl_vector, work: *mut gsl_multilarge_linear_workspace) -> enums::Value;
+    pub fn gsl_multilarge_linear_genform1(L: *const gsl_vector, cs: *const gsl_vector, c: *mut gsl_vector,
+        w: *mut gsl_multilarge_linear_workspace) -> enums::Value;
+    // Nonlinear least-squares fitting
+    pub fn gsl_multifit_nlinear_alloc(T: *const gsl_multifit_nlinear_type, params: *const gsl_multifit_nlinear_parameters, n: size_t,
+        p: size_t) -> *mut gsl_multifit_nlinear_workspace;
+    pub fn gsl_multifit_nlinear_free(w: *mut gsl_multifit_nlinear_workspace);
+    pub fn gsl_multifit_nlinear_default_parameters() -> gsl_multifit_nlinear_parameters;
+    pub fn gsl_multifit_nlinear_init(x: *const gsl_vector, fdf: *mut gsl_multifit_nlinear_fdf,
+        w: *mut gsl_multifit_nlinear_workspace) -> enums::Value;
+    pub fn gsl_multifit_nlinear_winit(x: *const gsl_vector, wts: *const gsl_vector, fdf: *mut gsl_multifit_nlinear_fdf,
+        w: *mut gsl_multifit_nlinear_workspace) -> enums::Value;
+    pub fn gsl_multifit_nlinear_iterate(w: *mut gsl_multifit_nlinear_workspace) -> enums::Value;
+    pub fn gsl_multifit_nlinear_avratio(w: *const gsl_multifit_nlinear_workspace) -> c_double;
+    pub fn gsl_multifit_nlinear_driver(maxiter: size_t, xtol: c_double, gtol: c_double, ftol: c_double,
+        callback: Option<extern "C" fn(iter: size_t, params: *mut c_void, w: *const gsl_multifit_nlinear_workspace)>,
+        callback_params: *mut c_void, info: *mut c_int, w: *mut gsl_multifit_nlinear_workspace) -> enums::Value;
+    pub fn gsl_multifit_nlinear_jac(w: *const gsl_multifit_nlinear_workspace) -> *mut gsl_matrix;
+    pub fn gsl_multifit_nlinear_name(w: *const gsl_multifit_nlinear_workspace) -> *const c_char;
+    pub fn gsl_multifit_nlinear_position(w: *const gsl_multifit_nlinear_workspace) -> *mut gsl_vector;
+    pub fn gsl_multifit_nlinear_residual(w: *const gsl_multifit_nlinear_workspace) -> *mut gsl_vector;
+    pub fn gsl_multifit_nlinear_niter(w: *const gsl_multifit_nlinear_workspace) -> size_t;
+    pub fn gsl_multifit_nlinear_rcond(rcond: *mut c_double, w: *const gsl_multifit_nlinear_workspace) -> enums::Value;
+    pub fn gsl_multifit_nlinear_trs_name(w: *const gsl_multifit_nlinear_workspace) -> *const c_char;
+    pub fn gsl_multifit_nlinear_test(xtol: c_double, gtol: c_double, ftol: c_double, info: *mut c_int,
+        w: *const gsl_multifit_nlinear_workspace) -> enums::Value;
+    pub fn gsl_multifit_nlinear_covar(J: *const gsl_matrix, epsrel: c_double, covar: *mut gsl_matrix) -> enums::Value;
+    // Regularized least-squares fitting
+    pub fn gsl_multifit_linear_svd(X: *const gsl_matrix, work: *mut gsl_multifit_linear_workspace) -> enums::Value;
+    pub fn gsl_multifit_linear_bsvd(X: *const gsl_matrix, work: *mut gsl_multifit_linear_workspace) -> enums::Value;
+    pub fn gsl_multifit_linear_solve(lambda: c_double, X: *const gsl_matrix, y: *const gsl_vector, c: *mut gsl_vector, rnorm: *mut c_double,
+        snorm: *mut c_double, work: *mut gsl_multifit_linear_workspace) -> enums::Value;
+    pub fn gsl_multifit_linear_stdform1(L: *const gsl_vector, X: *const gsl_matrix, y: *const gsl_vector, Xs: *mut gsl_matrix,
+        ys: *mut gsl_vector, work: *mut gsl_multifit_linear_workspace) -> enums::Value;
+    pub fn gsl_multifit_linear_wstdform1(L: *const gsl_vector, X: *const gsl_matrix, w: *const gsl_vector, y: *const gsl_vector,
+        Xs: *mut gsl_matrix, ys: *mut gsl_vector, work: *mut gsl_multifit_linear_workspace) -> enums::Value;
+    pub fn gsl_multifit_linear_L_decomp(L: *mut gsl_matrix, tau: *mut gsl_vector) -> enums::Value;
+    pub fn gsl_multifit_linear_stdform2(LQR: *const gsl_matrix, Ltau: *const gsl_vector, X: *const gsl_matrix, y: *const gsl_vector,
+        Xs: *mut gsl_matrix, ys: *mut gsl_vector, M: *mut gsl_matrix, work: *mut gsl_multifit_linear_workspace) -> enums::Value;
+    pub fn gsl_multifit_linear_wstdform2(LQR: *const gsl_matrix, Ltau: *const gsl_vector, X: *const gsl_matrix, w: *const gsl_vector,
+        y: *const gsl_vector, Xs: *mut gsl_matrix, ys: *mut gsl_vector, M: *mut gsl_matrix,
+        work: *mut gsl_multifit_linear_workspace) -> enums::Value;
+    pub fn gsl_multifit_linear_genform1(L: *const gsl_vector, cs: *const gsl_vector, c: *mut gsl_vector,
+        work: *mut gsl_multifit_linear_workspace) -> enums::Value;
+    pub fn gsl_multifit_linear_genform2(LQR: *const gsl_matrix, Ltau: *const gsl_vector, X: *const gsl_matrix, y: *const gsl_vector,
+        cs: *const gsl_vector, M: *const gsl_matrix, c: *mut gsl_vector, work: *mut gsl_multifit_linear_workspace) -> enums::Value;
+    pub fn gsl_multifit_linear_wgenform2(LQR: *const gsl_matrix, Ltau: *const gsl_vector, X: *const gsl_matrix, w: *const gsl_vector,
+        y: *const gsl_vector, cs: *const gsl_vector, M: *const gsl_matrix, c: *mut gsl_vector,
+        work: *mut gsl_multifit_linear_workspace) -> enums::Value;
+    pub fn gsl_multifit_linear_Lk(p: size_t, k: size_t, L: *mut gsl_matrix) -> enums::Value;
+    pub fn gsl_multifit_linear_Lsobolev(p: size_t, kmax: size_t, alpha: *const gsl_vector, L: *mut gsl_matrix,
+        work: *mut gsl_multifit_linear_workspace) -> enums::Value;
+    pub fn gsl_multifit_linear_lreg(smin: c_double, smax: c_double, reg_param: *mut gsl_vector) -> enums::Value;
+    pub fn gsl_multifit_linear_lcurve(y: *const gsl_vector, reg_param: *mut gsl_vector, rho: *mut gsl_vector, eta: *mut gsl_vector,
+        work: *mut gsl_multifit_linear_workspace) -> enums::Value;
+    pub fn gsl_multifit_linear_lcorner(rho: *const gsl_vector, eta: *const gsl_vector, idx: *mut size_t) -> enums::Value;
+    pub fn gsl_multifit_linear_lcorner2(reg_param: *const gsl_vector, eta: *const gsl_vector, idx: *mut size_t) -> enums::Value;
+    pub fn gsl_multifit_linear_gcv_init(y: *const gsl_vector, reg_param: *mut gsl_vector, UTy: *mut gsl_vector, delta0: *mut c_double,
+        work: *mut gsl_multifit_linear_workspace) -> enums::Value;
+    pub fn gsl_multifit_linear_gcv_curve(reg_param: *const gsl_vector, UTy: *const gsl_vector, delta0: c_double, G: *mut gsl_vector,
+        work: *mut gsl_multifit_linear_workspace) -> enums::Value;
+    pub fn gsl_multifit_linear_gcv_min(reg_param: *const gsl_vector, UTy: *const gsl_vector, G: *const gsl_vector, delta0: c_double,
+        lambda: *mut c_double, work: *mut gsl_multifit_linear_workspace) -> enums::Value;
+    pub fn gsl_multifit_linear_gcv_calc(lambda: c_double, UTy: *const gsl_vector, delta0: c_double,
+        work: *mut gsl_multifit_linear_workspace) -> c_double;
+    pub fn gsl_multifit_linear_gcv(y: *const gsl_vector, reg_param: *mut gsl_vector, G: *mut gsl_vector, lambda: *mut c_double,
+        G_lambda: *mut c_double, work: *mut gsl_multifit_linear_workspace) -> enums::Value;
 }
 
 #[repr(C)]
@@ -2547,6 +3320,11 @@ pub struct gsl_vector_view {
     pub vector: gsl_vector
 }
 
+#[repr(C)]
+pub struct gsl_vector_float_view {
+    pub vector: gsl_vector_float
+}
+
 #[repr(C)]
 pub struct gsl_block {
     pub size: size_t,
@@ -2885,6 +3663,69 @@ pub struct gsl_fft_complex_workspace {
     pub scratch: *mut c_double
 }
 
+#[repr(C)]
+pub struct gsl_fft_complex_wavetable_float {
+    pub n: size_t,
+    pub nf: size_t,
+    pub factor: [size_t; 64],
+    pub twiddle: [*mut gsl_complex_float; 64],
+    pub trig: *mut gsl_complex_float
+}
+
+#[repr(C)]
+pub struct gsl_fft_complex_workspace_float {
+    pub n: size_t,
+    pub scratch: *mut c_float
+}
+
+#[repr(C)]
+pub struct gsl_fft_real_wavetable {
+    pub n: size_t,
+    pub nf: size_t,
+    pub factor: [size_t; 64],
+    pub twiddle: [*mut gsl_complex; 64],
+    pub trig: *mut gsl_complex
+}
+
+#[repr(C)]
+pub struct gsl_fft_halfcomplex_wavetable {
+    pub n: size_t,
+    pub nf: size_t,
+    pub factor: [size_t; 64],
+    pub twiddle: [*mut gsl_complex; 64],
+    pub trig: *mut gsl_complex
+}
+
+#[repr(C)]
+pub struct gsl_fft_real_workspace {
+    pub n: size_t,
+    pub scratch: *mut c_double
+}
+
+#[repr(C)]
+pub struct gsl_fft_real_wavetable_float {
+    pub n: size_t,
+    pub nf: size_t,
+    pub factor: [size_t; 64],
+    pub twiddle: [*mut gsl_complex_float; 64],
+    pub trig: *mut gsl_complex_float
+}
+
+#[repr(C)]
+pub struct gsl_fft_halfcomplex_wavetable_float {
+    pub n: size_t,
+    pub nf: size_t,
+    pub factor: [size_t; 64],
+    pub twiddle: [*mut gsl_complex_float; 64],
+    pub trig: *mut gsl_complex_float
+}
+
+#[repr(C)]
+pub struct gsl_fft_real_workspace_float {
+    pub n: size_t,
+    pub scratch: *mut c_float
+}
+
 #[repr(C)]
 pub struct gsl_histogram {
     pub n: size_t, // This is the number of histogram bins
@@ -3023,7 +3864,7 @@ pub struct gsl_interp_type {
     pub eval: Option<extern "C" fn(*const c_void, *const c_double, *const c_double, size_t, c_double, *mut ::InterpAccel, *mut c_double) -> enums::Value>,
     pub eval_deriv: Option<extern "C" fn(*const c_void, *const c_double, *const c_double, size_t, c_double, *mut ::InterpAccel, *mut c_double) -> enums::Value>,
     pub eval_deriv2: Option<extern "C" fn(*const c_void, *const c_double, *const c_double, size_t, c_double, *mut ::InterpAccel, *mut c_double) -> enums::Value>,
-    pub eval_integ: Option<extern "C" fn(*const c_void, *const c_double, *const c_double, size_t, c_double, *mut ::InterpAccel, c_double, c_double, *mut c_double) -> enums::Value>,
+    pub eval_integ: Option<extern "C" fn(*const c_void, *const c_double, *const c_double, size_t, *mut ::InterpAccel, c_double, c_double, *mut c_double) -> enums::Value>,
     pub free: Option<extern "C" fn(*mut c_void)>
 }
 
@@ -3046,6 +3887,50 @@ pub struct gsl_spline {
     pub size: size_t
 }
 
+/* 2D interpolation object type */
+#[repr(C)]
+pub struct gsl_interp2d_type {
+    pub name: *const c_char,
+    pub min_size: c_uint,
+    pub alloc: Option<extern "C" fn(size_t, size_t) -> *mut c_void>,
+    pub init: Option<extern "C" fn(*mut c_void, *const c_double, *const c_double, *const c_double, size_t, size_t) -> enums::Value>,
+    pub eval: Option<extern "C" fn(*const c_void, *const c_double, *const c_double, *const c_double, size_t, size_t, c_double, c_double,
+        *mut ::InterpAccel, *mut ::InterpAccel, *mut c_double) -> enums::Value>,
+    pub eval_deriv_x: Option<extern "C" fn(*const c_void, *const c_double, *const c_double, *const c_double, size_t, size_t, c_double,
+        c_double, *mut ::InterpAccel, *mut ::InterpAccel, *mut c_double) -> enums::Value>,
+    pub eval_deriv_y: Option<extern "C" fn(*const c_void, *const c_double, *const c_double, *const c_double, size_t, size_t, c_double,
+        c_double, *mut ::InterpAccel, *mut ::InterpAccel, *mut c_double) -> enums::Value>,
+    pub eval_deriv_xx: Option<extern "C" fn(*const c_void, *const c_double, *const c_double, *const c_double, size_t, size_t, c_double,
+        c_double, *mut ::InterpAccel, *mut ::InterpAccel, *mut c_double) -> enums::Value>,
+    pub eval_deriv_xy: Option<extern "C" fn(*const c_void, *const c_double, *const c_double, *const c_double, size_t, size_t, c_double,
+        c_double, *mut ::InterpAccel, *mut ::InterpAccel, *mut c_double) -> enums::Value>,
+    pub eval_deriv_yy: Option<extern "C" fn(*const c_void, *const c_double, *const c_double, *const c_double, size_t, size_t, c_double,
+        c_double, *mut ::InterpAccel, *mut ::InterpAccel, *mut c_double) -> enums::Value>,
+    pub free: Option<extern "C" fn(*mut c_void)>
+}
+
+/* general 2D interpolation object */
+#[repr(C)]
+pub struct gsl_interp2d {
+    pub _type: *const gsl_interp2d_type,
+    pub xmin: c_double,
+    pub xmax: c_double,
+    pub ymin: c_double,
+    pub ymax: c_double,
+    pub xsize: size_t,
+    pub ysize: size_t,
+    pub state: *mut c_void
+}
+
+/* general 2D interpolation object with its own copy of the data */
+#[repr(C)]
+pub struct gsl_spline2d {
+    pub interp_object: gsl_interp2d,
+    pub xarr: *mut c_double,
+    pub yarr: *mut c_double,
+    pub zarr: *mut c_double
+}
+
 /*#[repr(C)]
 pub struct gsl_min_fminimizer_type {
     pub name: *const c_char,
@@ -3338,3 +4223,410 @@ pub struct gsl_wavelet_type {
     pub init: Option<extern "C" fn(h1: *const *const c_double, g1: *const *const c_double, h2: *const *const c_double,
         g2: *const *const c_double, nc: *mut size_t, offset: *mut size_t, member: size_t) -> enums::Value>
 }
+
+#[repr(C)]
+pub struct gsl_multiroot_function {
+    pub f: extern fn(x: *const gsl_vector, params: *mut c_void, f: *mut gsl_vector) -> enums::Value,
+    pub n: size_t,
+    pub params: *mut c_void
+}
+
+#[repr(C)]
+pub struct gsl_multiroot_function_fdf {
+    pub f: extern fn(x: *const gsl_vector, params: *mut c_void, f: *mut gsl_vector) -> enums::Value,
+    pub df: extern fn(x: *const gsl_vector, params: *mut c_void, J: *mut gsl_matrix) -> enums::Value,
+    pub fdf: extern fn(x: *const gsl_vector, params: *mut c_void, f: *mut gsl_vector, J: *mut gsl_matrix) -> enums::Value,
+    pub n: size_t,
+    pub params: *mut c_void
+}
+
+#[repr(C)]
+pub struct gsl_multiroot_fsolver_type {
+    pub name: *const c_char,
+    pub size: size_t
+}
+
+#[repr(C)]
+pub struct gsl_multiroot_fsolver {
+    pub type_: *const gsl_multiroot_fsolver_type,
+    pub function: *mut gsl_multiroot_function,
+    pub x: *mut gsl_vector,
+    pub f: *mut gsl_vector,
+    pub dx: *mut gsl_vector,
+    pub state: *mut c_void
+}
+
+#[repr(C)]
+pub struct gsl_multiroot_fdfsolver_type {
+    pub name: *const c_char,
+    pub size: size_t
+}
+
+#[repr(C)]
+pub struct gsl_multiroot_fdfsolver {
+    pub type_: *const gsl_multiroot_fdfsolver_type,
+    pub fdf: *mut gsl_multiroot_function_fdf,
+    pub x: *mut gsl_vector,
+    pub f: *mut gsl_vector,
+    pub J: *mut gsl_matrix,
+    pub dx: *mut gsl_vector,
+    pub state: *mut c_void
+}
+
+#[repr(C)]
+pub struct gsl_multimin_function {
+    pub f: extern fn(x: *const gsl_vector, params: *mut c_void) -> c_double,
+    pub n: size_t,
+    pub params: *mut c_void
+}
+
+#[repr(C)]
+pub struct gsl_multimin_function_fdf {
+    pub f: extern fn(x: *const gsl_vector, params: *mut c_void) -> c_double,
+    pub df: extern fn(x: *const gsl_vector, params: *mut c_void, g: *mut gsl_vector),
+    pub fdf: extern fn(x: *const gsl_vector, params: *mut c_void, f: *mut c_double, g: *mut gsl_vector),
+    pub n: size_t,
+    pub params: *mut c_void
+}
+
+#[repr(C)]
+pub struct gsl_multimin_fminimizer_type {
+    pub name: *const c_char,
+    pub size: size_t
+}
+
+#[repr(C)]
+pub struct gsl_multimin_fminimizer {
+    pub type_: *const gsl_multimin_fminimizer_type,
+    pub f: *mut gsl_multimin_function,
+    pub fval: c_double,
+    pub x: *mut gsl_vector,
+    pub size: c_double,
+    pub state: *mut c_void
+}
+
+#[repr(C)]
+pub struct gsl_multimin_fdfminimizer_type {
+    pub name: *const c_char,
+    pub size: size_t
+}
+
+#[repr(C)]
+pub struct gsl_multimin_fdfminimizer {
+    pub type_: *const gsl_multimin_fdfminimizer_type,
+    pub fdf: *mut gsl_multimin_function_fdf,
+    pub f: c_double,
+    pub x: *mut gsl_vector,
+    pub gradient: *mut gsl_vector,
+    pub dx: *mut gsl_vector,
+    pub state: *mut c_void
+}
+
+#[repr(C)]
+pub struct gsl_spmatrix {
+    pub size1: size_t,
+    pub size2: size_t,
+    // row indices (triplet and CCS) or column indices (CRS)
+    pub i: *mut c_int,
+    pub data: *mut c_double,
+    // column indices (triplet), column pointers (CCS) or row pointers (CRS)
+    pub p: *mut c_int,
+    pub nzmax: size_t,
+    pub nz: size_t,
+    pub tree: *mut c_void,
+    pub pool: *mut c_void,
+    pub node_size: size_t,
+    pub work: *mut c_void,
+    pub sptype: c_int,
+    pub spflags: size_t
+}
+
+#[repr(C)]
+pub struct gsl_splinalg_itersolve_type {
+    pub name: *const c_char
+}
+
+#[repr(C)]
+pub struct gsl_splinalg_itersolve {
+    pub type_: *const gsl_splinalg_itersolve_type,
+    pub normr: c_double,
+    pub state: *mut c_void
+}
+
+#[repr(C)]
+pub struct gsl_movstat_workspace {
+    pub H: size_t,
+    pub J: size_t,
+    pub K: size_t,
+    pub work: *mut c_double,
+    pub state: *mut c_void,
+    pub state_size: size_t
+}
+
+#[repr(C)]
+pub struct gsl_movstat_function {
+    pub function: extern "C" fn(n: size_t, x: *mut c_double, params: *mut c_void) -> c_double,
+    pub params: *mut c_void
+}
+
+#[repr(C)]
+pub struct gsl_vector_int {
+    pub size: size_t,
+    pub stride: size_t,
+    pub data: *mut c_int,
+    pub block: *mut c_void,
+    pub owner: c_int
+}
+
+#[repr(C)]
+pub struct gsl_vector_uint {
+    pub size: size_t,
+    pub stride: size_t,
+    pub data: *mut c_uint,
+    pub block: *mut c_void,
+    pub owner: c_int
+}
+
+#[repr(C)]
+pub struct gsl_vector_long {
+    pub size: size_t,
+    pub stride: size_t,
+    pub data: *mut c_long,
+    pub block: *mut c_void,
+    pub owner: c_int
+}
+
+#[repr(C)]
+pub struct gsl_vector_uchar {
+    pub size: size_t,
+    pub stride: size_t,
+    pub data: *mut c_uchar,
+    pub block: *mut c_void,
+    pub owner: c_int
+}
+
+#[repr(C)]
+pub struct gsl_matrix_int {
+    pub size1: size_t,
+    pub size2: size_t,
+    pub tda: size_t,
+    pub data: *mut c_int,
+    pub block: *mut c_void,
+    pub owner: c_int
+}
+
+#[repr(C)]
+pub struct gsl_matrix_uint {
+    pub size1: size_t,
+    pub size2: size_t,
+    pub tda: size_t,
+    pub data: *mut c_uint,
+    pub block: *mut c_void,
+    pub owner: c_int
+}
+
+#[repr(C)]
+pub struct gsl_matrix_long {
+    pub size1: size_t,
+    pub size2: size_t,
+    pub tda: size_t,
+    pub data: *mut c_long,
+    pub block: *mut c_void,
+    pub owner: c_int
+}
+
+#[repr(C)]
+pub struct gsl_matrix_uchar {
+    pub size1: size_t,
+    pub size2: size_t,
+    pub tda: size_t,
+    pub data: *mut c_uchar,
+    pub block: *mut c_void,
+    pub owner: c_int
+}
+
+#[repr(C)]
+pub struct gsl_filter_gaussian_workspace {
+    pub K: size_t,
+    pub kernel: *mut c_double,
+    pub movstat_workspace_p: *mut gsl_movstat_workspace
+}
+
+#[repr(C)]
+pub struct gsl_filter_median_workspace {
+    pub movstat_workspace_p: *mut gsl_movstat_workspace
+}
+
+#[repr(C)]
+pub struct gsl_filter_rmedian_workspace {
+    pub H: size_t,
+    pub K: size_t,
+    pub state_size: size_t,
+    pub state: *mut c_void,
+    pub window: *mut c_double,
+    pub minmaxacc: *const c_void,
+    pub medacc: *const c_void
+}
+
+#[repr(C)]
+pub struct gsl_filter_impulse_workspace {
+    pub movstat_workspace_p: *mut gsl_movstat_workspace
+}
+
+#[repr(C)]
+pub struct gsl_rstat_quantile_workspace {
+    pub p: c_double,
+    pub q: [c_double; 5],
+    pub npos: [c_int; 5],
+    pub np: [c_double; 5],
+    pub dnp: [c_double; 5],
+    pub n: size_t
+}
+
+#[repr(C)]
+pub struct gsl_rstat_workspace {
+    pub min: c_double,
+    pub max: c_double,
+    pub mean: c_double,
+    pub M2: c_double,
+    pub M3: c_double,
+    pub M4: c_double,
+    pub n: size_t,
+    pub median_workspace_p: *mut gsl_rstat_quantile_workspace
+}
+
+#[repr(C)]
+pub struct gsl_multifit_linear_workspace {
+    pub nmax: size_t,
+    pub pmax: size_t,
+    pub n: size_t,
+    pub p: size_t,
+    pub A: *mut gsl_matrix,
+    pub Q: *mut gsl_matrix,
+    pub QSI: *mut gsl_matrix,
+    pub S: *mut gsl_vector,
+    pub t: *mut gsl_vector,
+    pub xt: *mut gsl_vector,
+    pub D: *mut gsl_vector,
+    pub rcond: c_double
+}
+
+#[repr(C)]
+pub struct gsl_multifit_robust_type {
+    pub name: *const c_char,
+    pub wfun: Option<extern "C" fn(r: *const gsl_vector, w: *mut gsl_vector) -> c_int>,
+    pub psi_deriv: Option<extern "C" fn(r: *const gsl_vector, dpsi: *mut gsl_vector) -> c_int>,
+    pub tuning_default: c_double
+}
+
+#[repr(C)]
+pub struct gsl_multifit_robust_stats {
+    pub sigma_ols: c_double,
+    pub sigma_mad: c_double,
+    pub sigma_rob: c_double,
+    pub sigma: c_double,
+    pub Rsq: c_double,
+    pub adj_Rsq: c_double,
+    pub rmse: c_double,
+    pub sse: c_double,
+    pub dof: size_t,
+    pub numit: size_t,
+    pub weights: *mut gsl_vector,
+    pub r: *mut gsl_vector
+}
+
+#[repr(C)]
+pub struct gsl_multifit_robust_workspace {
+    pub n: size_t,
+    pub p: size_t,
+    pub numit: size_t,
+    pub maxiter: size_t,
+    pub type_: *const gsl_multifit_robust_type,
+    pub tune: c_double,
+    pub r: *mut gsl_vector,
+    pub weights: *mut gsl_vector,
+    pub c_prev: *mut gsl_vector,
+    pub resfac: *mut gsl_vector,
+    pub psi: *mut gsl_vector,
+    pub dpsi: *mut gsl_vector,
+    pub QSI: *mut gsl_matrix,
+    pub D: *mut gsl_vector,
+    pub workn: *mut gsl_vector,
+    pub stats: gsl_multifit_robust_stats,
+    pub multifit_p: *mut gsl_multifit_linear_workspace
+}
+
+#[repr(C)]
+pub struct gsl_multifit_nlinear_fdf {
+    pub f: extern fn(x: *const gsl_vector, params: *mut c_void, f: *mut gsl_vector) -> enums::Value,
+    pub df: Option<extern fn(x: *const gsl_vector, params: *mut c_void, df: *mut gsl_matrix) -> enums::Value>,
+    pub fvv: Option<extern fn(x: *const gsl_vector, v: *const gsl_vector, params: *mut c_void, fvv: *mut gsl_vector) -> enums::Value>,
+    pub n: size_t,
+    pub p: size_t,
+    pub params: *mut c_void,
+    pub nevalf: size_t,
+    pub nevaldf: size_t,
+    pub nevalfvv: size_t
+}
+
+#[repr(C)]
+pub struct gsl_multifit_nlinear_trs {
+    pub name: *const c_char
+}
+
+#[repr(C)]
+pub struct gsl_multifit_nlinear_scale {
+    pub name: *const c_char
+}
+
+#[repr(C)]
+pub struct gsl_multifit_nlinear_solver {
+    pub name: *const c_char
+}
+
+#[repr(C)]
+pub struct gsl_multifit_nlinear_type {
+    pub name: *const c_char
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct gsl_multifit_nlinear_parameters {
+    pub trs: *const gsl_multifit_nlinear_trs,
+    pub scale: *const gsl_multifit_nlinear_scale,
+    pub solver: *const gsl_multifit_nlinear_solver,
+    pub fdtype: ::MultifitNlinearFdType,
+    pub factor_up: c_double,
+    pub factor_down: c_double,
+    pub avmax: c_double,
+    pub h_df: c_double,
+    pub h_fvv: c_double
+}
+
+#[repr(C)]
+pub struct gsl_multifit_nlinear_workspace {
+    pub type_: *const gsl_multifit_nlinear_type,
+    pub fdf: *mut gsl_multifit_nlinear_fdf,
+    pub x: *mut gsl_vector,
+    pub f: *mut gsl_vector,
+    pub dx: *mut gsl_vector,
+    pub g: *mut gsl_vector,
+    pub J: *mut gsl_matrix,
+    pub sqrt_wts_work: *mut gsl_vector,
+    pub sqrt_wts: *mut gsl_vector,
+    pub niter: size_t,
+    pub params: gsl_multifit_nlinear_parameters,
+    pub state: *mut c_void
+}
+
+#[repr(C)]
+pub struct gsl_multilarge_linear_type {
+    pub name: *const c_char
+}
+
+#[repr(C)]
+pub struct gsl_multilarge_linear_workspace {
+    pub type_: *const gsl_multilarge_linear_type,
+    pub state: *mut c_void,
+    pub p: size_t
+}
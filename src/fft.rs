@@ -157,8 +157,47 @@ The precise storage arrangements depend on the algorithm, and are different for
 in-place, which constrains the locations where each element can be stored. The restriction forces real and imaginary parts to be stored far apart.
 The mixed-radix algorithm does not have this restriction, and it stores the real and imaginary parts of a given term in neighboring locations (which
 is desirable for better locality of memory accesses).
+
+##Multidimensional FFTs
+
+GSL only provides one-dimensional transforms. The modules fft2d and fftnd compute multidimensional transforms by applying the mixed-radix
+routines along each dimension in turn, which is valid because the multidimensional discrete Fourier transform is separable,
+
+z_{k_1 k_2} = \sum_{j_1=0}^{n_1-1} \sum_{j_2=0}^{n_2-1} x_{j_1 j_2} \exp(-2 \pi i (j_1 k_1 / n_1 + j_2 k_2 / n_2))
+
+The two-dimensional transform of a real matrix, as used in image processing, is available in the half-complex form which keeps only the
+non-redundant columns k_2 = 0, ..., n_2/2 of the spectrum.
 !*/
 
+/// This function returns the sample frequencies of the bins of an FFT of length n, for a sample spacing d. The frequency of bin k is k/(n d)
+/// for k < (n+1)/2, and the remaining bins hold the negative frequencies (k - n)/(n d), following the storage order of the transforms.
+///
+/// For example, for n = 8 and d = 0.1 the frequencies are [0, 1.25, 2.5, 3.75, -5, -3.75, -2.5, -1.25].
+pub fn fftfreq(n: usize, d: f64) -> Vec<f64> {
+    let scale = 1f64 / (n as f64 * d);
+    let half = (n + 1) / 2;
+
+    (0..n).map(|k| if k < half { k as f64 * scale } else { (k as f64 - n as f64) * scale }).collect()
+}
+
+/// This function reorders the output of an FFT so that the zero frequency is in the middle of the returned sequence, with the negative
+/// frequencies before it and the positive frequencies after it. The result is sorted by increasing frequency.
+pub fn fftshift<T: Clone>(data: &[T]) -> Vec<T> {
+    let n = data.len();
+    let half = (n + 1) / 2;
+
+    data[half..].iter().chain(data[..half].iter()).cloned().collect()
+}
+
+/// This function is the inverse of `fftshift`: it moves the zero frequency from the middle of the sequence back to its start. It differs
+/// from `fftshift` for odd lengths only.
+pub fn ifftshift<T: Clone>(data: &[T]) -> Vec<T> {
+    let n = data.len();
+    let half = n / 2;
+
+    data[half..].iter().chain(data[..half].iter()).cloned().collect()
+}
+
 /// These functions compute forward, backward and inverse FFTs of length n with stride stride, on the packed complex array data using an in-place radix-2
 /// decimation-in-time algorithm. The length of the transform is restricted to powers of two. For the transform version of the function
 /// the sign argument can be either forward (-1) or backward (+1).
@@ -330,3 +369,473 @@ pub mod real_radix2 {
             stride, n) }
     }
 }
+
+/// This section describes mixed-radix FFT algorithms for real data. The mixed-radix functions work for FFTs of any length. They are a
+/// reimplementation of the real-FFT routines in the Fortran FFTPACK library by Paul Swarztrauber. The theory behind the algorithm is
+/// explained in the article Fast Mixed-Radix Real Fourier Transforms by Clive Temperton. The routines here use the same indexing scheme and
+/// basic algorithms as FFTPACK.
+///
+/// The functions use the FFTPACK storage convention for half-complex sequences. In this convention the half-complex transform of a real
+/// sequence is stored with frequencies in increasing order, starting at zero, with the real and imaginary parts of each frequency in
+/// neighboring locations. When a value is known to be real the imaginary part is not stored. The imaginary part of the zero-frequency
+/// component is never stored. It is known to be zero (since the zero frequency component is simply the sum of the input data (all real)).
+/// For a sequence of even length the imaginary part of the frequency n/2 is not stored either, since the symmetry z_k = z_{n-k}^* implies
+/// that this is purely real too.
+///
+/// The caller must supply a wavetable containing trigonometric lookup tables and a workspace work. The real transform uses a
+/// FftRealWaveTable while the half-complex transforms use a FftHalfComplexWaveTable, and both use a FftRealWorkspace.
+pub mod real_mixed_radix {
+    use enums;
+    use ffi;
+    use types::{FftRealWaveTable, FftHalfComplexWaveTable, FftRealWorkspace};
+
+    /// This function computes the FFT of data, a real array of length n, using a mixed radix decimation-in-frequency algorithm. For
+    /// transform data is an array of time-ordered real data. The output is a half-complex sequence, which is stored in-place.
+    pub fn transform(data: &mut [f64], stride: usize, n: usize, wavetable: &FftRealWaveTable, work: &FftRealWorkspace) -> enums::Value {
+        unsafe { ffi::gsl_fft_real_transform(data.as_mut_ptr(), stride, n, ffi::FFI::unwrap(wavetable), ffi::FFI::unwrap(work)) }
+    }
+
+    /// This function computes the FFT of data, a half-complex array of length n, using a mixed radix decimation-in-frequency algorithm.
+    /// The data is stored according the output scheme used by `transform` and the output is a real array stored in natural order.
+    pub fn halfcomplex_transform(data: &mut [f64], stride: usize, n: usize, wavetable: &FftHalfComplexWaveTable,
+        work: &FftRealWorkspace) -> enums::Value {
+        unsafe { ffi::gsl_fft_halfcomplex_transform(data.as_mut_ptr(), stride, n, ffi::FFI::unwrap(wavetable), ffi::FFI::unwrap(work)) }
+    }
+
+    /// This function computes the backwards FFT of the half-complex array data of length n. The output is an unscaled real array stored
+    /// in natural order.
+    pub fn backward(data: &mut [f64], stride: usize, n: usize, wavetable: &FftHalfComplexWaveTable, work: &FftRealWorkspace) -> enums::Value {
+        unsafe { ffi::gsl_fft_halfcomplex_backward(data.as_mut_ptr(), stride, n, ffi::FFI::unwrap(wavetable), ffi::FFI::unwrap(work)) }
+    }
+
+    /// This function computes the inverse FFT of the half-complex array data of length n. The output is a real array stored in natural
+    /// order, scaled by 1/n so that it recreates the data given to `transform`.
+    pub fn inverse(data: &mut [f64], stride: usize, n: usize, wavetable: &FftHalfComplexWaveTable, work: &FftRealWorkspace) -> enums::Value {
+        unsafe { ffi::gsl_fft_halfcomplex_inverse(data.as_mut_ptr(), stride, n, ffi::FFI::unwrap(wavetable), ffi::FFI::unwrap(work)) }
+    }
+
+    /// This function converts a single real array, real_coefficient into an equivalent complex array, complex_coefficient, (with
+    /// imaginary part set to zero), suitable for mixed_radix routines. The algorithm for the conversion is simply,
+    ///
+    /// ```C
+    /// for (i = 0; i < n; i++)
+    ///   {
+    ///     complex_coefficient[i*stride].real = real_coefficient[i*stride];
+    ///     complex_coefficient[i*stride].imag = 0.0;
+    ///   }
+    /// ```
+    pub fn unpack(real_coefficient: &[f64], complex_coefficient: &mut [f64], stride: usize, n: usize) -> enums::Value {
+        unsafe { ffi::gsl_fft_real_unpack(real_coefficient.as_ptr(), complex_coefficient.as_mut_ptr(), stride, n) }
+    }
+
+    /// This function converts halfcomplex_coefficient, an array of half-complex coefficients as returned by `transform`, into an ordinary
+    /// complex array, complex_coefficient. It fills in the complex array using the symmetry z_k = z_{n-k}^* to reconstruct the
+    /// redundant elements.
+    pub fn halfcomplex_unpack(halfcomplex_coefficient: &[f64], complex_coefficient: &mut [f64], stride: usize, n: usize) -> enums::Value {
+        unsafe { ffi::gsl_fft_halfcomplex_unpack(halfcomplex_coefficient.as_ptr(), complex_coefficient.as_mut_ptr(), stride, n) }
+    }
+}
+
+/// Single precision version of the radix2 module. The data is a packed array of f32 complex numbers.
+pub mod radix2_f32 {
+    use enums;
+    use ffi;
+
+    pub fn forward(data: &mut [f32], stride: usize, n: usize) -> enums::Value {
+        unsafe { ffi::gsl_fft_complex_float_radix2_forward(data.as_mut_ptr(), stride, n) }
+    }
+
+    pub fn transform(data: &mut [f32], stride: usize, n: usize, sign: ::FftDirection) -> enums::Value {
+        unsafe { ffi::gsl_fft_complex_float_radix2_transform(data.as_mut_ptr(), stride, n, sign) }
+    }
+
+    pub fn backward(data: &mut [f32], stride: usize, n: usize) -> enums::Value {
+        unsafe { ffi::gsl_fft_complex_float_radix2_backward(data.as_mut_ptr(), stride, n) }
+    }
+
+    pub fn inverse(data: &mut [f32], stride: usize, n: usize) -> enums::Value {
+        unsafe { ffi::gsl_fft_complex_float_radix2_inverse(data.as_mut_ptr(), stride, n) }
+    }
+
+    /// This is decimation-in-frequency version of the radix-2 FFT function.
+    pub fn dif_forward(data: &mut [f32], stride: usize, n: usize) -> enums::Value {
+        unsafe { ffi::gsl_fft_complex_float_radix2_dif_forward(data.as_mut_ptr(), stride, n) }
+    }
+
+    /// This is decimation-in-frequency version of the radix-2 FFT function.
+    pub fn dif_transform(data: &mut [f32], stride: usize, n: usize, sign: ::FftDirection) -> enums::Value {
+        unsafe { ffi::gsl_fft_complex_float_radix2_dif_transform(data.as_mut_ptr(), stride, n, sign) }
+    }
+
+    /// This is decimation-in-frequency version of the radix-2 FFT function.
+    pub fn dif_backward(data: &mut [f32], stride: usize, n: usize) -> enums::Value {
+        unsafe { ffi::gsl_fft_complex_float_radix2_dif_backward(data.as_mut_ptr(), stride, n) }
+    }
+
+    /// This is decimation-in-frequency version of the radix-2 FFT function.
+    pub fn dif_inverse(data: &mut [f32], stride: usize, n: usize) -> enums::Value {
+        unsafe { ffi::gsl_fft_complex_float_radix2_dif_inverse(data.as_mut_ptr(), stride, n) }
+    }
+}
+
+/// Single precision version of the mixed_radix module. The data is a packed array of f32 complex numbers.
+pub mod mixed_radix_f32 {
+    use enums;
+    use ffi;
+    use types::{FftComplexWaveTableF32, FftComplexWorkspaceF32};
+
+    pub fn forward(data: &mut [f32], stride: usize, n: usize, wavetable: &FftComplexWaveTableF32,
+        work: &FftComplexWorkspaceF32) -> enums::Value {
+        unsafe { ffi::gsl_fft_complex_float_forward(data.as_mut_ptr(), stride, n, ffi::FFI::unwrap(wavetable), ffi::FFI::unwrap(work)) }
+    }
+
+    pub fn transform(data: &mut [f32], stride: usize, n: usize, wavetable: &FftComplexWaveTableF32, work: &FftComplexWorkspaceF32,
+        sign: ::FftDirection) -> enums::Value {
+        unsafe { ffi::gsl_fft_complex_float_transform(data.as_mut_ptr(), stride, n, ffi::FFI::unwrap(wavetable), ffi::FFI::unwrap(work),
+            sign) }
+    }
+
+    pub fn backward(data: &mut [f32], stride: usize, n: usize, wavetable: &FftComplexWaveTableF32,
+        work: &FftComplexWorkspaceF32) -> enums::Value {
+        unsafe { ffi::gsl_fft_complex_float_backward(data.as_mut_ptr(), stride, n, ffi::FFI::unwrap(wavetable), ffi::FFI::unwrap(work)) }
+    }
+
+    pub fn inverse(data: &mut [f32], stride: usize, n: usize, wavetable: &FftComplexWaveTableF32,
+        work: &FftComplexWorkspaceF32) -> enums::Value {
+        unsafe { ffi::gsl_fft_complex_float_inverse(data.as_mut_ptr(), stride, n, ffi::FFI::unwrap(wavetable), ffi::FFI::unwrap(work)) }
+    }
+}
+
+/// Single precision version of the real_radix2 module.
+pub mod real_radix2_f32 {
+    use enums;
+    use ffi;
+
+    pub fn transform(data: &mut [f32], stride: usize, n: usize) -> enums::Value {
+        unsafe { ffi::gsl_fft_real_float_radix2_transform(data.as_mut_ptr(), stride, n) }
+    }
+
+    pub fn inverse(data: &mut [f32], stride: usize, n: usize) -> enums::Value {
+        unsafe { ffi::gsl_fft_halfcomplex_float_radix2_inverse(data.as_mut_ptr(), stride, n) }
+    }
+
+    pub fn backward(data: &mut [f32], stride: usize, n: usize) -> enums::Value {
+        unsafe { ffi::gsl_fft_halfcomplex_float_radix2_backward(data.as_mut_ptr(), stride, n) }
+    }
+
+    pub fn unpack(halfcomplex_coefficient: &[f32], complex_coefficient: &mut [f32], stride: usize, n: usize) -> enums::Value {
+        unsafe { ffi::gsl_fft_halfcomplex_float_radix2_unpack(halfcomplex_coefficient.as_ptr(), complex_coefficient.as_mut_ptr(),
+            stride, n) }
+    }
+}
+
+/// Single precision version of the real_mixed_radix module.
+pub mod real_mixed_radix_f32 {
+    use enums;
+    use ffi;
+    use types::{FftRealWaveTableF32, FftHalfComplexWaveTableF32, FftRealWorkspaceF32};
+
+    pub fn transform(data: &mut [f32], stride: usize, n: usize, wavetable: &FftRealWaveTableF32,
+        work: &FftRealWorkspaceF32) -> enums::Value {
+        unsafe { ffi::gsl_fft_real_float_transform(data.as_mut_ptr(), stride, n, ffi::FFI::unwrap(wavetable), ffi::FFI::unwrap(work)) }
+    }
+
+    pub fn halfcomplex_transform(data: &mut [f32], stride: usize, n: usize, wavetable: &FftHalfComplexWaveTableF32,
+        work: &FftRealWorkspaceF32) -> enums::Value {
+        unsafe { ffi::gsl_fft_halfcomplex_float_transform(data.as_mut_ptr(), stride, n, ffi::FFI::unwrap(wavetable),
+            ffi::FFI::unwrap(work)) }
+    }
+
+    pub fn backward(data: &mut [f32], stride: usize, n: usize, wavetable: &FftHalfComplexWaveTableF32,
+        work: &FftRealWorkspaceF32) -> enums::Value {
+        unsafe { ffi::gsl_fft_halfcomplex_float_backward(data.as_mut_ptr(), stride, n, ffi::FFI::unwrap(wavetable),
+            ffi::FFI::unwrap(work)) }
+    }
+
+    pub fn inverse(data: &mut [f32], stride: usize, n: usize, wavetable: &FftHalfComplexWaveTableF32,
+        work: &FftRealWorkspaceF32) -> enums::Value {
+        unsafe { ffi::gsl_fft_halfcomplex_float_inverse(data.as_mut_ptr(), stride, n, ffi::FFI::unwrap(wavetable),
+            ffi::FFI::unwrap(work)) }
+    }
+
+    pub fn unpack(real_coefficient: &[f32], complex_coefficient: &mut [f32], stride: usize, n: usize) -> enums::Value {
+        unsafe { ffi::gsl_fft_real_float_unpack(real_coefficient.as_ptr(), complex_coefficient.as_mut_ptr(), stride, n) }
+    }
+
+    pub fn halfcomplex_unpack(halfcomplex_coefficient: &[f32], complex_coefficient: &mut [f32], stride: usize, n: usize) -> enums::Value {
+        unsafe { ffi::gsl_fft_halfcomplex_float_unpack(halfcomplex_coefficient.as_ptr(), complex_coefficient.as_mut_ptr(), stride, n) }
+    }
+}
+
+// Applies the one-dimensional complex transform f of length n to the lines of the packed array data starting at the complex offsets
+// given by starts, with the given stride.
+fn transform_lines<F, I>(data: &mut [f64], n: usize, stride: usize, starts: I, f: &F) -> ::Value
+    where F: Fn(&mut [f64], usize, usize, &::FftComplexWaveTable, &::FftComplexWorkspace) -> ::Value,
+          I: Iterator<Item = usize> {
+    let wavetable = match ::FftComplexWaveTable::new(n) {
+        Some(w) => w,
+        None => return ::Value::NoMem
+    };
+    let work = match ::FftComplexWorkspace::new(n) {
+        Some(w) => w,
+        None => return ::Value::NoMem
+    };
+
+    for start in starts {
+        let ret = f(&mut data[2 * start..], stride, n, &wavetable, &work);
+
+        if ret != ::Value::Success {
+            return ret;
+        }
+    }
+    ::Value::Success
+}
+
+/// Two-dimensional FFTs of packed complex arrays and complex matrices. The transforms are computed with the mixed-radix routines, first
+/// along each row and then along each column, so both dimensions can have any length.
+///
+/// The packed array functions take the array data holding size1 rows of size2 complex numbers, stored in row-major order with a physical
+/// row length of tda complex numbers, as for a MatrixComplexF64. The element (i,j) has its real part at data[2*(i*tda + j)] and its
+/// imaginary part at data[2*(i*tda + j) + 1]. The functions return ::Value::BadLen if data is too short for the given dimensions.
+///
+/// The inverse transforms are scaled by 1/(size1 size2), so that they recreate the data given to the forward transforms.
+pub mod fft2d {
+    use enums;
+    use ffi;
+    use types::{FftComplexWaveTable, FftComplexWorkspace, FftRealWaveTable, FftRealWorkspace, FftHalfComplexWaveTable, ComplexF64};
+    use enums::GSLResult;
+
+    fn apply<F>(data: &mut [f64], tda: usize, size1: usize, size2: usize, f: &F) -> enums::Value
+        where F: Fn(&mut [f64], usize, usize, &FftComplexWaveTable, &FftComplexWorkspace) -> enums::Value {
+        if size1 == 0 || size2 == 0 {
+            rgsl_error!("matrix dimension must be positive integer", ::Value::Dom);
+            return ::Value::Dom;
+        }
+        if tda < size2 || data.len() < 2 * ((size1 - 1) * tda + size2) {
+            rgsl_error!("data is too short for the given dimensions", ::Value::BadLen);
+            return ::Value::BadLen;
+        }
+        match super::transform_lines(data, size2, 1, (0..size1).map(|i| i * tda), f) {
+            ::Value::Success => super::transform_lines(data, size1, tda, 0..size2, f),
+            e => e
+        }
+    }
+
+    fn apply_matrix<F>(m: &mut ::MatrixComplexF64, f: &F) -> enums::Value
+        where F: Fn(&mut [f64], usize, usize, &FftComplexWaveTable, &FftComplexWorkspace) -> enums::Value {
+        let p = ffi::FFI::unwrap(m);
+        let (size1, size2, tda) = unsafe { ((*p).size1, (*p).size2, (*p).tda) };
+
+        if size1 == 0 || size2 == 0 {
+            rgsl_error!("matrix dimension must be positive integer", ::Value::Dom);
+            return ::Value::Dom;
+        }
+        let data = unsafe { ::std::slice::from_raw_parts_mut((*p).data, 2 * ((size1 - 1) * tda + size2)) };
+
+        apply(data, tda, size1, size2, f)
+    }
+
+    /// This function computes the forward or backward two-dimensional FFT of the packed complex array data, depending on sign.
+    pub fn transform(data: &mut [f64], tda: usize, size1: usize, size2: usize, sign: ::FftDirection) -> enums::Value {
+        apply(data, tda, size1, size2, &|d: &mut [f64], s, n, w: &FftComplexWaveTable, k: &FftComplexWorkspace| {
+            super::mixed_radix::transform(d, s, n, w, k, sign)
+        })
+    }
+
+    pub fn forward(data: &mut [f64], tda: usize, size1: usize, size2: usize) -> enums::Value {
+        apply(data, tda, size1, size2, &super::mixed_radix::forward)
+    }
+
+    pub fn backward(data: &mut [f64], tda: usize, size1: usize, size2: usize) -> enums::Value {
+        apply(data, tda, size1, size2, &super::mixed_radix::backward)
+    }
+
+    pub fn inverse(data: &mut [f64], tda: usize, size1: usize, size2: usize) -> enums::Value {
+        apply(data, tda, size1, size2, &super::mixed_radix::inverse)
+    }
+
+    /// This function computes the forward or backward in-place two-dimensional FFT of the matrix m, depending on sign.
+    pub fn transform_matrix(m: &mut ::MatrixComplexF64, sign: ::FftDirection) -> enums::Value {
+        apply_matrix(m, &|d: &mut [f64], s, n, w: &FftComplexWaveTable, k: &FftComplexWorkspace| {
+            super::mixed_radix::transform(d, s, n, w, k, sign)
+        })
+    }
+
+    pub fn forward_matrix(m: &mut ::MatrixComplexF64) -> enums::Value {
+        apply_matrix(m, &super::mixed_radix::forward)
+    }
+
+    pub fn backward_matrix(m: &mut ::MatrixComplexF64) -> enums::Value {
+        apply_matrix(m, &super::mixed_radix::backward)
+    }
+
+    pub fn inverse_matrix(m: &mut ::MatrixComplexF64) -> enums::Value {
+        apply_matrix(m, &super::mixed_radix::inverse)
+    }
+
+    /// This function computes the two-dimensional FFT of the real matrix m, of size size1 x size2. As the transform of real data has the
+    /// symmetry z(i,j) = z(size1-i,size2-j)^*, only the non-redundant half of the spectrum is returned: the columns j = 0, ..., size2/2,
+    /// in a new complex matrix of size size1 x (size2/2 + 1). This is the usual layout for image processing.
+    pub fn real_forward_matrix(m: &::MatrixF64) -> GSLResult<::MatrixComplexF64> {
+        let (size1, size2) = (m.size1(), m.size2());
+
+        if size1 == 0 || size2 == 0 {
+            rgsl_error!("matrix dimension must be positive integer", ::Value::Dom);
+            return Err(::Value::Dom);
+        }
+        let ncols = size2 / 2 + 1;
+        let mut out = match ::MatrixComplexF64::new(size1, ncols) {
+            Some(o) => o,
+            None => return Err(::Value::NoMem)
+        };
+        let wavetable = match FftRealWaveTable::new(size2) {
+            Some(w) => w,
+            None => return Err(::Value::NoMem)
+        };
+        let work = match FftRealWorkspace::new(size2) {
+            Some(w) => w,
+            None => return Err(::Value::NoMem)
+        };
+        let mut row = vec![0f64; size2];
+        let mut unpacked = vec![0f64; 2 * size2];
+
+        for i in 0..size1 {
+            for j in 0..size2 {
+                row[j] = m.get(i, j);
+            }
+            GSLResult::from(super::real_mixed_radix::transform(&mut row, 1, size2, &wavetable, &work))?;
+            GSLResult::from(super::real_mixed_radix::halfcomplex_unpack(&row, &mut unpacked, 1, size2))?;
+            for j in 0..ncols {
+                out.set(i, j, &ComplexF64::rect(unpacked[2 * j], unpacked[2 * j + 1]));
+            }
+        }
+
+        let p = ffi::FFI::unwrap(&out);
+        let tda = unsafe { (*p).tda };
+        let data = unsafe { ::std::slice::from_raw_parts_mut((*p).data, 2 * ((size1 - 1) * tda + ncols)) };
+
+        GSLResult::from(super::transform_lines(data, size1, tda, 0..ncols, &super::mixed_radix::forward))?;
+        Ok(out)
+    }
+
+    /// This function computes the inverse of `real_forward_matrix`: m holds the non-redundant half of the spectrum of a real matrix with
+    /// size2 columns, so it must have size2/2 + 1 columns. The returned real matrix has the same number of rows as m and size2 columns,
+    /// and is scaled by 1/(size1 size2).
+    pub fn halfcomplex_inverse_matrix(m: &::MatrixComplexF64, size2: usize) -> GSLResult<::MatrixF64> {
+        let p = ffi::FFI::unwrap(m);
+        let (size1, ncols) = unsafe { ((*p).size1, (*p).size2) };
+
+        if size1 == 0 || size2 == 0 {
+            rgsl_error!("matrix dimension must be positive integer", ::Value::Dom);
+            return Err(::Value::Dom);
+        }
+        if ncols != size2 / 2 + 1 {
+            rgsl_error!("the spectrum must have size2/2 + 1 columns", ::Value::BadLen);
+            return Err(::Value::BadLen);
+        }
+        let mut spectrum = match ::MatrixComplexF64::new(size1, ncols) {
+            Some(s) => s,
+            None => return Err(::Value::NoMem)
+        };
+        let mut out = match ::MatrixF64::new(size1, size2) {
+            Some(o) => o,
+            None => return Err(::Value::NoMem)
+        };
+        let wavetable = match FftHalfComplexWaveTable::new(size2) {
+            Some(w) => w,
+            None => return Err(::Value::NoMem)
+        };
+        let work = match FftRealWorkspace::new(size2) {
+            Some(w) => w,
+            None => return Err(::Value::NoMem)
+        };
+
+        GSLResult::from(spectrum.copy_from(m))?;
+        {
+            let sp = ffi::FFI::unwrap(&spectrum);
+            let tda = unsafe { (*sp).tda };
+            let data = unsafe { ::std::slice::from_raw_parts_mut((*sp).data, 2 * ((size1 - 1) * tda + ncols)) };
+
+            GSLResult::from(super::transform_lines(data, size1, tda, 0..ncols, &super::mixed_radix::inverse))?;
+        }
+
+        let mut row = vec![0f64; size2];
+
+        for i in 0..size1 {
+            // pack the row using the FFTPACK half-complex storage convention
+            row[0] = spectrum.get(i, 0).data[0];
+            for k in 1..(size2 + 1) / 2 {
+                let z = spectrum.get(i, k);
+
+                row[2 * k - 1] = z.data[0];
+                row[2 * k] = z.data[1];
+            }
+            if size2 % 2 == 0 {
+                row[size2 - 1] = spectrum.get(i, size2 / 2).data[0];
+            }
+            GSLResult::from(super::real_mixed_radix::inverse(&mut row, 1, size2, &wavetable, &work))?;
+            for j in 0..size2 {
+                out.set(i, j, row[j]);
+            }
+        }
+        Ok(out)
+    }
+}
+
+/// N-dimensional FFTs of packed complex arrays. The array data holds a complex number for each point of a grid with the dimensions dims,
+/// stored in row-major order: the last dimension varies fastest. The transform is computed as a sequence of one-dimensional mixed-radix
+/// transforms along each dimension, so the dimensions can have any length. The functions return ::Value::BadLen if data is too short
+/// for the given dimensions.
+///
+/// The inverse transform is scaled by the inverse of the total number of points, so that it recreates the data given to the forward
+/// transform.
+pub mod fftnd {
+    use enums;
+    use ffi;
+    use types::{FftComplexWaveTable, FftComplexWorkspace};
+
+    fn apply<F>(data: &mut [f64], dims: &[usize], f: &F) -> enums::Value
+        where F: Fn(&mut [f64], usize, usize, &FftComplexWaveTable, &FftComplexWorkspace) -> enums::Value {
+        let total = dims.iter().fold(1usize, |acc, &d| acc * d);
+
+        if dims.is_empty() || total == 0 {
+            rgsl_error!("dimensions must be positive integers", ::Value::Dom);
+            return ::Value::Dom;
+        }
+        if data.len() < 2 * total {
+            rgsl_error!("data is too short for the given dimensions", ::Value::BadLen);
+            return ::Value::BadLen;
+        }
+        for (axis, &n) in dims.iter().enumerate() {
+            // distance between two consecutive elements along this axis, and number of lines before it
+            let stride = dims[axis + 1..].iter().fold(1usize, |acc, &d| acc * d);
+            let outer = total / (n * stride);
+            let starts = (0..outer).flat_map(|o| (0..stride).map(move |i| o * n * stride + i));
+            let ret = super::transform_lines(data, n, stride, starts, f);
+
+            if ret != ::Value::Success {
+                return ret;
+            }
+        }
+        ::Value::Success
+    }
+
+    /// This function computes the forward or backward N-dimensional FFT of the packed complex array data, depending on sign.
+    pub fn transform(data: &mut [f64], dims: &[usize], sign: ::FftDirection) -> enums::Value {
+        apply(data, dims, &|d: &mut [f64], s, n, w: &FftComplexWaveTable, k: &FftComplexWorkspace| {
+            super::mixed_radix::transform(d, s, n, w, k, sign)
+        })
+    }
+
+    pub fn forward(data: &mut [f64], dims: &[usize]) -> enums::Value {
+        apply(data, dims, &super::mixed_radix::forward)
+    }
+
+    pub fn backward(data: &mut [f64], dims: &[usize]) -> enums::Value {
+        apply(data, dims, &super::mixed_radix::backward)
+    }
+
+    pub fn inverse(data: &mut [f64], dims: &[usize]) -> enums::Value {
+        apply(data, dims, &super::mixed_radix::inverse)
+    }
+}
@@ -3,6 +3,7 @@
 //
 
 use ffi;
+use enums::GSLResult;
 
 /// Compute the covariance matrix cov = inv (J^T J) by QRP^T decomposition of J
 pub fn covar(J: &::MatrixF64, epsrel: f64, covar: &mut ::MatrixF64) -> ::Value {
@@ -17,4 +18,78 @@ pub fn test_delta(dx: &::VectorF64, x: &::VectorF64, epsabs: f64, epsrel: f64) -
 pub fn gradient(J: &::MatrixF64, f: &::VectorF64, g: &mut ::VectorF64) -> ::Value {
     unsafe { ffi::gsl_multifit_gradient(ffi::FFI::unwrap(J),
         ffi::FFI::unwrap(f), ffi::FFI::unwrap(g)) }
-}
\ No newline at end of file
+}
+
+/// This function uses the best-fit multilinear regression coefficients c and their covariance matrix cov to compute the fitted function
+/// value y and its standard deviation y_err for the model y = x.c at the point x. It returns (y, y_err).
+pub fn linear_est(x: &::VectorF64, c: &::VectorF64, cov: &::MatrixF64) -> GSLResult<(f64, f64)> {
+    let mut y = 0f64;
+    let mut y_err = 0f64;
+
+    GSLResult::from(unsafe { ffi::gsl_multifit_linear_est(ffi::FFI::unwrap(x), ffi::FFI::unwrap(c), ffi::FFI::unwrap(cov), &mut y,
+        &mut y_err) })?;
+    Ok((y, y_err))
+}
+
+/// This function computes the vector of residuals r = y - X c for the observations y, coefficients c and matrix of predictor
+/// variables X.
+pub fn linear_residuals(X: &::MatrixF64, y: &::VectorF64, c: &::VectorF64, r: &mut ::VectorF64) -> GSLResult<()> {
+    GSLResult::from(unsafe { ffi::gsl_multifit_linear_residuals(ffi::FFI::unwrap(X), ffi::FFI::unwrap(y), ffi::FFI::unwrap(c),
+        ffi::FFI::unwrap(r)) })
+}
+
+/// This function factors the m-by-p regularization matrix L into a form needed for the standard form transformation
+/// `MultifitLinearWorkspace::linear_stdform2`. On output, L is replaced by its QR (if m >= p) or LQ (if m < p) decomposition, and tau
+/// must have length MIN(m,p).
+pub fn linear_L_decomp(L: &mut ::MatrixF64, tau: &mut ::VectorF64) -> GSLResult<()> {
+    GSLResult::from(unsafe { ffi::gsl_multifit_linear_L_decomp(ffi::FFI::unwrap(L), ffi::FFI::unwrap(tau)) })
+}
+
+/// This function computes the (p-k)-by-p discrete approximation of the k-th derivative operator L_k, suitable as a regularization
+/// matrix which smooths the solution. L_0 is the identity matrix.
+pub fn linear_Lk(p: usize, k: usize, L: &mut ::MatrixF64) -> GSLResult<()> {
+    GSLResult::from(unsafe { ffi::gsl_multifit_linear_Lk(p, k, ffi::FFI::unwrap(L)) })
+}
+
+/// This function fills reg_param with regularization parameters sampled logarithmically between smin and smax, which are usually the
+/// smallest and largest singular values of the standard form matrix.
+pub fn linear_lreg(smin: f64, smax: f64, reg_param: &mut ::VectorF64) -> GSLResult<()> {
+    GSLResult::from(unsafe { ffi::gsl_multifit_linear_lreg(smin, smax, ffi::FFI::unwrap(reg_param)) })
+}
+
+/// This function returns the index of the corner of the L-curve given by the residual norms rho and solution norms eta, computed by
+/// `MultifitLinearWorkspace::linear_lcurve`. The corner is the point of maximum curvature, found by fitting circles through
+/// consecutive triples of points. The matching regularization parameter is reg_param[idx].
+pub fn linear_lcorner(rho: &::VectorF64, eta: &::VectorF64) -> GSLResult<usize> {
+    let mut idx = 0usize;
+
+    GSLResult::from(unsafe { ffi::gsl_multifit_linear_lcorner(ffi::FFI::unwrap(rho), ffi::FFI::unwrap(eta), &mut idx) })?;
+    Ok(idx)
+}
+
+/// This function returns the index of the corner of the L-curve like `linear_lcorner`, but uses the curve (\lambda^2, ||c||^2) instead,
+/// which avoids the numerical difficulties of the logarithmic scale. The matching regularization parameter is reg_param[idx].
+pub fn linear_lcorner2(reg_param: &::VectorF64, eta: &::VectorF64) -> GSLResult<usize> {
+    let mut idx = 0usize;
+
+    GSLResult::from(unsafe { ffi::gsl_multifit_linear_lcorner2(ffi::FFI::unwrap(reg_param), ffi::FFI::unwrap(eta), &mut idx) })?;
+    Ok(idx)
+}
+
+/// This function uses the best-fit robust regression coefficients c and their estimated covariance matrix cov to calculate the fitted
+/// function value y and its standard deviation y_err for the model y = x.c at the point x. It returns (y, y_err).
+pub fn robust_est(x: &::VectorF64, c: &::VectorF64, cov: &::MatrixF64) -> GSLResult<(f64, f64)> {
+    let mut y = 0f64;
+    let mut y_err = 0f64;
+
+    GSLResult::from(unsafe { ffi::gsl_multifit_robust_est(ffi::FFI::unwrap(x), ffi::FFI::unwrap(c), ffi::FFI::unwrap(cov), &mut y,
+        &mut y_err) })?;
+    Ok((y, y_err))
+}
+
+/// This function computes the covariance matrix of the best-fit parameters of a nonlinear least-squares fit, covar = (J^T J)^{-1},
+/// using the Jacobian matrix J at the solution, given by `MultifitNlinearWorkspace::jac`. Columns of the R factor of the QR
+/// decomposition of J with |R_kk| <= epsrel |R_11| are considered linearly dependent and are excluded from the covariance matrix.
+pub fn nlinear_covar(J: &::MatrixF64, epsrel: f64, covar: &mut ::MatrixF64) -> GSLResult<()> {
+    GSLResult::from(unsafe { ffi::gsl_multifit_nlinear_covar(ffi::FFI::unwrap(J), epsrel, ffi::FFI::unwrap(covar)) })
+}
@@ -0,0 +1,23 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+use ffi;
+use enums;
+
+/// This function tests the norm of the gradient g against the absolute tolerance epsabs. The gradient of a multidimensional function
+/// goes to zero at a minimum. The test returns ::Value::Success if the following condition is achieved,
+///
+/// |g| < epsabs
+///
+/// and returns ::Value::Continue otherwise. A suitable choice of epsabs can be made from the desired accuracy in the function for small
+/// variations in x. The relationship between these quantities is given by \delta f = g \delta x.
+pub fn test_gradient(g: &::VectorF64, epsabs: f64) -> enums::Value {
+    unsafe { ffi::gsl_multimin_test_gradient(ffi::FFI::unwrap(g), epsabs) }
+}
+
+/// This function tests the minimizer specific characteristic size (if applicable to the used minimizer) against absolute tolerance
+/// epsabs. The test returns ::Value::Success if the size is smaller than tolerance, otherwise ::Value::Continue is returned.
+pub fn test_size(size: f64, epsabs: f64) -> enums::Value {
+    unsafe { ffi::gsl_multimin_test_size(size, epsabs) }
+}
@@ -0,0 +1,27 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+use ffi;
+use enums;
+
+/// This function tests for the convergence of the sequence by comparing the last step dx with the absolute error epsabs and relative
+/// error epsrel to the current position x. The test returns ::Value::Success if the following condition is achieved,
+///
+/// |dx_i| < epsabs + epsrel |x_i|
+///
+/// for each component of x and returns ::Value::Continue otherwise.
+pub fn test_delta(dx: &::VectorF64, x: &::VectorF64, epsabs: f64, epsrel: f64) -> enums::Value {
+    unsafe { ffi::gsl_multiroot_test_delta(ffi::FFI::unwrap(dx), ffi::FFI::unwrap(x), epsabs, epsrel) }
+}
+
+/// This function tests the residual value f against the absolute error bound epsabs. The test returns ::Value::Success if the following
+/// condition is achieved,
+///
+/// \sum_i |f_i| < epsabs
+///
+/// and returns ::Value::Continue otherwise. This criterion is suitable for situations where the precise location of the root, x, is
+/// unimportant provided a value can be found where the residual is small enough.
+pub fn test_residual(f: &::VectorF64, epsabs: f64) -> enums::Value {
+    unsafe { ffi::gsl_multiroot_test_residual(ffi::FFI::unwrap(f), epsabs) }
+}
@@ -65,13 +65,36 @@ pub use types::{
     MatrixF64,
     MatrixF32,
     MatrixView,
+    MatrixViewMut,
     MatrixComplexF64,
     MatrixComplexF32,
+    MatrixI32,
+    MatrixU32,
+    MatrixI64,
+    MatrixU8,
     VectorF64,
     VectorF32,
     VectorView,
+    VectorViewMut,
+    VectorViewF32,
+    VectorViewMutF32,
     VectorComplexF64,
     VectorComplexF32,
+    VectorI32,
+    VectorU32,
+    VectorI64,
+    VectorU8,
+    GslVector,
+    GslMatrix,
+    VectorIter,
+    VectorIterMut,
+    MatrixIter,
+    MatrixIterMut,
+    MatrixRows,
+    MatrixColumns,
+    SpMatrixF64,
+    SpLinalgIterSolver,
+    SpLinalgIterSolverType,
     Rng,
     RngType,
     Permutation,
@@ -93,6 +116,19 @@ pub use types::{
     EigenGenVWorkspace,
     FftComplexWaveTable,
     FftComplexWorkspace,
+    FftComplexWaveTableF32,
+    FftComplexWorkspaceF32,
+    FftRealWaveTable,
+    FftHalfComplexWaveTable,
+    FftRealWorkspace,
+    FftRealWaveTableF32,
+    FftHalfComplexWaveTableF32,
+    FftRealWorkspaceF32,
+    FftPlan,
+    GaussianFilter,
+    MedianFilter,
+    RecursiveMedianFilter,
+    ImpulseFilter,
     Histogram,
     HistogramPdf,
     Histogram2D,
@@ -106,8 +142,17 @@ pub use types::{
     Interp,
     InterpType,
     Spline,
+    Interp2dAccel,
+    Interp2d,
+    Interp2dType,
+    Spline2d,
     Minimizer,
     MinimizerType,
+    MovStatWorkspace,
+    RootFSolver,
+    RootFSolverType,
+    RootFdfSolver,
+    RootFdfSolverType,
     PlainMonteCarlo,
     MiserMonteCarlo,
     VegasMonteCarlo,
@@ -122,6 +167,8 @@ pub use types::{
     ODEiv2Driver,
     QRng,
     QRngType,
+    RStatWorkspace,
+    RStatQuantile,
     LevinUWorkspace,
     LevinUTruncWorkspace,
     Wavelet,
@@ -130,7 +177,32 @@ pub use types::{
     MultiFitFdfSolver,
     MultiFitFunction,
     MultiFitFdfSolverType,
-    MultiFitFunctionFdf
+    MultiFitFunctionFdf,
+    MultifitLinearWorkspace,
+    MultifitNlinearFunction,
+    MultifitNlinearParameters,
+    MultifitNlinearWorkspace,
+    MultifitNlinearType,
+    MultifitNlinearTrs,
+    MultifitNlinearScale,
+    MultifitNlinearSolver,
+    MultifitRobustWorkspace,
+    MultifitRobustType,
+    RobustStats,
+    MultilargeLinearWorkspace,
+    MultilargeLinearType,
+    MultiRootFSolver,
+    MultiRootFSolverType,
+    MultiRootFdfSolver,
+    MultiRootFdfSolverType,
+    MultiRootFunction,
+    MultiRootFunctionFdf,
+    MultiMinFMinimizer,
+    MultiMinFMinimizerType,
+    MultiMinFdfMinimizer,
+    MultiMinFdfMinimizerType,
+    MultiMinFunction,
+    MultiMinFunctionFdf
 };
 
 pub use elementary::Elementary;
@@ -150,6 +222,13 @@ pub use self::enums::{
     VegasMode,
     ODEiv,
     WaveletDirection,
+    SpMatrixType,
+    MovStatEnd,
+    FilterEnd,
+    FilterScale,
+    ConvolutionMode,
+    WindowType,
+    MultifitNlinearFdType,
 };
 
 mod ffi;
@@ -192,13 +271,19 @@ pub mod linear_algebra;
 pub mod logarithm;
 pub mod minimizer;
 pub mod multifit;
+pub mod multimin;
+pub mod multiroot;
 pub mod numerical_differentiation;
 pub mod physical_constant;
 pub mod polynomials;
 pub mod pow;
 pub mod power;
 pub mod psi;
+pub mod roots;
+pub mod signal;
+pub mod siman;
 pub mod sort;
+pub mod spblas;
 pub mod statistics;
 pub mod synchrotron;
 pub mod transport;
@@ -209,6 +294,7 @@ pub mod zeta;
 
 pub type comparison_fn<T> = fn(a: &T, b: &T) -> i32;
 pub type function<T> = fn(x: f64, p: &mut T) -> f64;
+pub type fdf_function<T> = fn(x: f64, p: &mut T, y: &mut f64, dy: &mut f64);
 pub type integration_function<T> = fn(f: ::function<T>, arg: &mut T, a: f64, b: f64, result: &mut f64, abserr: &mut f64, resabs: &mut f64, resasc: &mut f64);
 pub type monte_function<T> = fn(x: &mut [f64], params: &mut T) -> f64;
 pub type select_function<T, U> = fn(ntuple_data: &mut T, params: &mut U) -> bool;
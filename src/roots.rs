@@ -0,0 +1,46 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+use ffi;
+use enums;
+
+/// This function tests for the convergence of the interval [x_lower, x_upper] with absolute error epsabs and relative error epsrel. The
+/// test returns ::Value::Success if the following condition is achieved,
+///
+/// |a - b| < epsabs + epsrel min(|a|,|b|)
+///
+/// when the interval x = [a,b] does not include the origin. If the interval includes the origin then \min(|a|,|b|) is replaced by zero (
+/// which is the minimum value of |x| over the interval). This ensures that the relative error is accurately estimated for roots close to
+/// the origin.
+///
+/// This condition on the interval also implies that any estimate of the root r in the interval satisfies the same condition with respect
+/// to the true root r^*,
+///
+/// |r - r^*| < epsabs + epsrel r^*
+///
+/// assuming that the true root r^* is contained within the interval.
+pub fn test_interval(x_lower: f64, x_upper: f64, epsabs: f64, epsrel: f64) -> enums::Value {
+    unsafe { ffi::gsl_root_test_interval(x_lower, x_upper, epsabs, epsrel) }
+}
+
+/// This function tests for the convergence of the sequence ..., x0, x1 with absolute error epsabs and relative error epsrel. The test
+/// returns ::Value::Success if the following condition is achieved,
+///
+/// |x_1 - x_0| < epsabs + epsrel |x_1|
+///
+/// and returns ::Value::Continue otherwise.
+pub fn test_delta(x1: f64, x0: f64, epsabs: f64, epsrel: f64) -> enums::Value {
+    unsafe { ffi::gsl_root_test_delta(x1, x0, epsabs, epsrel) }
+}
+
+/// This function tests the residual value f against the absolute error bound epsabs. The test returns ::Value::Success if the following
+/// condition is achieved,
+///
+/// |f| < epsabs
+///
+/// and returns ::Value::Continue otherwise. This criterion is suitable for situations where the precise location of the root, x, is
+/// unimportant provided a value can be found where the residual, |f(x)|, is small enough.
+pub fn test_residual(f: f64, epsabs: f64) -> enums::Value {
+    unsafe { ffi::gsl_root_test_residual(f, epsabs) }
+}
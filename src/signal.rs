@@ -0,0 +1,178 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+/*!
+#Signal Processing
+
+This module provides common signal processing operations built on top of the mixed-radix FFT routines, so that sequences of any length
+can be processed without zero padding to a power of two.
+
+##Convolution and correlation
+
+The discrete linear convolution of two sequences a (of length n) and b (of length m) is
+
+(a * b)_k = \sum_j a_j b_{k-j}
+
+for k = 0, ..., n + m - 2. It is computed by multiplying the Fourier transforms of both sequences, zero padded to the length of the
+result, which takes O((n + m) \log(n + m)) operations instead of O(n m). The cross-correlation of a with v is
+
+c_k = \sum_j a_{j+k} v_j
+
+which is the convolution of a with the reversed sequence v. The ConvolutionMode enum selects which part of the result is returned.
+
+##Spectral density estimation
+
+The power spectral density of a signal is estimated with Welch's method: the signal is split into overlapping segments, each segment
+is multiplied by a tapering window, and the periodograms of the segments are averaged. Averaging reduces the variance of the estimate,
+at the cost of the frequency resolution which is set by the segment length.
+
+##References and Further Reading
+
+P. D. Welch. The use of fast Fourier transform for the estimation of power spectra: A method based on time averaging over short,
+modified periodograms, IEEE Transactions on Audio and Electroacoustics, Volume 15, Number 2, 1967, p. 70-73.
+!*/
+
+use ffi;
+use enums::GSLResult;
+use types::{ComplexF64, FftPlan};
+
+// Linear convolution of a and b, of length a.len() + b.len() - 1.
+fn full_convolution(plan: &mut FftPlan, a: &[f64], b: &[f64]) -> GSLResult<Vec<f64>> {
+    let n = a.len() + b.len() - 1;
+    let mut pa = vec![0f64; n];
+    let mut pb = vec![0f64; n];
+
+    pa[..a.len()].copy_from_slice(a);
+    pb[..b.len()].copy_from_slice(b);
+
+    let fa = plan.forward_real(&pa)?;
+    let fb = plan.forward_real(&pb)?;
+    let product: Vec<ComplexF64> = fa.iter().zip(fb.iter()).map(|(x, y)| x.mul(y)).collect();
+
+    plan.inverse_real(&product)
+}
+
+/// This function returns the discrete linear convolution of a and b. The part of the full convolution which is returned is given by
+/// mode. Both sequences must be non-empty, otherwise the error ::Value::Inval is returned.
+pub fn convolve(a: &[f64], b: &[f64], mode: ::ConvolutionMode) -> GSLResult<Vec<f64>> {
+    if a.is_empty() || b.is_empty() {
+        rgsl_error!("sequences must be non-empty", ::Value::Inval);
+        return Err(::Value::Inval);
+    }
+    let mut plan = FftPlan::new();
+    let full = full_convolution(&mut plan, a, b)?;
+    let (long, short) = if a.len() >= b.len() { (a.len(), b.len()) } else { (b.len(), a.len()) };
+
+    Ok(match mode {
+        ::ConvolutionMode::Full => full,
+        ::ConvolutionMode::Same => {
+            let start = (full.len() - long) / 2;
+
+            full[start..start + long].to_vec()
+        }
+        ::ConvolutionMode::Valid => full[short - 1..long].to_vec()
+    })
+}
+
+/// This function returns the cross-correlation c_k = \sum_j a_{j+k} v_j of a and v, computed as the convolution of a with the reversed
+/// sequence v. In Full mode, c[i] holds the correlation at lag k = i - (v.len() - 1). The part of the result which is returned is given
+/// by mode. Both sequences must be non-empty, otherwise the error ::Value::Inval is returned.
+pub fn correlate(a: &[f64], v: &[f64], mode: ::ConvolutionMode) -> GSLResult<Vec<f64>> {
+    let reversed: Vec<f64> = v.iter().rev().cloned().collect();
+
+    convolve(a, &reversed, mode)
+}
+
+/// This function returns the autocorrelation r_k = \sum_j x_j x_{j+k} of x for the lags k = 0, ..., n - 1. The result is not
+/// normalized, divide it by r_0 to get autocorrelation coefficients.
+pub fn autocorrelation(x: &[f64]) -> GSLResult<Vec<f64>> {
+    let n = x.len();
+
+    if n == 0 {
+        return Ok(Vec::new());
+    }
+    // zero padding to 2n - 1 avoids the wrap-around of the circular correlation
+    let mut padded = vec![0f64; 2 * n - 1];
+    let mut plan = FftPlan::new();
+
+    padded[..n].copy_from_slice(x);
+
+    let power: Vec<ComplexF64> = plan.forward_real(&padded)?.iter().map(|z| ComplexF64::rect(z.abs2(), 0f64)).collect();
+    let mut r = plan.inverse_real(&power)?;
+
+    r.truncate(n);
+    Ok(r)
+}
+
+/// This function returns the periodic window of length n of the given type, as used for spectral estimation. The periodic form is the
+/// symmetric window of length n + 1 with its last point dropped, so that the window tiles exactly when segments are overlapped.
+///
+/// Hann: w_k = 0.5 - 0.5 \cos(2\pi k/n)
+///
+/// Hamming: w_k = 0.54 - 0.46 \cos(2\pi k/n)
+///
+/// Blackman: w_k = 0.42 - 0.5 \cos(2\pi k/n) + 0.08 \cos(4\pi k/n)
+pub fn window(t: ::WindowType, n: usize) -> Vec<f64> {
+    let w = 2f64 * ::std::f64::consts::PI / n as f64;
+
+    (0..n).map(|k| {
+        let x = w * k as f64;
+
+        match t {
+            ::WindowType::Hann => 0.5f64 - 0.5f64 * x.cos(),
+            ::WindowType::Hamming => 0.54f64 - 0.46f64 * x.cos(),
+            ::WindowType::Blackman => 0.42f64 - 0.5f64 * x.cos() + 0.08f64 * (2f64 * x).cos()
+        }
+    }).collect()
+}
+
+/// This function estimates the one-sided power spectral density of the signal x sampled at the frequency fs, using Welch's method.
+/// The signal is split into segments of segment_len samples, consecutive segments sharing overlap samples, and each segment is
+/// tapered by the window t. The segments are not detrended.
+///
+/// The function returns the frequencies k fs / segment_len for k = 0, ..., segment_len / 2, and the density at each of them, in units
+/// of x^2 per unit of fs. The error ::Value::Inval is returned if segment_len is zero or larger than x, or if overlap is not smaller
+/// than segment_len.
+pub fn welch_psd(x: &[f64], fs: f64, segment_len: usize, overlap: usize, t: ::WindowType) -> GSLResult<(Vec<f64>, Vec<f64>)> {
+    if segment_len == 0 || segment_len > x.len() {
+        rgsl_error!("segment length must be between 1 and the signal length", ::Value::Inval);
+        return Err(::Value::Inval);
+    }
+    if overlap >= segment_len {
+        rgsl_error!("overlap must be smaller than the segment length", ::Value::Inval);
+        return Err(::Value::Inval);
+    }
+    let w = window(t, segment_len);
+    let scale = 1f64 / (fs * w.iter().map(|v| v * v).sum::<f64>());
+    let step = segment_len - overlap;
+    let nfreq = segment_len / 2 + 1;
+    let mut plan = FftPlan::new();
+    let mut psd = vec![0f64; nfreq];
+    let mut segments = 0usize;
+    let mut start = 0usize;
+    let mut segment = vec![0f64; segment_len];
+
+    while start + segment_len <= x.len() {
+        for k in 0..segment_len {
+            segment[k] = x[start + k] * w[k];
+        }
+        let spectrum = plan.forward_real(&segment)?;
+
+        for k in 0..nfreq {
+            psd[k] += spectrum[k].abs2();
+        }
+        segments += 1;
+        start += step;
+    }
+
+    for k in 0..nfreq {
+        // the energy of the negative frequencies is folded onto the positive ones, except for the zero and Nyquist frequencies
+        let fold = if k == 0 || (segment_len % 2 == 0 && k == segment_len / 2) { 1f64 } else { 2f64 };
+
+        psd[k] *= fold * scale / segments as f64;
+    }
+    let freqs = (0..nfreq).map(|k| k as f64 * fs / segment_len as f64).collect();
+
+    Ok((freqs, psd))
+}
@@ -0,0 +1,145 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+/*!
+#Simulated Annealing
+
+Stochastic search techniques are used when the structure of a space is not well understood or is not smooth, so that techniques like
+Newton's method (which requires calculating Jacobian derivative matrices) cannot be used. In particular, these techniques are frequently
+used to solve combinatorial optimization problems, such as the traveling salesman problem.
+
+The goal is to find a point in the space at which a real valued energy function (or cost function) is minimized. Simulated annealing is a
+minimization technique which has given good results in avoiding local minima; it is based on the idea of taking a random walk through the
+space at successively lower temperatures, where the probability of taking a step is given by a Boltzmann distribution.
+
+The checks for a step are given in the energy closure and the random step is given by the step closure. The configuration is an arbitrary
+Rust type which only needs to be `Clone`, so no manual copy or destruction functions are required.
+
+##Simulated Annealing algorithm
+
+The simulated annealing algorithm takes random walks through the problem space, looking for points with low energies; in these random
+walks, the probability of taking a step is determined by the Boltzmann distribution,
+
+p = e^{-(E_{i+1} - E_i)/(kT)}
+
+if E_{i+1} > E_i, and p = 1 when E_{i+1} <= E_i.
+
+In other words, a step will occur if the new energy is lower. If the new energy is higher, the transition can still occur, and its
+likelihood is proportional to the temperature T and inversely proportional to the energy difference E_{i+1} - E_i.
+
+The temperature T is initially set to a high value, and a random walk is carried out at that temperature. Then the temperature is lowered
+very slightly according to a cooling schedule, for example: T -> T/mu_T where mu_T is slightly greater than 1.
+
+The slight probability of taking a step that gives higher energy is what allows simulated annealing to frequently get out of local minima.
+!*/
+
+use types::Rng;
+
+/// These are the parameters that control a run of `solve`.
+#[derive(Clone, Copy, Debug)]
+pub struct SimanParams {
+    /// The number of points to try for each step. It is kept for compatibility with GSL and is not used by `solve`.
+    pub n_tries: i32,
+    /// The number of iterations at each temperature.
+    pub iters_fixed_t: i32,
+    /// The maximum step size in the random walk.
+    pub step_size: f64,
+    /// The Boltzmann constant of the cooling schedule.
+    pub k: f64,
+    /// The initial temperature.
+    pub t_initial: f64,
+    /// The damping factor for temperature: T is divided by mu_t after each temperature step.
+    pub mu_t: f64,
+    /// The minimum temperature, the run stops once the temperature falls below it.
+    pub t_min: f64
+}
+
+impl SimanParams {
+    pub fn new(n_tries: i32, iters_fixed_t: i32, step_size: f64, k: f64, t_initial: f64, mu_t: f64, t_min: f64) -> SimanParams {
+        SimanParams {
+            n_tries: n_tries,
+            iters_fixed_t: iters_fixed_t,
+            step_size: step_size,
+            k: k,
+            t_initial: t_initial,
+            mu_t: mu_t,
+            t_min: t_min
+        }
+    }
+}
+
+/// This function performs a simulated annealing search through a given space. The space is specified by providing the closures energy,
+/// step and metric. The print closure is optional: if it is given, a debugging log is written to the standard output, with one line per
+/// temperature step and the current configuration printed by the closure. Otherwise no information is printed.
+///
+/// The simulated annealing steps are generated using the random number generator r, which makes a run reproducible for a given seed.
+///
+/// The starting configuration of the system should be given by x0. When the search is over x0 holds the best result found.
+///
+/// The metric closure returns the distance between two configurations. It is not used by the algorithm itself but is part of the problem
+/// definition, as in GSL.
+///
+/// The params structure (described above) controls the run by providing the temperature schedule and other tunable parameters to the
+/// algorithm.
+#[allow(unused_variables)]
+pub fn solve<S: Clone>(r: &Rng, x0: &mut S, energy: &mut FnMut(&S) -> f64, step: &mut FnMut(&Rng, &mut S, f64),
+    metric: &mut FnMut(&S, &S) -> f64, print: Option<&mut FnMut(&S)>, params: &SimanParams) {
+    let mut print = print;
+    let mut x = x0.clone();
+    let mut e = energy(&x);
+
+    let mut best_x = x0.clone();
+    let mut best_e = e;
+
+    let mut t = params.t_initial;
+    let t_factor = 1f64 / params.mu_t;
+
+    let mut n_evals = 1usize;
+    let mut n_iter = 0usize;
+
+    if print.is_some() {
+        println!("#-iter  #-evals   temperature     position   energy     best_energy");
+    }
+
+    loop {
+        for _ in 0..params.iters_fixed_t {
+            let mut new_x = x.clone();
+
+            step(r, &mut new_x, params.step_size);
+            let new_e = energy(&new_x);
+
+            if new_e <= best_e {
+                best_x = new_x.clone();
+                best_e = new_e;
+            }
+
+            n_evals += 1;
+
+            /* now take the crucial step: see if the new point is accepted or not, as determined by the boltzmann probability */
+            if new_e < e {
+                x = new_x;
+                e = new_e;
+            } else if r.uniform() < (-(new_e - e) / (params.k * t)).exp() {
+                x = new_x;
+                e = new_e;
+            }
+        }
+
+        if let Some(ref mut p) = print {
+            print!("{:5}   {:7}  {:12e}", n_iter, n_evals, t);
+            p(&x);
+            println!("  {:12e}  {:12e}", e, best_e);
+        }
+
+        /* apply the cooling schedule to the temperature */
+        t *= t_factor;
+        n_iter += 1;
+        if t < params.t_min {
+            break;
+        }
+    }
+
+    /* at the end, copy the result onto the initial point, so we pass it back to the caller */
+    *x0 = best_x;
+}
@@ -0,0 +1,25 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+/*!
+#Sparse BLAS Support
+
+The Sparse Basic Linear Algebra Subprograms (BLAS) define a set of fundamental operations on vectors and sparse matrices which can be
+used to create optimized higher-level linear algebra functionality. GSL supports a limited number of BLAS operations for sparse matrices.
+!*/
+
+use enums;
+
+/// This function computes the matrix-vector product and sum y = \alpha op(A) x + \beta y, where op(A) = A, A^T for TransA = NoTrans,
+/// Trans. In-place computations are not supported, so x and y must be distinct vectors. The matrix A may be in triplet or compressed
+/// format.
+pub fn dgemv(transA: ::cblas::Transpose, alpha: f64, A: &::types::SpMatrixF64, x: &::types::VectorF64, beta: f64,
+    y: &mut ::types::VectorF64) -> enums::Value {
+    unsafe { ::ffi::gsl_spblas_dgemv(transA, alpha, ::ffi::FFI::unwrap(A), ::ffi::FFI::unwrap(x), beta, ::ffi::FFI::unwrap(y)) }
+}
+
+/// This function computes the sparse matrix-matrix product C = \alpha A B. The matrices must be in compressed format.
+pub fn dgemm(alpha: f64, A: &::types::SpMatrixF64, B: &::types::SpMatrixF64, C: &mut ::types::SpMatrixF64) -> enums::Value {
+    unsafe { ::ffi::gsl_spblas_dgemm(alpha, ::ffi::FFI::unwrap(A), ::ffi::FFI::unwrap(B), ::ffi::FFI::unwrap(C)) }
+}
@@ -4,6 +4,9 @@
 
 use ffi;
 use c_vec::CSlice;
+use enums::GSLResult;
+use std::collections::HashMap;
+use types::ComplexF64;
 
 pub struct FftComplexWaveTable {
     w: *mut ffi::gsl_fft_complex_wavetable,
@@ -97,4 +100,521 @@ impl ffi::FFI<ffi::gsl_fft_complex_workspace> for FftComplexWorkspace {
     fn unwrap(w: &FftComplexWorkspace) -> *mut ffi::gsl_fft_complex_workspace {
         w.w
     }
-}
\ No newline at end of file
+}
+
+pub struct FftComplexWaveTableF32 {
+    w: *mut ffi::gsl_fft_complex_wavetable_float,
+    f: CSlice<usize>
+}
+
+impl FftComplexWaveTableF32 {
+    /// This function prepares a trigonometric lookup table for a single precision complex FFT of length n. See FftComplexWaveTable for
+    /// details, the same wavetable can be used for both forward and backward (or inverse) transforms of a given length.
+    pub fn new(n: usize) -> Option<FftComplexWaveTableF32> {
+        let tmp = unsafe { ffi::gsl_fft_complex_wavetable_float_alloc(n) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            unsafe {
+                Some(FftComplexWaveTableF32 {
+                    w: tmp,
+                    f: CSlice::new((*tmp).factor.as_mut_ptr(), 64usize)
+                })
+            }
+        }
+    }
+
+    pub fn factor<'r>(&'r mut self) -> &'r mut [usize] {
+        self.f.as_mut()
+    }
+}
+
+impl Drop for FftComplexWaveTableF32 {
+    fn drop(&mut self) {
+        unsafe { ffi::gsl_fft_complex_wavetable_float_free(self.w) };
+        self.w = ::std::ptr::null_mut();
+    }
+}
+
+impl ffi::FFI<ffi::gsl_fft_complex_wavetable_float> for FftComplexWaveTableF32 {
+    fn wrap(w: *mut ffi::gsl_fft_complex_wavetable_float) -> FftComplexWaveTableF32 {
+        unsafe {
+            FftComplexWaveTableF32 {
+                w: w,
+                f: CSlice::new((*w).factor.as_mut_ptr(), 64usize)
+            }
+        }
+    }
+
+    fn unwrap(w: &FftComplexWaveTableF32) -> *mut ffi::gsl_fft_complex_wavetable_float {
+        w.w
+    }
+}
+
+pub struct FftComplexWorkspaceF32 {
+    w: *mut ffi::gsl_fft_complex_workspace_float
+}
+
+impl FftComplexWorkspaceF32 {
+    /// This function allocates a workspace for a single precision complex transform of length n.
+    pub fn new(n: usize) -> Option<FftComplexWorkspaceF32> {
+        let tmp = unsafe { ffi::gsl_fft_complex_workspace_float_alloc(n) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(FftComplexWorkspaceF32 {
+                w: tmp
+            })
+        }
+    }
+}
+
+impl Drop for FftComplexWorkspaceF32 {
+    fn drop(&mut self) {
+        unsafe { ffi::gsl_fft_complex_workspace_float_free(self.w) };
+        self.w = ::std::ptr::null_mut();
+    }
+}
+
+impl ffi::FFI<ffi::gsl_fft_complex_workspace_float> for FftComplexWorkspaceF32 {
+    fn wrap(w: *mut ffi::gsl_fft_complex_workspace_float) -> FftComplexWorkspaceF32 {
+        FftComplexWorkspaceF32 {
+            w: w
+        }
+    }
+
+    fn unwrap(w: &FftComplexWorkspaceF32) -> *mut ffi::gsl_fft_complex_workspace_float {
+        w.w
+    }
+}
+
+pub struct FftRealWaveTable {
+    w: *mut ffi::gsl_fft_real_wavetable,
+    f: CSlice<usize>
+}
+
+impl FftRealWaveTable {
+    /// This function prepares a trigonometric lookup table for an FFT of size n real elements. The length n is factorized into a product
+    /// of subtransforms, and the factors and their trigonometric coefficients are stored in the wavetable. The trigonometric coefficients
+    /// are computed using direct calls to sin and cos, for accuracy.
+    ///
+    /// The wavetable structure can be used repeatedly for any transform of the same length. The table is not modified by calls to any of
+    /// the other FFT functions. The appropriate type of wavetable must be used for forward real or inverse half-complex transforms.
+    pub fn new(n: usize) -> Option<FftRealWaveTable> {
+        let tmp = unsafe { ffi::gsl_fft_real_wavetable_alloc(n) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            unsafe {
+                Some(FftRealWaveTable {
+                    w: tmp,
+                    f: CSlice::new((*tmp).factor.as_mut_ptr(), 64usize)
+                })
+            }
+        }
+    }
+
+    pub fn factor<'r>(&'r mut self) -> &'r mut [usize] {
+        self.f.as_mut()
+    }
+}
+
+impl Drop for FftRealWaveTable {
+    fn drop(&mut self) {
+        unsafe { ffi::gsl_fft_real_wavetable_free(self.w) };
+        self.w = ::std::ptr::null_mut();
+    }
+}
+
+impl ffi::FFI<ffi::gsl_fft_real_wavetable> for FftRealWaveTable {
+    fn wrap(w: *mut ffi::gsl_fft_real_wavetable) -> FftRealWaveTable {
+        unsafe {
+            FftRealWaveTable {
+                w: w,
+                f: CSlice::new((*w).factor.as_mut_ptr(), 64usize)
+            }
+        }
+    }
+
+    fn unwrap(w: &FftRealWaveTable) -> *mut ffi::gsl_fft_real_wavetable {
+        w.w
+    }
+}
+
+pub struct FftHalfComplexWaveTable {
+    w: *mut ffi::gsl_fft_halfcomplex_wavetable,
+    f: CSlice<usize>
+}
+
+impl FftHalfComplexWaveTable {
+    /// This function prepares a trigonometric lookup table for an inverse FFT of size n half-complex elements. The length n is factorized into a product
+    /// of subtransforms, and the factors and their trigonometric coefficients are stored in the wavetable. The trigonometric coefficients
+    /// are computed using direct calls to sin and cos, for accuracy.
+    ///
+    /// The wavetable structure can be used repeatedly for any transform of the same length. The table is not modified by calls to any of
+    /// the other FFT functions. The appropriate type of wavetable must be used for forward real or inverse half-complex transforms.
+    pub fn new(n: usize) -> Option<FftHalfComplexWaveTable> {
+        let tmp = unsafe { ffi::gsl_fft_halfcomplex_wavetable_alloc(n) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            unsafe {
+                Some(FftHalfComplexWaveTable {
+                    w: tmp,
+                    f: CSlice::new((*tmp).factor.as_mut_ptr(), 64usize)
+                })
+            }
+        }
+    }
+
+    pub fn factor<'r>(&'r mut self) -> &'r mut [usize] {
+        self.f.as_mut()
+    }
+}
+
+impl Drop for FftHalfComplexWaveTable {
+    fn drop(&mut self) {
+        unsafe { ffi::gsl_fft_halfcomplex_wavetable_free(self.w) };
+        self.w = ::std::ptr::null_mut();
+    }
+}
+
+impl ffi::FFI<ffi::gsl_fft_halfcomplex_wavetable> for FftHalfComplexWaveTable {
+    fn wrap(w: *mut ffi::gsl_fft_halfcomplex_wavetable) -> FftHalfComplexWaveTable {
+        unsafe {
+            FftHalfComplexWaveTable {
+                w: w,
+                f: CSlice::new((*w).factor.as_mut_ptr(), 64usize)
+            }
+        }
+    }
+
+    fn unwrap(w: &FftHalfComplexWaveTable) -> *mut ffi::gsl_fft_halfcomplex_wavetable {
+        w.w
+    }
+}
+
+pub struct FftRealWorkspace {
+    w: *mut ffi::gsl_fft_real_workspace
+}
+
+impl FftRealWorkspace {
+    /// This function allocates a workspace for a real transform of length n. The same workspace can be used for both forward real and
+    /// inverse halfcomplex transforms.
+    pub fn new(n: usize) -> Option<FftRealWorkspace> {
+        let tmp = unsafe { ffi::gsl_fft_real_workspace_alloc(n) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(FftRealWorkspace {
+                w: tmp
+            })
+        }
+    }
+}
+
+impl Drop for FftRealWorkspace {
+    fn drop(&mut self) {
+        unsafe { ffi::gsl_fft_real_workspace_free(self.w) };
+        self.w = ::std::ptr::null_mut();
+    }
+}
+
+impl ffi::FFI<ffi::gsl_fft_real_workspace> for FftRealWorkspace {
+    fn wrap(w: *mut ffi::gsl_fft_real_workspace) -> FftRealWorkspace {
+        FftRealWorkspace {
+            w: w
+        }
+    }
+
+    fn unwrap(w: &FftRealWorkspace) -> *mut ffi::gsl_fft_real_workspace {
+        w.w
+    }
+}
+
+pub struct FftRealWaveTableF32 {
+    w: *mut ffi::gsl_fft_real_wavetable_float,
+    f: CSlice<usize>
+}
+
+impl FftRealWaveTableF32 {
+    /// This function prepares a trigonometric lookup table for a single precision FFT of size n real elements. See FftRealWaveTable for
+    /// details.
+    pub fn new(n: usize) -> Option<FftRealWaveTableF32> {
+        let tmp = unsafe { ffi::gsl_fft_real_wavetable_float_alloc(n) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            unsafe {
+                Some(FftRealWaveTableF32 {
+                    w: tmp,
+                    f: CSlice::new((*tmp).factor.as_mut_ptr(), 64usize)
+                })
+            }
+        }
+    }
+
+    pub fn factor<'r>(&'r mut self) -> &'r mut [usize] {
+        self.f.as_mut()
+    }
+}
+
+impl Drop for FftRealWaveTableF32 {
+    fn drop(&mut self) {
+        unsafe { ffi::gsl_fft_real_wavetable_float_free(self.w) };
+        self.w = ::std::ptr::null_mut();
+    }
+}
+
+impl ffi::FFI<ffi::gsl_fft_real_wavetable_float> for FftRealWaveTableF32 {
+    fn wrap(w: *mut ffi::gsl_fft_real_wavetable_float) -> FftRealWaveTableF32 {
+        unsafe {
+            FftRealWaveTableF32 {
+                w: w,
+                f: CSlice::new((*w).factor.as_mut_ptr(), 64usize)
+            }
+        }
+    }
+
+    fn unwrap(w: &FftRealWaveTableF32) -> *mut ffi::gsl_fft_real_wavetable_float {
+        w.w
+    }
+}
+
+pub struct FftHalfComplexWaveTableF32 {
+    w: *mut ffi::gsl_fft_halfcomplex_wavetable_float,
+    f: CSlice<usize>
+}
+
+impl FftHalfComplexWaveTableF32 {
+    /// This function prepares a trigonometric lookup table for a single precision inverse FFT of size n half-complex elements. See
+    /// FftHalfComplexWaveTable for details.
+    pub fn new(n: usize) -> Option<FftHalfComplexWaveTableF32> {
+        let tmp = unsafe { ffi::gsl_fft_halfcomplex_wavetable_float_alloc(n) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            unsafe {
+                Some(FftHalfComplexWaveTableF32 {
+                    w: tmp,
+                    f: CSlice::new((*tmp).factor.as_mut_ptr(), 64usize)
+                })
+            }
+        }
+    }
+
+    pub fn factor<'r>(&'r mut self) -> &'r mut [usize] {
+        self.f.as_mut()
+    }
+}
+
+impl Drop for FftHalfComplexWaveTableF32 {
+    fn drop(&mut self) {
+        unsafe { ffi::gsl_fft_halfcomplex_wavetable_float_free(self.w) };
+        self.w = ::std::ptr::null_mut();
+    }
+}
+
+impl ffi::FFI<ffi::gsl_fft_halfcomplex_wavetable_float> for FftHalfComplexWaveTableF32 {
+    fn wrap(w: *mut ffi::gsl_fft_halfcomplex_wavetable_float) -> FftHalfComplexWaveTableF32 {
+        unsafe {
+            FftHalfComplexWaveTableF32 {
+                w: w,
+                f: CSlice::new((*w).factor.as_mut_ptr(), 64usize)
+            }
+        }
+    }
+
+    fn unwrap(w: &FftHalfComplexWaveTableF32) -> *mut ffi::gsl_fft_halfcomplex_wavetable_float {
+        w.w
+    }
+}
+
+pub struct FftRealWorkspaceF32 {
+    w: *mut ffi::gsl_fft_real_workspace_float
+}
+
+impl FftRealWorkspaceF32 {
+    /// This function allocates a workspace for a single precision real transform of length n. The same workspace can be used for both
+    /// forward real and inverse halfcomplex transforms.
+    pub fn new(n: usize) -> Option<FftRealWorkspaceF32> {
+        let tmp = unsafe { ffi::gsl_fft_real_workspace_float_alloc(n) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(FftRealWorkspaceF32 {
+                w: tmp
+            })
+        }
+    }
+}
+
+impl Drop for FftRealWorkspaceF32 {
+    fn drop(&mut self) {
+        unsafe { ffi::gsl_fft_real_workspace_float_free(self.w) };
+        self.w = ::std::ptr::null_mut();
+    }
+}
+
+impl ffi::FFI<ffi::gsl_fft_real_workspace_float> for FftRealWorkspaceF32 {
+    fn wrap(w: *mut ffi::gsl_fft_real_workspace_float) -> FftRealWorkspaceF32 {
+        FftRealWorkspaceF32 {
+            w: w
+        }
+    }
+
+    fn unwrap(w: &FftRealWorkspaceF32) -> *mut ffi::gsl_fft_real_workspace_float {
+        w.w
+    }
+}
+
+/// A high-level FFT interface which owns the wavetables and workspaces it needs and returns newly allocated spectra.
+///
+/// The wavetables are computed the first time a given length is transformed and are kept in the plan, so that later transforms of the
+/// same length only pay for the transform itself. Any length is supported, using the mixed-radix routines.
+///
+/// The spectra follow the usual GSL conventions: the forward transform uses a negative exponential, the frequency k is stored at index k
+/// for k < n/2 and the negative frequencies are stored in the second half of the output, see `fft::fftfreq`.
+pub struct FftPlan {
+    complex: HashMap<usize, (FftComplexWaveTable, FftComplexWorkspace)>,
+    real: HashMap<usize, (FftRealWaveTable, FftRealWorkspace)>,
+    halfcomplex: HashMap<usize, FftHalfComplexWaveTable>
+}
+
+impl FftPlan {
+    pub fn new() -> FftPlan {
+        FftPlan {
+            complex: HashMap::new(),
+            real: HashMap::new(),
+            halfcomplex: HashMap::new()
+        }
+    }
+
+    fn complex_tables(&mut self, n: usize) -> GSLResult<&(FftComplexWaveTable, FftComplexWorkspace)> {
+        if !self.complex.contains_key(&n) {
+            let wavetable = match FftComplexWaveTable::new(n) {
+                Some(w) => w,
+                None => return Err(::Value::NoMem)
+            };
+            let work = match FftComplexWorkspace::new(n) {
+                Some(w) => w,
+                None => return Err(::Value::NoMem)
+            };
+            self.complex.insert(n, (wavetable, work));
+        }
+        Ok(&self.complex[&n])
+    }
+
+    fn real_tables(&mut self, n: usize) -> GSLResult<&(FftRealWaveTable, FftRealWorkspace)> {
+        if !self.real.contains_key(&n) {
+            let wavetable = match FftRealWaveTable::new(n) {
+                Some(w) => w,
+                None => return Err(::Value::NoMem)
+            };
+            let work = match FftRealWorkspace::new(n) {
+                Some(w) => w,
+                None => return Err(::Value::NoMem)
+            };
+            self.real.insert(n, (wavetable, work));
+        }
+        Ok(&self.real[&n])
+    }
+
+    fn complex_transform(&mut self, data: &[ComplexF64], sign: ::FftDirection) -> GSLResult<Vec<ComplexF64>> {
+        let n = data.len();
+        let mut out = data.to_vec();
+
+        if n == 0 {
+            return Ok(out);
+        }
+        {
+            let &(ref wavetable, ref work) = self.complex_tables(n)?;
+            let packed = unsafe { ::std::slice::from_raw_parts_mut(out.as_mut_ptr() as *mut f64, 2 * n) };
+
+            GSLResult::from(::fft::mixed_radix::transform(packed, 1, n, wavetable, work, sign))?;
+        }
+        Ok(out)
+    }
+
+    /// This function returns the forward FFT of the complex sequence data.
+    pub fn forward(&mut self, data: &[ComplexF64]) -> GSLResult<Vec<ComplexF64>> {
+        self.complex_transform(data, ::FftDirection::Forward)
+    }
+
+    /// This function returns the backward FFT of the complex sequence data, which is the unscaled version of the inverse FFT.
+    pub fn backward(&mut self, data: &[ComplexF64]) -> GSLResult<Vec<ComplexF64>> {
+        self.complex_transform(data, ::FftDirection::Backward)
+    }
+
+    /// This function returns the inverse FFT of the complex sequence data. The result is scaled by 1/n, so that it recreates the
+    /// sequence given to `forward`.
+    pub fn inverse(&mut self, data: &[ComplexF64]) -> GSLResult<Vec<ComplexF64>> {
+        let scale = 1f64 / data.len() as f64;
+
+        self.complex_transform(data, ::FftDirection::Backward)
+            .map(|v| v.iter().map(|z| z.mul_real(scale)).collect())
+    }
+
+    /// This function returns the forward FFT of the real sequence data, as a full complex spectrum of the same length. The spectrum
+    /// satisfies the half-complex symmetry z_k = z_{n-k}^*.
+    pub fn forward_real(&mut self, data: &[f64]) -> GSLResult<Vec<ComplexF64>> {
+        let n = data.len();
+        let mut tmp = data.to_vec();
+        let mut out = vec![ComplexF64::rect(0f64, 0f64); n];
+
+        if n == 0 {
+            return Ok(out);
+        }
+        {
+            let &(ref wavetable, ref work) = self.real_tables(n)?;
+            let packed = unsafe { ::std::slice::from_raw_parts_mut(out.as_mut_ptr() as *mut f64, 2 * n) };
+
+            GSLResult::from(::fft::real_mixed_radix::transform(&mut tmp, 1, n, wavetable, work))?;
+            GSLResult::from(::fft::real_mixed_radix::halfcomplex_unpack(&tmp, packed, 1, n))?;
+        }
+        Ok(out)
+    }
+
+    /// This function returns the real sequence whose forward FFT is spectrum. Only the half-complex part of the spectrum, the terms k
+    /// with k <= n/2, is used: the other terms are assumed to satisfy the symmetry z_k = z_{n-k}^*. The result is scaled by 1/n, so
+    /// that it recreates the sequence given to `forward_real`.
+    pub fn inverse_real(&mut self, spectrum: &[ComplexF64]) -> GSLResult<Vec<f64>> {
+        let n = spectrum.len();
+        let mut out = vec![0f64; n];
+
+        if n == 0 {
+            return Ok(out);
+        }
+        // pack the spectrum using the FFTPACK half-complex storage convention
+        out[0] = spectrum[0].data[0];
+        for k in 1..(n + 1) / 2 {
+            out[2 * k - 1] = spectrum[k].data[0];
+            out[2 * k] = spectrum[k].data[1];
+        }
+        if n % 2 == 0 {
+            out[n - 1] = spectrum[n / 2].data[0];
+        }
+        if !self.halfcomplex.contains_key(&n) {
+            match FftHalfComplexWaveTable::new(n) {
+                Some(w) => { self.halfcomplex.insert(n, w); }
+                None => return Err(::Value::NoMem)
+            }
+        }
+        self.real_tables(n)?;
+        GSLResult::from(::fft::real_mixed_radix::inverse(&mut out, 1, n, &self.halfcomplex[&n], &self.real[&n].1))?;
+        Ok(out)
+    }
+
+    /// This function returns the power spectrum |z_k|^2 of the real sequence data, for each frequency bin k of its forward FFT.
+    pub fn power_spectrum(&mut self, data: &[f64]) -> GSLResult<Vec<f64>> {
+        self.forward_real(data).map(|v| v.iter().map(|z| z.abs2()).collect())
+    }
+}
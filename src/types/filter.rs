@@ -0,0 +1,345 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+/*!
+#Digital Filtering
+
+The filters discussed in this chapter are based on the following moving data window which is centered on i-th sample:
+
+W_i^H = \left\{ x_{i-H}, \dots, x_i, \dots, x_{i+H} \right\}
+
+Here, H is a non-negative integer called the window half-length, which represents the number of samples before and after sample i. The
+total window length is K = 2 H + 1.
+
+##Handling Endpoints
+
+When processing samples near the ends of the input signal, there will not be enough samples to fill the window W_i^H defined above.
+Therefore the user must specify how to construct the windows near the end points. This is done with the FilterEnd enum:
+
+ * PadZero: inserts zeros into the window near the signal end points.
+ * PadValue: pads the window with the first and last sample in the input signal.
+ * Truncate: no padding is performed, and the windows are simply truncated as the end points are approached.
+
+##Linear Digital Filters
+
+The Gaussian filter convolves the input signal with a Gaussian kernel or window. This filter is often used as a smoothing or noise
+reduction filter. The Gaussian kernel is defined by
+
+G(k) = e^{-\frac{1}{2} \left( \alpha \frac{k}{(K-1)/2} \right)^2} = e^{-k^2/2\sigma^2}
+
+for -(K-1)/2 \le k \le (K-1)/2, and K is the size of the kernel. The parameter \alpha specifies the number of standard deviations \sigma
+desired in the kernel. The derivatives of the Gaussian kernel can also be used, which allows smoothing and differentiating a signal in one
+pass.
+
+##Nonlinear Digital Filters
+
+The nonlinear digital filters described below are based on the window median, which is less sensitive to outliers than the mean.
+
+The standard median filter (SMF) replaces each sample by the median of its window. The recursive median filter (RMF) uses the previous
+filter outputs in the window instead of the input samples, which leads to a root sequence in a single pass.
+
+The impulse detection filter (IDF), also called the Hampel filter when the MAD scale is used, marks a sample x_i as an outlier when
+
+|x_i - m_i| > t S_i
+
+where m_i is the median of the window W_i^H, S_i is a robust estimate of its scale and t is a tuning parameter. Outliers are replaced by
+the window median, while other samples are left unchanged.
+
+The filters operate either on VectorF64 objects, writing into a given output vector, or on slices, returning newly allocated outputs.
+!*/
+
+use ffi;
+use enums::GSLResult;
+
+// Builds a gsl_vector pointing to the elements of the slice, without taking ownership of them.
+fn slice_vector(s: &[f64]) -> ffi::gsl_vector {
+    ffi::gsl_vector {
+        size: s.len(),
+        stride: 1,
+        data: s.as_ptr() as *mut f64,
+        block: ::std::ptr::null_mut(),
+        owner: 0
+    }
+}
+
+fn slice_vector_mut(s: &mut [f64]) -> ffi::gsl_vector {
+    ffi::gsl_vector {
+        size: s.len(),
+        stride: 1,
+        data: s.as_mut_ptr(),
+        block: ::std::ptr::null_mut(),
+        owner: 0
+    }
+}
+
+pub struct GaussianFilter {
+    w: *mut ffi::gsl_filter_gaussian_workspace
+}
+
+impl GaussianFilter {
+    /// This function initializes a workspace for Gaussian filtering using a kernel of size K. Here, H = K / 2. If K is even, it is
+    /// rounded up to the next odd integer to ensure a symmetric window. The size of the workspace is O(K).
+    pub fn new(k: usize) -> Option<GaussianFilter> {
+        let tmp = unsafe { ffi::gsl_filter_gaussian_alloc(k) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(GaussianFilter {
+                w: tmp
+            })
+        }
+    }
+
+    /// This function applies a Gaussian filter parameterized by alpha to the input vector x, storing the output in y. The derivative
+    /// order is specified by order, with 0 corresponding to a Gaussian, 1 corresponding to a first derivative Gaussian, and so on. The
+    /// parameter endtype specifies how the signal end points are handled. It is allowed for x = y for an in-place filter.
+    pub fn apply(&mut self, endtype: ::FilterEnd, alpha: f64, order: usize, x: &::VectorF64, y: &mut ::VectorF64) -> GSLResult<()> {
+        GSLResult::from(unsafe { ffi::gsl_filter_gaussian(endtype, alpha, order, ffi::FFI::unwrap(x), ffi::FFI::unwrap(y), self.w) })
+    }
+
+    /// This function applies a Gaussian filter parameterized by alpha to the samples of x, and returns the filtered signal. The
+    /// derivative order is specified by order, with 0 corresponding to a Gaussian, 1 corresponding to a first derivative Gaussian, and
+    /// so on. The parameter endtype specifies how the signal end points are handled.
+    pub fn apply_slice(&mut self, endtype: ::FilterEnd, alpha: f64, order: usize, x: &[f64]) -> GSLResult<Vec<f64>> {
+        let mut out = vec![0f64; x.len()];
+        let xv = slice_vector(x);
+        let mut yv = slice_vector_mut(&mut out);
+
+        GSLResult::from(unsafe { ffi::gsl_filter_gaussian(endtype, alpha, order, &xv, &mut yv, self.w) })?;
+        Ok(out)
+    }
+
+    /// This function constructs a Gaussian kernel parameterized by alpha and stores the output in kernel. The parameter order specifies
+    /// the derivative order, with 0 corresponding to a Gaussian, 1 corresponding to a first derivative Gaussian, and so on. If normalize
+    /// is set to true, then the kernel will be normalized to sum to one on output. If normalize is set to false, no normalization is
+    /// performed.
+    pub fn kernel(alpha: f64, order: usize, normalize: bool, kernel: &mut ::VectorF64) -> GSLResult<()> {
+        GSLResult::from(unsafe { ffi::gsl_filter_gaussian_kernel(alpha, order, if normalize { 1 } else { 0 }, ffi::FFI::unwrap(kernel)) })
+    }
+}
+
+impl Drop for GaussianFilter {
+    fn drop(&mut self) {
+        unsafe { ffi::gsl_filter_gaussian_free(self.w) };
+        self.w = ::std::ptr::null_mut();
+    }
+}
+
+impl ffi::FFI<ffi::gsl_filter_gaussian_workspace> for GaussianFilter {
+    fn wrap(w: *mut ffi::gsl_filter_gaussian_workspace) -> GaussianFilter {
+        GaussianFilter {
+            w: w
+        }
+    }
+
+    fn unwrap(w: &GaussianFilter) -> *mut ffi::gsl_filter_gaussian_workspace {
+        w.w
+    }
+}
+
+pub struct MedianFilter {
+    w: *mut ffi::gsl_filter_median_workspace
+}
+
+impl MedianFilter {
+    /// This function initializes a workspace for standard median filtering using a symmetric centered moving window of size K. Here,
+    /// H = K / 2. If K is even, it is rounded up to the next odd integer to ensure a symmetric window. The size of the workspace is O(7K).
+    pub fn new(k: usize) -> Option<MedianFilter> {
+        let tmp = unsafe { ffi::gsl_filter_median_alloc(k) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(MedianFilter {
+                w: tmp
+            })
+        }
+    }
+
+    /// This function applies a standard median filter to the input x, storing the output in y. The parameter endtype specifies how the
+    /// signal end points are handled.
+    pub fn apply(&mut self, endtype: ::FilterEnd, x: &::VectorF64, y: &mut ::VectorF64) -> GSLResult<()> {
+        GSLResult::from(unsafe { ffi::gsl_filter_median(endtype, ffi::FFI::unwrap(x), ffi::FFI::unwrap(y), self.w) })
+    }
+
+    /// This function applies a standard median filter to the samples of x, and returns the filtered signal. The parameter endtype
+    /// specifies how the signal end points are handled.
+    pub fn apply_slice(&mut self, endtype: ::FilterEnd, x: &[f64]) -> GSLResult<Vec<f64>> {
+        let mut out = vec![0f64; x.len()];
+        let xv = slice_vector(x);
+        let mut yv = slice_vector_mut(&mut out);
+
+        GSLResult::from(unsafe { ffi::gsl_filter_median(endtype, &xv, &mut yv, self.w) })?;
+        Ok(out)
+    }
+}
+
+impl Drop for MedianFilter {
+    fn drop(&mut self) {
+        unsafe { ffi::gsl_filter_median_free(self.w) };
+        self.w = ::std::ptr::null_mut();
+    }
+}
+
+impl ffi::FFI<ffi::gsl_filter_median_workspace> for MedianFilter {
+    fn wrap(w: *mut ffi::gsl_filter_median_workspace) -> MedianFilter {
+        MedianFilter {
+            w: w
+        }
+    }
+
+    fn unwrap(w: &MedianFilter) -> *mut ffi::gsl_filter_median_workspace {
+        w.w
+    }
+}
+
+pub struct RecursiveMedianFilter {
+    w: *mut ffi::gsl_filter_rmedian_workspace
+}
+
+impl RecursiveMedianFilter {
+    /// This function initializes a workspace for recursive median filtering using a symmetric centered moving window of size K. Here,
+    /// H = K / 2. If K is even, it is rounded up to the next odd integer to ensure a symmetric window. The size of the workspace is O(K).
+    pub fn new(k: usize) -> Option<RecursiveMedianFilter> {
+        let tmp = unsafe { ffi::gsl_filter_rmedian_alloc(k) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(RecursiveMedianFilter {
+                w: tmp
+            })
+        }
+    }
+
+    /// This function applies a recursive median filter to the input x, storing the output in y. The parameter endtype specifies how the
+    /// signal end points are handled.
+    pub fn apply(&mut self, endtype: ::FilterEnd, x: &::VectorF64, y: &mut ::VectorF64) -> GSLResult<()> {
+        GSLResult::from(unsafe { ffi::gsl_filter_rmedian(endtype, ffi::FFI::unwrap(x), ffi::FFI::unwrap(y), self.w) })
+    }
+
+    /// This function applies a recursive median filter to the samples of x, and returns the filtered signal. The parameter endtype
+    /// specifies how the signal end points are handled.
+    pub fn apply_slice(&mut self, endtype: ::FilterEnd, x: &[f64]) -> GSLResult<Vec<f64>> {
+        let mut out = vec![0f64; x.len()];
+        let xv = slice_vector(x);
+        let mut yv = slice_vector_mut(&mut out);
+
+        GSLResult::from(unsafe { ffi::gsl_filter_rmedian(endtype, &xv, &mut yv, self.w) })?;
+        Ok(out)
+    }
+}
+
+impl Drop for RecursiveMedianFilter {
+    fn drop(&mut self) {
+        unsafe { ffi::gsl_filter_rmedian_free(self.w) };
+        self.w = ::std::ptr::null_mut();
+    }
+}
+
+impl ffi::FFI<ffi::gsl_filter_rmedian_workspace> for RecursiveMedianFilter {
+    fn wrap(w: *mut ffi::gsl_filter_rmedian_workspace) -> RecursiveMedianFilter {
+        RecursiveMedianFilter {
+            w: w
+        }
+    }
+
+    fn unwrap(w: &RecursiveMedianFilter) -> *mut ffi::gsl_filter_rmedian_workspace {
+        w.w
+    }
+}
+
+pub struct ImpulseFilter {
+    w: *mut ffi::gsl_filter_impulse_workspace
+}
+
+impl ImpulseFilter {
+    /// This function initializes a workspace for impulse detection filtering using a symmetric moving window of size K. Here, H = K / 2.
+    /// If K is even, it is rounded up to the next odd integer to ensure a symmetric window. The size of the workspace is O(6K).
+    pub fn new(k: usize) -> Option<ImpulseFilter> {
+        let tmp = unsafe { ffi::gsl_filter_impulse_alloc(k) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(ImpulseFilter {
+                w: tmp
+            })
+        }
+    }
+
+    /// This function applies an impulse detection filter to the input vector x, storing the filtered output in y. The tuning parameter t
+    /// is provided in t. The window medians m_i are stored in xmedian and the scale estimates S_i are stored in xsigma on output. The
+    /// scale_type specifies which robust scale estimate is used. The parameter endtype specifies how the signal end points are handled.
+    ///
+    /// The returned vector is the outlier mask: its i-th element is true when sample i has been detected as an outlier.
+    pub fn apply(&mut self, endtype: ::FilterEnd, scale_type: ::FilterScale, t: f64, x: &::VectorF64, y: &mut ::VectorF64,
+        xmedian: &mut ::VectorF64, xsigma: &mut ::VectorF64) -> GSLResult<Vec<bool>> {
+        let n = x.len();
+        let mut ioutlier = vec![0i32; n];
+        let mut ivec = ffi::gsl_vector_int {
+            size: n,
+            stride: 1,
+            data: ioutlier.as_mut_ptr(),
+            block: ::std::ptr::null_mut(),
+            owner: 0
+        };
+        let mut noutlier = 0usize;
+
+        GSLResult::from(unsafe {
+            ffi::gsl_filter_impulse(endtype, scale_type, t, ffi::FFI::unwrap(x), ffi::FFI::unwrap(y), ffi::FFI::unwrap(xmedian),
+                ffi::FFI::unwrap(xsigma), &mut noutlier, &mut ivec, self.w)
+        })?;
+        Ok(ioutlier.iter().map(|&i| i != 0).collect())
+    }
+
+    /// This function applies an impulse detection filter to the samples of x, with the tuning parameter t and the robust scale estimate
+    /// given by scale_type. The parameter endtype specifies how the signal end points are handled.
+    ///
+    /// It returns the filtered signal, in which the outliers are replaced by their window median, together with the outlier mask.
+    pub fn apply_slice(&mut self, endtype: ::FilterEnd, scale_type: ::FilterScale, t: f64, x: &[f64]) -> GSLResult<(Vec<f64>, Vec<bool>)> {
+        let n = x.len();
+        let mut out = vec![0f64; n];
+        let mut xmedian = vec![0f64; n];
+        let mut xsigma = vec![0f64; n];
+        let mut ioutlier = vec![0i32; n];
+        let xv = slice_vector(x);
+        let mut yv = slice_vector_mut(&mut out);
+        let mut mv = slice_vector_mut(&mut xmedian);
+        let mut sv = slice_vector_mut(&mut xsigma);
+        let mut ivec = ffi::gsl_vector_int {
+            size: n,
+            stride: 1,
+            data: ioutlier.as_mut_ptr(),
+            block: ::std::ptr::null_mut(),
+            owner: 0
+        };
+        let mut noutlier = 0usize;
+
+        GSLResult::from(unsafe {
+            ffi::gsl_filter_impulse(endtype, scale_type, t, &xv, &mut yv, &mut mv, &mut sv, &mut noutlier, &mut ivec, self.w)
+        })?;
+        Ok((out, ioutlier.iter().map(|&i| i != 0).collect()))
+    }
+}
+
+impl Drop for ImpulseFilter {
+    fn drop(&mut self) {
+        unsafe { ffi::gsl_filter_impulse_free(self.w) };
+        self.w = ::std::ptr::null_mut();
+    }
+}
+
+impl ffi::FFI<ffi::gsl_filter_impulse_workspace> for ImpulseFilter {
+    fn wrap(w: *mut ffi::gsl_filter_impulse_workspace) -> ImpulseFilter {
+        ImpulseFilter {
+            w: w
+        }
+    }
+
+    fn unwrap(w: &ImpulseFilter) -> *mut ffi::gsl_filter_impulse_workspace {
+        w.w
+    }
+}
@@ -0,0 +1,246 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+/*!
+#Generic vectors and matrices
+
+Each element type of GSL has its own vector and matrix types, like VectorF32 and VectorF64, with the same functions. The GslVector and
+GslMatrix traits gather these common functions, so that code which only needs the basic operations can be written once and used with
+any element type:
+
+```ignore
+fn fill_with_index<V: GslVector<f64>>(v: &mut V) {
+    for i in 0..v.len() {
+        v.set(i, i as f64);
+    }
+}
+```
+
+The type parameter T is the type of the elements, which is given and returned by value, including for complex numbers. The associated
+type Raw is the underlying GSL struct, like gsl_vector for VectorF64 or gsl_matrix_complex_float for MatrixComplexF32.
+
+The BLAS functions which exist for every floating point element type can be called in the same generic way through the blas::generic
+module.
+!*/
+
+use ffi;
+use enums;
+use types::{ComplexF32, ComplexF64, VectorF32, VectorF64, VectorComplexF32, VectorComplexF64, VectorI32, VectorU32, VectorI64, VectorU8,
+    MatrixF32, MatrixF64, MatrixComplexF32, MatrixComplexF64, MatrixI32, MatrixU32, MatrixI64, MatrixU8};
+
+/// The functions shared by the vectors of every element type T.
+pub trait GslVector<T>: Sized {
+    /// The GSL struct of the vector.
+    type Raw;
+
+    /// Creates a new vector of the given size with all elements set to zero.
+    fn new(size: usize) -> Option<Self>;
+    /// Returns the number of elements of the vector.
+    fn len(&self) -> usize;
+    /// Returns the i-th element of the vector.
+    fn get(&self, i: usize) -> T;
+    /// Sets the value of the i-th element of the vector to x.
+    fn set(&mut self, i: usize, x: T) -> &mut Self;
+    /// Sets all the elements of the vector to the value x.
+    fn set_all(&mut self, x: T) -> &mut Self;
+    /// Sets all the elements of the vector to zero.
+    fn set_zero(&mut self) -> &mut Self;
+    /// Copies the elements of the other vector into the self vector. The two vectors must have the same length.
+    fn copy_from(&mut self, other: &Self) -> enums::Value;
+    /// Adds the elements of the other vector to the elements of the self vector. The two vectors must have the same length.
+    fn add(&mut self, other: &Self) -> enums::Value;
+    /// Subtracts the elements of the other vector from the elements of the self vector. The two vectors must have the same length.
+    fn sub(&mut self, other: &Self) -> enums::Value;
+    /// Multiplies the elements of the self vector by the constant factor x.
+    fn scale(&mut self, x: T) -> enums::Value;
+    /// Returns a copy of the vector.
+    fn clone(&self) -> Option<Self>;
+    /// Returns the pointer to the GSL struct of the vector.
+    fn as_raw(&self) -> *mut Self::Raw;
+}
+
+/// The functions shared by the matrices of every element type T.
+pub trait GslMatrix<T>: Sized {
+    /// The GSL struct of the matrix.
+    type Raw;
+
+    /// Creates a new matrix of n1 rows and n2 columns with all elements set to zero.
+    fn new(n1: usize, n2: usize) -> Option<Self>;
+    /// Returns the number of rows of the matrix.
+    fn size1(&self) -> usize;
+    /// Returns the number of columns of the matrix.
+    fn size2(&self) -> usize;
+    /// Returns the (i,j)-th element of the matrix.
+    fn get(&self, i: usize, j: usize) -> T;
+    /// Sets the value of the (i,j)-th element of the matrix to x.
+    fn set(&mut self, i: usize, j: usize, x: T) -> &mut Self;
+    /// Sets all the elements of the matrix to the value x.
+    fn set_all(&mut self, x: T) -> &mut Self;
+    /// Sets all the elements of the matrix to zero.
+    fn set_zero(&mut self) -> &mut Self;
+    /// Copies the elements of the other matrix into the self matrix. The two matrices must have the same size.
+    fn copy_from(&mut self, other: &Self) -> enums::Value;
+    /// Adds the elements of the other matrix to the elements of the self matrix. The two matrices must have the same dimensions.
+    fn add(&mut self, other: &Self) -> enums::Value;
+    /// Subtracts the elements of the other matrix from the elements of the self matrix. The two matrices must have the same dimensions.
+    fn sub(&mut self, other: &Self) -> enums::Value;
+    /// Multiplies the elements of the self matrix by the constant factor x.
+    fn scale(&mut self, x: T) -> enums::Value;
+    /// Returns a copy of the matrix.
+    fn clone(&self) -> Option<Self>;
+    /// Returns the pointer to the GSL struct of the matrix.
+    fn as_raw(&self) -> *mut Self::Raw;
+}
+
+// The complex types take their scalar arguments by reference, the other ones by value.
+macro_rules! arg {
+    (val, $x:expr) => ($x);
+    (ref, $x:expr) => (&$x);
+}
+
+macro_rules! impl_gsl_vector {
+    ($vector:ident, $elem:ty, $raw:ident, $pass:tt) => (
+        impl GslVector<$elem> for $vector {
+            type Raw = ffi::$raw;
+
+            fn new(size: usize) -> Option<$vector> {
+                $vector::new(size)
+            }
+
+            fn len(&self) -> usize {
+                $vector::len(self)
+            }
+
+            fn get(&self, i: usize) -> $elem {
+                $vector::get(self, i)
+            }
+
+            fn set(&mut self, i: usize, x: $elem) -> &mut $vector {
+                $vector::set(self, i, arg!($pass, x))
+            }
+
+            fn set_all(&mut self, x: $elem) -> &mut $vector {
+                $vector::set_all(self, arg!($pass, x))
+            }
+
+            fn set_zero(&mut self) -> &mut $vector {
+                $vector::set_zero(self)
+            }
+
+            fn copy_from(&mut self, other: &$vector) -> enums::Value {
+                $vector::copy_from(self, other)
+            }
+
+            fn add(&mut self, other: &$vector) -> enums::Value {
+                $vector::add(self, other)
+            }
+
+            fn sub(&mut self, other: &$vector) -> enums::Value {
+                $vector::sub(self, other)
+            }
+
+            fn scale(&mut self, x: $elem) -> enums::Value {
+                $vector::scale(self, arg!($pass, x))
+            }
+
+            fn clone(&self) -> Option<$vector> {
+                $vector::clone(self)
+            }
+
+            fn as_raw(&self) -> *mut ffi::$raw {
+                ffi::FFI::unwrap(self)
+            }
+        }
+    );
+}
+
+macro_rules! impl_gsl_matrix {
+    ($matrix:ident, $elem:ty, $raw:ident, $pass:tt) => (
+        impl GslMatrix<$elem> for $matrix {
+            type Raw = ffi::$raw;
+
+            fn new(n1: usize, n2: usize) -> Option<$matrix> {
+                $matrix::new(n1, n2)
+            }
+
+            fn size1(&self) -> usize {
+                let m: *mut ffi::$raw = ffi::FFI::unwrap(self);
+
+                if m.is_null() {
+                    0usize
+                } else {
+                    unsafe { (*m).size1 }
+                }
+            }
+
+            fn size2(&self) -> usize {
+                let m: *mut ffi::$raw = ffi::FFI::unwrap(self);
+
+                if m.is_null() {
+                    0usize
+                } else {
+                    unsafe { (*m).size2 }
+                }
+            }
+
+            fn get(&self, i: usize, j: usize) -> $elem {
+                $matrix::get(self, i, j)
+            }
+
+            fn set(&mut self, i: usize, j: usize, x: $elem) -> &mut $matrix {
+                $matrix::set(self, i, j, arg!($pass, x))
+            }
+
+            fn set_all(&mut self, x: $elem) -> &mut $matrix {
+                $matrix::set_all(self, arg!($pass, x))
+            }
+
+            fn set_zero(&mut self) -> &mut $matrix {
+                $matrix::set_zero(self)
+            }
+
+            fn copy_from(&mut self, other: &$matrix) -> enums::Value {
+                $matrix::copy_from(self, other)
+            }
+
+            fn add(&mut self, other: &$matrix) -> enums::Value {
+                $matrix::add(self, other)
+            }
+
+            fn sub(&mut self, other: &$matrix) -> enums::Value {
+                $matrix::sub(self, other)
+            }
+
+            fn scale(&mut self, x: $elem) -> enums::Value {
+                $matrix::scale(self, arg!($pass, x))
+            }
+
+            fn clone(&self) -> Option<$matrix> {
+                $matrix::clone(self)
+            }
+
+            fn as_raw(&self) -> *mut ffi::$raw {
+                ffi::FFI::unwrap(self)
+            }
+        }
+    );
+}
+
+impl_gsl_vector!(VectorF32, f32, gsl_vector_float, val);
+impl_gsl_vector!(VectorF64, f64, gsl_vector, val);
+impl_gsl_vector!(VectorComplexF32, ComplexF32, gsl_vector_complex_float, ref);
+impl_gsl_vector!(VectorComplexF64, ComplexF64, gsl_vector_complex, ref);
+impl_gsl_vector!(VectorI32, i32, gsl_vector_int, val);
+impl_gsl_vector!(VectorU32, u32, gsl_vector_uint, val);
+impl_gsl_vector!(VectorI64, i64, gsl_vector_long, val);
+impl_gsl_vector!(VectorU8, u8, gsl_vector_uchar, val);
+
+impl_gsl_matrix!(MatrixF32, f32, gsl_matrix_float, val);
+impl_gsl_matrix!(MatrixF64, f64, gsl_matrix, val);
+impl_gsl_matrix!(MatrixComplexF32, ComplexF32, gsl_matrix_complex_float, ref);
+impl_gsl_matrix!(MatrixComplexF64, ComplexF64, gsl_matrix_complex, ref);
+impl_gsl_matrix!(MatrixI32, i32, gsl_matrix_int, val);
+impl_gsl_matrix!(MatrixU32, u32, gsl_matrix_uint, val);
+impl_gsl_matrix!(MatrixI64, i64, gsl_matrix_long, val);
+impl_gsl_matrix!(MatrixU8, u8, gsl_matrix_uchar, val);
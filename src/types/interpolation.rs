@@ -29,6 +29,14 @@ The functions described in the previous sections required the user to supply poi
 functions are equivalent to the corresponding gsl_interp functions but maintain a copy of this data in the gsl_spline object. This removes 
 the need to pass both xa and ya as arguments on each evaluation.
 
+##2D Interpolation
+
+Given a set of x coordinates x_1,...,x_m and a set of y coordinates y_1,...,y_n, each in increasing order, plus a set of function values
+z_{ij} for each grid point (x_i,y_j), the Interp2d and Spline2d objects compute a continuous interpolation function z(x,y) such that
+z(x_i,y_j) = z_{ij}. The function values are stored in row-major order with the x index varying fastest, so that z_{ij} is found at
+za[j * xsize + i]. Equivalently, a MatrixF64 with one row per y coordinate and one column per x coordinate can be used. Each dimension
+keeps its own accelerator, both are grouped in an Interp2dAccel.
+
 ##References and Further Reading
 
 Descriptions of the interpolation algorithms and further references can be found in the following books:
@@ -196,6 +204,24 @@ impl InterpType {
     pub fn akima_periodic() -> InterpType {
         ffi::FFI::wrap(ffi::gsl_interp_akima_periodic as *mut ffi::gsl_interp_type)
     }
+
+    /// Steffen’s method guarantees the monotonicity of the interpolating function between the given data points. Therefore, minima and
+    /// maxima can only occur exactly at the data points, and there can never be spurious oscillations between data points. The
+    /// interpolated function is piecewise cubic in each interval. The resulting curve and its first derivative are guaranteed to be
+    /// continuous, but the second derivative may be discontinuous.
+    pub fn steffen() -> InterpType {
+        ffi::FFI::wrap(unsafe { ffi::gsl_interp_steffen } as *mut ffi::gsl_interp_type)
+    }
+
+    /// Piecewise Cubic Hermite Interpolating Polynomial, with the derivatives at the data points chosen following Fritsch and Carlson so
+    /// that the interpolating function is monotone wherever the data is monotone. Like the Steffen method, it never overshoots the data,
+    /// and the resulting curve and its first derivative are continuous. This type is implemented in Rust and can be used through Interp
+    /// and Spline like any other interpolation type.
+    pub fn pchip() -> InterpType {
+        InterpType {
+            t: &PCHIP.0
+        }
+    }
 }
 
 impl ffi::FFI<ffi::gsl_interp_type> for InterpType {
@@ -296,4 +322,641 @@ impl ffi::FFI<ffi::gsl_spline> for Spline {
     fn unwrap(spline: &Spline) -> *mut ffi::gsl_spline {
         spline.spline
     }
-}
\ No newline at end of file
+}
+
+/// A pair of accelerators for 2D interpolation lookups, one for each dimension.
+#[derive(Clone, Copy)]
+pub struct Interp2dAccel {
+    /// accelerator for the x dimension
+    pub x: InterpAccel,
+    /// accelerator for the y dimension
+    pub y: InterpAccel
+}
+
+impl Interp2dAccel {
+    pub fn new() -> Interp2dAccel {
+        Interp2dAccel {
+            x: InterpAccel::new(),
+            y: InterpAccel::new()
+        }
+    }
+
+    /// This function reinitializes both accelerators. It should be used when switching to a new dataset.
+    pub fn reset(&mut self) {
+        self.x.reset();
+        self.y.reset();
+    }
+}
+
+fn check_grid_size(xa: &[f64], ya: &[f64], za: &[f64]) -> enums::Value {
+    if za.len() != xa.len() * ya.len() {
+        rgsl_error!("za must have xsize * ysize elements", ::Value::BadLen);
+        ::Value::BadLen
+    } else {
+        ::Value::Success
+    }
+}
+
+pub struct Interp2d {
+    interp: *mut ffi::gsl_interp2d
+}
+
+impl Interp2d {
+    /// This function returns a pointer to a newly allocated interpolation object of type T for xsize grid points in the x direction and
+    /// ysize grid points in the y direction.
+    pub fn new(t: &Interp2dType, xsize: usize, ysize: usize) -> Option<Interp2d> {
+        let tmp = unsafe { ffi::gsl_interp2d_alloc(t.t, xsize, ysize) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(Interp2d {
+                interp: tmp
+            })
+        }
+    }
+
+    /// This function initializes the interpolation object interp for the data (xa,ya,za) where xa and ya are the x and y grids and za is
+    /// the array of function values of size xsize * ysize, in row-major order. The interpolation object does not save the data arrays
+    /// xa, ya and za and only stores the static state computed from the data. The xa and ya data arrays are always assumed to be strictly
+    /// ordered, with increasing x,y values; the behavior for other arrangements is not defined.
+    ///
+    /// The arrays given to `set`, `get` and the eval functions are checked against the grid size given to `new`: xa and ya must have
+    /// xsize and ysize elements and za at least xsize * ysize. Otherwise ::Value::BadLen is returned, or NaN by the functions which
+    /// return a value.
+    pub fn init(&self, xa: &[f64], ya: &[f64], za: &[f64]) -> enums::Value {
+        match check_grid_size(xa, ya, za) {
+            ::Value::Success => unsafe { ffi::gsl_interp2d_init(self.interp, xa.as_ptr(), ya.as_ptr(), za.as_ptr(), xa.len(), ya.len()) },
+            err => err
+        }
+    }
+
+    /// This function returns the name of the interpolation type used by interp.
+    pub fn name(&self) -> String {
+        let tmp = unsafe { ffi::gsl_interp2d_name(self.interp) };
+
+        if tmp.is_null() {
+            String::new()
+        } else {
+            unsafe { String::from_utf8_lossy(::std::ffi::CStr::from_ptr(tmp).to_bytes()).to_string() }
+        }
+    }
+
+    /// This function returns the minimum number of points required by the interpolation object interp in each dimension.
+    pub fn min_size(&self) -> u32 {
+        unsafe { ffi::gsl_interp2d_min_size(self.interp) }
+    }
+
+    /// This function sets the value z_{ij} for grid point (i,j) of the array za to z.
+    pub fn set(&self, za: &mut [f64], i: usize, j: usize, z: f64) -> enums::Value {
+        let ret = self.check_za(za);
+
+        if ret != ::Value::Success {
+            return ret;
+        }
+        unsafe { ffi::gsl_interp2d_set(self.interp, za.as_mut_ptr(), i, j, z) }
+    }
+
+    /// This function returns the value z_{ij} for grid point (i,j) stored in the array za.
+    pub fn get(&self, za: &[f64], i: usize, j: usize) -> f64 {
+        if self.check_za(za) != ::Value::Success {
+            return ::std::f64::NAN;
+        }
+        unsafe { ffi::gsl_interp2d_get(self.interp, za.as_ptr(), i, j) }
+    }
+
+    /// This function returns the index corresponding to the grid point (i,j). The index is given by j * xsize + i.
+    pub fn idx(&self, i: usize, j: usize) -> usize {
+        unsafe { ffi::gsl_interp2d_idx(self.interp, i, j) }
+    }
+
+    /// This function returns the interpolated value of z for a given point (x,y), using the interpolation object interp, data arrays xa,
+    /// ya, and za and the accelerators acc. When x is outside the range of xa or y is outside the range of ya, the error code ::Dom is
+    /// returned.
+    pub fn eval(&self, xa: &[f64], ya: &[f64], za: &[f64], x: f64, y: f64, acc: &mut Interp2dAccel) -> f64 {
+        if self.check_data(xa, ya, za) != ::Value::Success {
+            return ::std::f64::NAN;
+        }
+        unsafe { ffi::gsl_interp2d_eval(self.interp, xa.as_ptr(), ya.as_ptr(), za.as_ptr(), x, y, &mut acc.x, &mut acc.y) }
+    }
+
+    /// This function returns the interpolated value of z for a given point (x,y), using the interpolation object interp, data arrays xa,
+    /// ya, and za and the accelerators acc. When x is outside the range of xa or y is outside the range of ya, the error code ::Dom is
+    /// returned.
+    pub fn eval_e(&self, xa: &[f64], ya: &[f64], za: &[f64], x: f64, y: f64, acc: &mut Interp2dAccel, z: &mut f64) -> enums::Value {
+        let ret = self.check_data(xa, ya, za);
+
+        if ret != ::Value::Success {
+            return ret;
+        }
+        unsafe { ffi::gsl_interp2d_eval_e(self.interp, xa.as_ptr(), ya.as_ptr(), za.as_ptr(), x, y, &mut acc.x, &mut acc.y, z) }
+    }
+
+    /// This function returns the interpolated value of z for a given point (x,y), using the interpolation object interp, data arrays xa,
+    /// ya, and za and the accelerators acc. The functions perform no bounds checking, so when x is outside the range of xa or y is outside
+    /// the range of ya, extrapolation is performed.
+    pub fn eval_extrap(&self, xa: &[f64], ya: &[f64], za: &[f64], x: f64, y: f64, acc: &mut Interp2dAccel) -> f64 {
+        if self.check_data(xa, ya, za) != ::Value::Success {
+            return ::std::f64::NAN;
+        }
+        unsafe { ffi::gsl_interp2d_eval_extrap(self.interp, xa.as_ptr(), ya.as_ptr(), za.as_ptr(), x, y, &mut acc.x, &mut acc.y) }
+    }
+
+    /// This function returns the interpolated value of z for a given point (x,y), using the interpolation object interp, data arrays xa,
+    /// ya, and za and the accelerators acc. The functions perform no bounds checking, so when x is outside the range of xa or y is outside
+    /// the range of ya, extrapolation is performed.
+    pub fn eval_extrap_e(&self, xa: &[f64], ya: &[f64], za: &[f64], x: f64, y: f64, acc: &mut Interp2dAccel,
+        z: &mut f64) -> enums::Value {
+        let ret = self.check_data(xa, ya, za);
+
+        if ret != ::Value::Success {
+            return ret;
+        }
+        unsafe { ffi::gsl_interp2d_eval_e_extrap(self.interp, xa.as_ptr(), ya.as_ptr(), za.as_ptr(), x, y, &mut acc.x, &mut acc.y, z) }
+    }
+
+    /// This function returns the interpolated partial derivative d = \partial z / \partial x for a given point (x,y), using the
+    /// interpolation object interp, data arrays xa, ya, and za and the accelerators acc.
+    pub fn eval_deriv_x(&self, xa: &[f64], ya: &[f64], za: &[f64], x: f64, y: f64, acc: &mut Interp2dAccel) -> f64 {
+        if self.check_data(xa, ya, za) != ::Value::Success {
+            return ::std::f64::NAN;
+        }
+        unsafe { ffi::gsl_interp2d_eval_deriv_x(self.interp, xa.as_ptr(), ya.as_ptr(), za.as_ptr(), x, y, &mut acc.x, &mut acc.y) }
+    }
+
+    /// This function returns the interpolated partial derivative d = \partial z / \partial x for a given point (x,y), using the
+    /// interpolation object interp, data arrays xa, ya, and za and the accelerators acc.
+    pub fn eval_deriv_x_e(&self, xa: &[f64], ya: &[f64], za: &[f64], x: f64, y: f64, acc: &mut Interp2dAccel,
+        d: &mut f64) -> enums::Value {
+        let ret = self.check_data(xa, ya, za);
+
+        if ret != ::Value::Success {
+            return ret;
+        }
+        unsafe { ffi::gsl_interp2d_eval_deriv_x_e(self.interp, xa.as_ptr(), ya.as_ptr(), za.as_ptr(), x, y, &mut acc.x, &mut acc.y, d) }
+    }
+
+    /// This function returns the interpolated partial derivative d = \partial z / \partial y for a given point (x,y), using the
+    /// interpolation object interp, data arrays xa, ya, and za and the accelerators acc.
+    pub fn eval_deriv_y(&self, xa: &[f64], ya: &[f64], za: &[f64], x: f64, y: f64, acc: &mut Interp2dAccel) -> f64 {
+        if self.check_data(xa, ya, za) != ::Value::Success {
+            return ::std::f64::NAN;
+        }
+        unsafe { ffi::gsl_interp2d_eval_deriv_y(self.interp, xa.as_ptr(), ya.as_ptr(), za.as_ptr(), x, y, &mut acc.x, &mut acc.y) }
+    }
+
+    /// This function returns the interpolated partial derivative d = \partial z / \partial y for a given point (x,y), using the
+    /// interpolation object interp, data arrays xa, ya, and za and the accelerators acc.
+    pub fn eval_deriv_y_e(&self, xa: &[f64], ya: &[f64], za: &[f64], x: f64, y: f64, acc: &mut Interp2dAccel,
+        d: &mut f64) -> enums::Value {
+        let ret = self.check_data(xa, ya, za);
+
+        if ret != ::Value::Success {
+            return ret;
+        }
+        unsafe { ffi::gsl_interp2d_eval_deriv_y_e(self.interp, xa.as_ptr(), ya.as_ptr(), za.as_ptr(), x, y, &mut acc.x, &mut acc.y, d) }
+    }
+
+    /// This function returns the interpolated partial derivative d = \partial^2 z / \partial x^2 for a given point (x,y), using the
+    /// interpolation object interp, data arrays xa, ya, and za and the accelerators acc.
+    pub fn eval_deriv_xx(&self, xa: &[f64], ya: &[f64], za: &[f64], x: f64, y: f64, acc: &mut Interp2dAccel) -> f64 {
+        if self.check_data(xa, ya, za) != ::Value::Success {
+            return ::std::f64::NAN;
+        }
+        unsafe { ffi::gsl_interp2d_eval_deriv_xx(self.interp, xa.as_ptr(), ya.as_ptr(), za.as_ptr(), x, y, &mut acc.x, &mut acc.y) }
+    }
+
+    /// This function returns the interpolated partial derivative d = \partial^2 z / \partial x^2 for a given point (x,y), using the
+    /// interpolation object interp, data arrays xa, ya, and za and the accelerators acc.
+    pub fn eval_deriv_xx_e(&self, xa: &[f64], ya: &[f64], za: &[f64], x: f64, y: f64, acc: &mut Interp2dAccel,
+        d: &mut f64) -> enums::Value {
+        let ret = self.check_data(xa, ya, za);
+
+        if ret != ::Value::Success {
+            return ret;
+        }
+        unsafe { ffi::gsl_interp2d_eval_deriv_xx_e(self.interp, xa.as_ptr(), ya.as_ptr(), za.as_ptr(), x, y, &mut acc.x, &mut acc.y, d) }
+    }
+
+    /// This function returns the interpolated partial derivative d = \partial^2 z / \partial y^2 for a given point (x,y), using the
+    /// interpolation object interp, data arrays xa, ya, and za and the accelerators acc.
+    pub fn eval_deriv_yy(&self, xa: &[f64], ya: &[f64], za: &[f64], x: f64, y: f64, acc: &mut Interp2dAccel) -> f64 {
+        if self.check_data(xa, ya, za) != ::Value::Success {
+            return ::std::f64::NAN;
+        }
+        unsafe { ffi::gsl_interp2d_eval_deriv_yy(self.interp, xa.as_ptr(), ya.as_ptr(), za.as_ptr(), x, y, &mut acc.x, &mut acc.y) }
+    }
+
+    /// This function returns the interpolated partial derivative d = \partial^2 z / \partial y^2 for a given point (x,y), using the
+    /// interpolation object interp, data arrays xa, ya, and za and the accelerators acc.
+    pub fn eval_deriv_yy_e(&self, xa: &[f64], ya: &[f64], za: &[f64], x: f64, y: f64, acc: &mut Interp2dAccel,
+        d: &mut f64) -> enums::Value {
+        let ret = self.check_data(xa, ya, za);
+
+        if ret != ::Value::Success {
+            return ret;
+        }
+        unsafe { ffi::gsl_interp2d_eval_deriv_yy_e(self.interp, xa.as_ptr(), ya.as_ptr(), za.as_ptr(), x, y, &mut acc.x, &mut acc.y, d) }
+    }
+
+    /// This function returns the interpolated cross derivative d = \partial^2 z / \partial x \partial y for a given point (x,y),
+    /// using the interpolation object interp, data arrays xa, ya, and za and the accelerators acc.
+    pub fn eval_deriv_xy(&self, xa: &[f64], ya: &[f64], za: &[f64], x: f64, y: f64, acc: &mut Interp2dAccel) -> f64 {
+        if self.check_data(xa, ya, za) != ::Value::Success {
+            return ::std::f64::NAN;
+        }
+        unsafe { ffi::gsl_interp2d_eval_deriv_xy(self.interp, xa.as_ptr(), ya.as_ptr(), za.as_ptr(), x, y, &mut acc.x, &mut acc.y) }
+    }
+
+    /// This function returns the interpolated cross derivative d = \partial^2 z / \partial x \partial y for a given point (x,y),
+    /// using the interpolation object interp, data arrays xa, ya, and za and the accelerators acc.
+    pub fn eval_deriv_xy_e(&self, xa: &[f64], ya: &[f64], za: &[f64], x: f64, y: f64, acc: &mut Interp2dAccel,
+        d: &mut f64) -> enums::Value {
+        let ret = self.check_data(xa, ya, za);
+
+        if ret != ::Value::Success {
+            return ret;
+        }
+        unsafe { ffi::gsl_interp2d_eval_deriv_xy_e(self.interp, xa.as_ptr(), ya.as_ptr(), za.as_ptr(), x, y, &mut acc.x, &mut acc.y, d) }
+    }
+
+    // The data arrays are not stored by GSL, so their lengths are checked against the grid size given to new before each call.
+    fn check_za(&self, za: &[f64]) -> enums::Value {
+        let (xsize, ysize) = unsafe { ((*self.interp).xsize, (*self.interp).ysize) };
+
+        if za.len() < xsize * ysize {
+            rgsl_error!("za must have xsize * ysize elements", ::Value::BadLen);
+            ::Value::BadLen
+        } else {
+            ::Value::Success
+        }
+    }
+
+    fn check_data(&self, xa: &[f64], ya: &[f64], za: &[f64]) -> enums::Value {
+        let (xsize, ysize) = unsafe { ((*self.interp).xsize, (*self.interp).ysize) };
+
+        if xa.len() != xsize || ya.len() != ysize {
+            rgsl_error!("xa and ya must have xsize and ysize elements", ::Value::BadLen);
+            ::Value::BadLen
+        } else {
+            self.check_za(za)
+        }
+    }
+}
+
+impl Drop for Interp2d {
+    fn drop(&mut self) {
+        unsafe { ffi::gsl_interp2d_free(self.interp) };
+        self.interp = ::std::ptr::null_mut();
+    }
+}
+
+impl ffi::FFI<ffi::gsl_interp2d> for Interp2d {
+    fn wrap(interp: *mut ffi::gsl_interp2d) -> Interp2d {
+        Interp2d {
+            interp: interp
+        }
+    }
+
+    fn unwrap(interp: &Interp2d) -> *mut ffi::gsl_interp2d {
+        interp.interp
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct Interp2dType {
+    t: *const ffi::gsl_interp2d_type
+}
+
+impl Interp2dType {
+    /// This function returns the minimum number of points required by the interpolation type T in each dimension.
+    pub fn min_size(&self) -> u32 {
+        unsafe { ffi::gsl_interp2d_type_min_size(self.t) }
+    }
+
+    /// Bilinear interpolation. This interpolation method does not require any additional memory.
+    pub fn bilinear() -> Interp2dType {
+        ffi::FFI::wrap(unsafe { ffi::gsl_interp2d_bilinear } as *mut ffi::gsl_interp2d_type)
+    }
+
+    /// Bicubic interpolation.
+    pub fn bicubic() -> Interp2dType {
+        ffi::FFI::wrap(unsafe { ffi::gsl_interp2d_bicubic } as *mut ffi::gsl_interp2d_type)
+    }
+}
+
+impl ffi::FFI<ffi::gsl_interp2d_type> for Interp2dType {
+    fn wrap(t: *mut ffi::gsl_interp2d_type) -> Interp2dType {
+        Interp2dType {
+            t: t
+        }
+    }
+
+    fn unwrap(t: &Interp2dType) -> *mut ffi::gsl_interp2d_type {
+        t.t as *mut ffi::gsl_interp2d_type
+    }
+}
+
+/// general 2D interpolation object, which keeps its own copy of the grid and of the function values
+pub struct Spline2d {
+    spline: *mut ffi::gsl_spline2d
+}
+
+impl Spline2d {
+    /// This function returns a pointer to a newly allocated interpolation object of type T for xsize grid points in the x direction and
+    /// ysize grid points in the y direction.
+    pub fn new(t: &Interp2dType, xsize: usize, ysize: usize) -> Option<Spline2d> {
+        let tmp = unsafe { ffi::gsl_spline2d_alloc(t.t, xsize, ysize) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(Spline2d {
+                spline: tmp
+            })
+        }
+    }
+
+    /// This function initializes the interpolation object for the data (xa,ya,za) where xa and ya are the x and y grids and za is the
+    /// array of function values of size xsize * ysize, in row-major order. The data is copied into the spline object.
+    pub fn init(&self, xa: &[f64], ya: &[f64], za: &[f64]) -> enums::Value {
+        match check_grid_size(xa, ya, za) {
+            ::Value::Success => unsafe { ffi::gsl_spline2d_init(self.spline, xa.as_ptr(), ya.as_ptr(), za.as_ptr(), xa.len(), ya.len()) },
+            err => err
+        }
+    }
+
+    /// This function initializes the interpolation object for the grid (xa,ya) and the function values stored in the matrix za. The
+    /// matrix must have one row for each y coordinate and one column for each x coordinate, so that za(j, i) = z(x_i, y_j).
+    pub fn init_matrix(&self, xa: &[f64], ya: &[f64], za: &::MatrixF64) -> enums::Value {
+        if za.size1() != ya.len() || za.size2() != xa.len() {
+            rgsl_error!("matrix dimensions must match the y and x grid sizes", ::Value::BadLen);
+            return ::Value::BadLen;
+        }
+        let mut z = Vec::with_capacity(xa.len() * ya.len());
+
+        for j in 0..ya.len() {
+            for i in 0..xa.len() {
+                z.push(za.get(j, i));
+            }
+        }
+        self.init(xa, ya, &z)
+    }
+
+    pub fn name(&self) -> String {
+        let tmp = unsafe { ffi::gsl_spline2d_name(self.spline) };
+
+        if tmp.is_null() {
+            String::new()
+        } else {
+            unsafe { String::from_utf8_lossy(::std::ffi::CStr::from_ptr(tmp).to_bytes()).to_string() }
+        }
+    }
+
+    pub fn min_size(&self) -> u32 {
+        unsafe { ffi::gsl_spline2d_min_size(self.spline) }
+    }
+
+    /// This function returns the value z_{ij} for grid point (i,j) stored in the spline object.
+    pub fn get(&self, i: usize, j: usize) -> f64 {
+        unsafe { ffi::gsl_spline2d_get(self.spline, (*self.spline).zarr, i, j) }
+    }
+
+    pub fn eval(&self, x: f64, y: f64, acc: &mut Interp2dAccel) -> f64 {
+        unsafe { ffi::gsl_spline2d_eval(self.spline, x, y, &mut acc.x, &mut acc.y) }
+    }
+
+    pub fn eval_e(&self, x: f64, y: f64, acc: &mut Interp2dAccel, z: &mut f64) -> enums::Value {
+        unsafe { ffi::gsl_spline2d_eval_e(self.spline, x, y, &mut acc.x, &mut acc.y, z) }
+    }
+
+    /// This function performs no bounds checking, so when x or y are outside the grid, extrapolation is performed.
+    pub fn eval_extrap(&self, x: f64, y: f64, acc: &mut Interp2dAccel) -> f64 {
+        unsafe { ffi::gsl_spline2d_eval_extrap(self.spline, x, y, &mut acc.x, &mut acc.y) }
+    }
+
+    /// This function performs no bounds checking, so when x or y are outside the grid, extrapolation is performed.
+    pub fn eval_extrap_e(&self, x: f64, y: f64, acc: &mut Interp2dAccel, z: &mut f64) -> enums::Value {
+        unsafe { ffi::gsl_spline2d_eval_extrap_e(self.spline, x, y, &mut acc.x, &mut acc.y, z) }
+    }
+
+    pub fn eval_deriv_x(&self, x: f64, y: f64, acc: &mut Interp2dAccel) -> f64 {
+        unsafe { ffi::gsl_spline2d_eval_deriv_x(self.spline, x, y, &mut acc.x, &mut acc.y) }
+    }
+
+    pub fn eval_deriv_x_e(&self, x: f64, y: f64, acc: &mut Interp2dAccel, d: &mut f64) -> enums::Value {
+        unsafe { ffi::gsl_spline2d_eval_deriv_x_e(self.spline, x, y, &mut acc.x, &mut acc.y, d) }
+    }
+
+    pub fn eval_deriv_y(&self, x: f64, y: f64, acc: &mut Interp2dAccel) -> f64 {
+        unsafe { ffi::gsl_spline2d_eval_deriv_y(self.spline, x, y, &mut acc.x, &mut acc.y) }
+    }
+
+    pub fn eval_deriv_y_e(&self, x: f64, y: f64, acc: &mut Interp2dAccel, d: &mut f64) -> enums::Value {
+        unsafe { ffi::gsl_spline2d_eval_deriv_y_e(self.spline, x, y, &mut acc.x, &mut acc.y, d) }
+    }
+
+    pub fn eval_deriv_xx(&self, x: f64, y: f64, acc: &mut Interp2dAccel) -> f64 {
+        unsafe { ffi::gsl_spline2d_eval_deriv_xx(self.spline, x, y, &mut acc.x, &mut acc.y) }
+    }
+
+    pub fn eval_deriv_xx_e(&self, x: f64, y: f64, acc: &mut Interp2dAccel, d: &mut f64) -> enums::Value {
+        unsafe { ffi::gsl_spline2d_eval_deriv_xx_e(self.spline, x, y, &mut acc.x, &mut acc.y, d) }
+    }
+
+    pub fn eval_deriv_yy(&self, x: f64, y: f64, acc: &mut Interp2dAccel) -> f64 {
+        unsafe { ffi::gsl_spline2d_eval_deriv_yy(self.spline, x, y, &mut acc.x, &mut acc.y) }
+    }
+
+    pub fn eval_deriv_yy_e(&self, x: f64, y: f64, acc: &mut Interp2dAccel, d: &mut f64) -> enums::Value {
+        unsafe { ffi::gsl_spline2d_eval_deriv_yy_e(self.spline, x, y, &mut acc.x, &mut acc.y, d) }
+    }
+
+    pub fn eval_deriv_xy(&self, x: f64, y: f64, acc: &mut Interp2dAccel) -> f64 {
+        unsafe { ffi::gsl_spline2d_eval_deriv_xy(self.spline, x, y, &mut acc.x, &mut acc.y) }
+    }
+
+    pub fn eval_deriv_xy_e(&self, x: f64, y: f64, acc: &mut Interp2dAccel, d: &mut f64) -> enums::Value {
+        unsafe { ffi::gsl_spline2d_eval_deriv_xy_e(self.spline, x, y, &mut acc.x, &mut acc.y, d) }
+    }
+}
+
+impl Drop for Spline2d {
+    fn drop(&mut self) {
+        unsafe { ffi::gsl_spline2d_free(self.spline) };
+        self.spline = ::std::ptr::null_mut();
+    }
+}
+
+impl ffi::FFI<ffi::gsl_spline2d> for Spline2d {
+    fn wrap(spline: *mut ffi::gsl_spline2d) -> Spline2d {
+        Spline2d {
+            spline: spline
+        }
+    }
+
+    fn unwrap(spline: &Spline2d) -> *mut ffi::gsl_spline2d {
+        spline.spline
+    }
+}
+
+// Fritsch-Carlson PCHIP interpolation, exposed to GSL as a regular gsl_interp_type.
+struct PchipType(ffi::gsl_interp_type);
+
+unsafe impl Sync for PchipType {}
+
+static PCHIP: PchipType = PchipType(ffi::gsl_interp_type {
+    name: b"pchip\0" as *const u8 as *const ::libc::c_char,
+    min_size: 2,
+    alloc: Some(pchip_alloc),
+    init: Some(pchip_init),
+    eval: Some(pchip_eval),
+    eval_deriv: Some(pchip_eval_deriv),
+    eval_deriv2: Some(pchip_eval_deriv2),
+    eval_integ: Some(pchip_eval_integ),
+    free: Some(pchip_free)
+});
+
+struct PchipState {
+    // derivatives at the data points
+    d: Vec<f64>
+}
+
+extern "C" fn pchip_alloc(size: usize) -> *mut ::libc::c_void {
+    Box::into_raw(Box::new(PchipState { d: vec![0f64; size] })) as *mut ::libc::c_void
+}
+
+extern "C" fn pchip_free(vstate: *mut ::libc::c_void) {
+    if !vstate.is_null() {
+        drop(unsafe { Box::from_raw(vstate as *mut PchipState) });
+    }
+}
+
+fn same_sign(a: f64, b: f64) -> bool {
+    (a > 0f64 && b > 0f64) || (a < 0f64 && b < 0f64)
+}
+
+// shape-preserving three-point estimate of the derivative at an end point
+fn pchip_end_slope(h0: f64, h1: f64, del0: f64, del1: f64) -> f64 {
+    let d = ((2f64 * h0 + h1) * del0 - h0 * del1) / (h0 + h1);
+
+    if !same_sign(d, del0) {
+        0f64
+    } else if !same_sign(del0, del1) && d.abs() > 3f64 * del0.abs() {
+        3f64 * del0
+    } else {
+        d
+    }
+}
+
+extern "C" fn pchip_init(vstate: *mut ::libc::c_void, xa: *const f64, ya: *const f64, size: usize) -> enums::Value {
+    let state = unsafe { &mut *(vstate as *mut PchipState) };
+    let xa = unsafe { ::std::slice::from_raw_parts(xa, size) };
+    let ya = unsafe { ::std::slice::from_raw_parts(ya, size) };
+    let d = &mut state.d;
+
+    let h = |i: usize| xa[i + 1] - xa[i];
+    let del = |i: usize| (ya[i + 1] - ya[i]) / (xa[i + 1] - xa[i]);
+
+    if size == 2 {
+        d[0] = del(0);
+        d[1] = del(0);
+        return ::Value::Success;
+    }
+
+    for i in 1..size - 1 {
+        let (del0, del1) = (del(i - 1), del(i));
+
+        if !same_sign(del0, del1) {
+            d[i] = 0f64;
+        } else {
+            // weighted harmonic mean of the neighbouring secant slopes
+            let w1 = 2f64 * h(i) + h(i - 1);
+            let w2 = h(i) + 2f64 * h(i - 1);
+
+            d[i] = (w1 + w2) / (w1 / del0 + w2 / del1);
+        }
+    }
+    d[0] = pchip_end_slope(h(0), h(1), del(0), del(1));
+    d[size - 1] = pchip_end_slope(h(size - 2), h(size - 3), del(size - 2), del(size - 3));
+    ::Value::Success
+}
+
+fn pchip_index(xa: &[f64], x: f64, acc: *mut InterpAccel) -> usize {
+    if acc.is_null() {
+        unsafe { ffi::gsl_interp_bsearch(xa.as_ptr(), x, 0, xa.len() - 1) }
+    } else {
+        unsafe { ffi::gsl_interp_accel_find(acc, xa.as_ptr(), xa.len(), x) }
+    }
+}
+
+// returns (y0, d0, c, b, h): the cubic coefficients of y0 + dx * (d0 + dx * (c + dx * b)) on the interval starting at xa[index], where
+// dx is the offset from xa[index], followed by the interval width h
+fn pchip_coeffs(state: &PchipState, xa: &[f64], ya: &[f64], index: usize) -> (f64, f64, f64, f64, f64) {
+    let h = xa[index + 1] - xa[index];
+    let delta = (ya[index + 1] - ya[index]) / h;
+    let (d0, d1) = (state.d[index], state.d[index + 1]);
+
+    (ya[index], d0, (3f64 * delta - 2f64 * d0 - d1) / h, (d0 + d1 - 2f64 * delta) / (h * h), h)
+}
+
+extern "C" fn pchip_eval(vstate: *const ::libc::c_void, xa: *const f64, ya: *const f64, size: usize, x: f64, acc: *mut InterpAccel,
+    y: *mut f64) -> enums::Value {
+    let state = unsafe { &*(vstate as *const PchipState) };
+    let xa = unsafe { ::std::slice::from_raw_parts(xa, size) };
+    let ya = unsafe { ::std::slice::from_raw_parts(ya, size) };
+    let index = pchip_index(xa, x, acc);
+    let (y0, d0, c, b, _) = pchip_coeffs(state, xa, ya, index);
+    let dx = x - xa[index];
+
+    unsafe { *y = y0 + dx * (d0 + dx * (c + dx * b)) };
+    ::Value::Success
+}
+
+extern "C" fn pchip_eval_deriv(vstate: *const ::libc::c_void, xa: *const f64, ya: *const f64, size: usize, x: f64, acc: *mut InterpAccel,
+    dydx: *mut f64) -> enums::Value {
+    let state = unsafe { &*(vstate as *const PchipState) };
+    let xa = unsafe { ::std::slice::from_raw_parts(xa, size) };
+    let ya = unsafe { ::std::slice::from_raw_parts(ya, size) };
+    let index = pchip_index(xa, x, acc);
+    let (_, d0, c, b, _) = pchip_coeffs(state, xa, ya, index);
+    let dx = x - xa[index];
+
+    unsafe { *dydx = d0 + dx * (2f64 * c + 3f64 * b * dx) };
+    ::Value::Success
+}
+
+extern "C" fn pchip_eval_deriv2(vstate: *const ::libc::c_void, xa: *const f64, ya: *const f64, size: usize, x: f64, acc: *mut InterpAccel,
+    y_pp: *mut f64) -> enums::Value {
+    let state = unsafe { &*(vstate as *const PchipState) };
+    let xa = unsafe { ::std::slice::from_raw_parts(xa, size) };
+    let ya = unsafe { ::std::slice::from_raw_parts(ya, size) };
+    let index = pchip_index(xa, x, acc);
+    let (_, _, c, b, _) = pchip_coeffs(state, xa, ya, index);
+    let dx = x - xa[index];
+
+    unsafe { *y_pp = 2f64 * c + 6f64 * b * dx };
+    ::Value::Success
+}
+
+extern "C" fn pchip_eval_integ(vstate: *const ::libc::c_void, xa: *const f64, ya: *const f64, size: usize, acc: *mut InterpAccel, a: f64,
+    b: f64, result: *mut f64) -> enums::Value {
+    let state = unsafe { &*(vstate as *const PchipState) };
+    let xa = unsafe { ::std::slice::from_raw_parts(xa, size) };
+    let ya = unsafe { ::std::slice::from_raw_parts(ya, size) };
+    let index_a = pchip_index(xa, a, acc);
+    let index_b = pchip_index(xa, b, acc);
+    let mut sum = 0f64;
+
+    // integrate over the intervals, clipping the first and last ones to [a,b]
+    for i in index_a..index_b + 1 {
+        let (y0, d0, c, cb, h) = pchip_coeffs(state, xa, ya, i);
+        let lo = if i == index_a { a - xa[i] } else { 0f64 };
+        let hi = if i == index_b { b - xa[i] } else { h };
+        let integ = |u: f64| u * (y0 + u * (d0 / 2f64 + u * (c / 3f64 + u * cb / 4f64)));
+
+        sum += integ(hi) - integ(lo);
+    }
+    unsafe { *result = sum };
+    ::Value::Success
+}
@@ -42,6 +42,19 @@ pointer block stores the location of the memory block in which the elements of t
 block then the owner field is set to one and the block will be deallocated when the matrix is freed. If the matrix is only a slice of a 
 block owned by another object then the owner field is zero and any underlying block will not be freed.
 
+##Matrix views
+
+A matrix view is a temporary object which can be used to operate on a subset of matrix elements, or on an array of elements. A
+MatrixView borrows the matrix, the vector or the array it was created from, so it cannot outlive it, and only gives read access to the
+elements through `matrix`, which can be passed to any function taking a matrix, like the blas and linear_algebra functions. A
+MatrixViewMut borrows it mutably, and its elements can be modified with the functions of the same name as on matrices (`set`,
+`set_all`, `scale`, indexing, `iter_mut`...). A mutable reference to the underlying matrix is never given, as swapping it with an owned
+matrix would free the elements of the view.
+
+The rows, columns and diagonals of a matrix are returned as vector views by `row`, `column`, `diagonal`, `subdiagonal` and
+`superdiagonal`, and a submatrix as a matrix view by `submatrix`. They borrow the matrix and give direct access to its elements, and
+the `_mut` variants of these functions return views through which the elements can be modified. An out of range index gives None.
+
 ##References and Further Reading
 
 The block, vector and matrix objects in GSL follow the valarray model of C++. A description of this model can be found in the following 
@@ -52,15 +65,36 @@ B. Stroustrup, The C++ Programming Language (3rd Ed), Section 22.4 Vector Arithm
 
 use std::fmt;
 use std::fmt::{Formatter, Debug};
-use types::{VectorF64, VectorF32};
+use std::marker::PhantomData;
+use std::ops::{Index, IndexMut};
+use types::{VectorF64, VectorF32, VectorView, VectorViewMut, MatrixIterMut};
 use ffi;
 use enums;
 
-pub struct MatrixView {
-    mat: ffi::gsl_matrix
+/// A read-only view of the elements of a matrix, of a vector or of an array. It borrows the object it was created from, so that object
+/// cannot be modified while the view exists.
+pub struct MatrixView<'a> {
+    // boxed so that the address given to mat stays valid when the view is moved
+    _m: Box<ffi::gsl_matrix_view>,
+    mat: MatrixF64,
+    phantom: PhantomData<&'a ()>
 }
 
-impl MatrixView {
+impl<'a> MatrixView<'a> {
+    pub(crate) fn new(m: ffi::gsl_matrix_view) -> MatrixView<'a> {
+        let mut m = Box::new(m);
+        let mat = MatrixF64 {
+            mat: &mut m.mat,
+            can_free: false
+        };
+
+        MatrixView {
+            _m: m,
+            mat: mat,
+            phantom: PhantomData
+        }
+    }
+
     /// These functions return a matrix view of a submatrix of the matrix m. The upper-left element of the submatrix is the element (k1,k2)
     /// of the original matrix. The submatrix has n1 rows and n2 columns. The physical number of columns in memory given by tda is unchanged.
     /// Mathematically, the (i,j)-th element of the new matrix is given by,
@@ -78,12 +112,8 @@ impl MatrixView {
     /// is still in use.
     /// 
     /// The function gsl_matrix_const_submatrix is equivalent to gsl_matrix_submatrix but can be used for matrices which are declared const.
-    pub fn from_matrix(m: &MatrixF64, k1: usize, k2: usize, n1: usize, n2: usize) -> MatrixView {
-        unsafe {
-            MatrixView {
-                mat: ffi::gsl_matrix_submatrix(m.mat, k1, k2, n1, n2).mat
-            }
-        }
+    pub fn from_matrix(m: &'a MatrixF64, k1: usize, k2: usize, n1: usize, n2: usize) -> MatrixView<'a> {
+        MatrixView::new(unsafe { ffi::gsl_matrix_submatrix(m.mat, k1, k2, n1, n2) })
     }
 
     /// These functions return a matrix view of the array base. The matrix has n1 rows and n2 columns. The physical number of columns in memory
@@ -98,12 +128,8 @@ impl MatrixView {
     /// the view is still in use.
     /// 
     /// The function gsl_matrix_const_view_array is equivalent to gsl_matrix_view_array but can be used for matrices which are declared const.
-    pub fn from_array(base: &mut [f64], n1: usize, n2: usize) -> MatrixView {
-        unsafe {
-            MatrixView {
-                mat: ffi::gsl_matrix_view_array(base.as_mut_ptr(), n1, n2).mat
-            }
-        }
+    pub fn from_array(base: &'a mut [f64], n1: usize, n2: usize) -> MatrixView<'a> {
+        MatrixView::new(unsafe { ffi::gsl_matrix_view_array(base.as_mut_ptr(), n1, n2) })
     }
 
     /// These functions return a matrix view of the array base with a physical number of columns tda which may differ from the corresponding
@@ -120,12 +146,8 @@ impl MatrixView {
     /// 
     /// The function gsl_matrix_const_view_array_with_tda is equivalent to gsl_matrix_view_array_with_tda but can be used for matrices which
     /// are declared const.
-    pub fn from_array_with_tda(base: &mut [f64], n1: usize, n2: usize, tda: usize) -> MatrixView {
-        unsafe {
-            MatrixView {
-                mat: ffi::gsl_matrix_view_array_with_tda(base.as_mut_ptr(), n1, n2, tda).mat
-            }
-        }
+    pub fn from_array_with_tda(base: &'a mut [f64], n1: usize, n2: usize, tda: usize) -> MatrixView<'a> {
+        MatrixView::new(unsafe { ffi::gsl_matrix_view_array_with_tda(base.as_mut_ptr(), n1, n2, tda) })
     }
 
     /// These functions return a matrix view of the vector v. The matrix has n1 rows and n2 columns. The vector must have unit stride. The
@@ -140,12 +162,8 @@ impl MatrixView {
     /// is still in use.
     /// 
     /// The function gsl_matrix_const_view_vector is equivalent to gsl_matrix_view_vector but can be used for matrices which are declared const.
-    pub fn from_vector(v: &VectorF64, n1: usize, n2: usize) -> MatrixView {
-        unsafe {
-            MatrixView {
-                mat: ffi::gsl_matrix_view_vector(ffi::FFI::unwrap(v), n1, n2).mat
-            }
-        }
+    pub fn from_vector(v: &'a VectorF64, n1: usize, n2: usize) -> MatrixView<'a> {
+        MatrixView::new(unsafe { ffi::gsl_matrix_view_vector(ffi::FFI::unwrap(v), n1, n2) })
     }
 
     /// These functions return a matrix view of the vector v with a physical number of columns tda which may differ from the corresponding
@@ -162,22 +180,198 @@ impl MatrixView {
     /// 
     /// The function gsl_matrix_const_view_vector_with_tda is equivalent to gsl_matrix_view_vector_with_tda but can be used for matrices which
     /// are declared const.
-    pub fn from_vector_with_tda(v: &VectorF64, n1: usize, n2: usize, tda: usize) -> MatrixView {
-        unsafe {
-            MatrixView {
-                mat: ffi::gsl_matrix_view_vector_with_tda(ffi::FFI::unwrap(v), n1, n2, tda).mat
-            }
-        }
+    pub fn from_vector_with_tda(v: &'a VectorF64, n1: usize, n2: usize, tda: usize) -> MatrixView<'a> {
+        MatrixView::new(unsafe { ffi::gsl_matrix_view_vector_with_tda(ffi::FFI::unwrap(v), n1, n2, tda) })
     }
 
-    pub fn matrix(&mut self) -> MatrixF64 {
-        unsafe {
-            MatrixF64 {
-                mat: ::std::mem::transmute(&mut self.mat),
-                can_free: false
-            }
+    /// Returns the view as a matrix, which can be passed to any function taking a matrix. The matrix cannot outlive the view.
+    pub fn matrix(&self) -> &MatrixF64 {
+        &self.mat
+    }
+}
+
+/// A view through which the elements of a matrix, of a vector or of an array can be modified. It borrows mutably the object it was
+/// created from.
+pub struct MatrixViewMut<'a> {
+    // boxed so that the address given to mat stays valid when the view is moved
+    _m: Box<ffi::gsl_matrix_view>,
+    mat: MatrixF64,
+    phantom: PhantomData<&'a mut ()>
+}
+
+impl<'a> MatrixViewMut<'a> {
+    pub(crate) fn new(m: ffi::gsl_matrix_view) -> MatrixViewMut<'a> {
+        let mut m = Box::new(m);
+        let mat = MatrixF64 {
+            mat: &mut m.mat,
+            can_free: false
+        };
+
+        MatrixViewMut {
+            _m: m,
+            mat: mat,
+            phantom: PhantomData
         }
     }
+
+    /// Returns a mutable matrix view of the n1-by-n2 submatrix of m whose upper-left element is (k1,k2), like `MatrixView::from_matrix`.
+    pub fn from_matrix(m: &'a mut MatrixF64, k1: usize, k2: usize, n1: usize, n2: usize) -> MatrixViewMut<'a> {
+        MatrixViewMut::new(unsafe { ffi::gsl_matrix_submatrix(m.mat, k1, k2, n1, n2) })
+    }
+
+    /// Returns a mutable matrix view of the array base with n1 rows and n2 columns, like `MatrixView::from_array`.
+    pub fn from_array(base: &'a mut [f64], n1: usize, n2: usize) -> MatrixViewMut<'a> {
+        MatrixViewMut::new(unsafe { ffi::gsl_matrix_view_array(base.as_mut_ptr(), n1, n2) })
+    }
+
+    /// Returns a mutable matrix view of the array base with n1 rows, n2 columns and a physical number of columns tda, like
+    /// `MatrixView::from_array_with_tda`.
+    pub fn from_array_with_tda(base: &'a mut [f64], n1: usize, n2: usize, tda: usize) -> MatrixViewMut<'a> {
+        MatrixViewMut::new(unsafe { ffi::gsl_matrix_view_array_with_tda(base.as_mut_ptr(), n1, n2, tda) })
+    }
+
+    /// Returns a mutable matrix view of the vector v with n1 rows and n2 columns, like `MatrixView::from_vector`.
+    pub fn from_vector(v: &'a mut VectorF64, n1: usize, n2: usize) -> MatrixViewMut<'a> {
+        MatrixViewMut::new(unsafe { ffi::gsl_matrix_view_vector(ffi::FFI::unwrap(v), n1, n2) })
+    }
+
+    /// Returns a mutable matrix view of the vector v with n1 rows, n2 columns and a physical number of columns tda, like
+    /// `MatrixView::from_vector_with_tda`.
+    pub fn from_vector_with_tda(v: &'a mut VectorF64, n1: usize, n2: usize, tda: usize) -> MatrixViewMut<'a> {
+        MatrixViewMut::new(unsafe { ffi::gsl_matrix_view_vector_with_tda(ffi::FFI::unwrap(v), n1, n2, tda) })
+    }
+
+    /// Returns the view as a matrix, which can be passed to any function taking a matrix. The matrix cannot outlive the view.
+    pub fn matrix(&self) -> &MatrixF64 {
+        &self.mat
+    }
+
+    // The elements are modified through the following functions, which forward to the matrix held by the view, instead of through a
+    // &mut to that matrix: it could be swapped with an owned matrix, which would then be freed when the view is dropped.
+
+    /// This function sets the value of the (i,j)-th element of the view to value. If y or x lies outside the allowed range of 0 to n1-1
+    /// and 0 to n2-1 then the error handler is invoked.
+    pub fn set(&mut self, y: usize, x: usize, value: f64) -> &mut MatrixViewMut<'a> {
+        self.mat.set(y, x, value);
+        self
+    }
+
+    /// This function sets all the elements of the view to the value x.
+    pub fn set_all(&mut self, x: f64) -> &mut MatrixViewMut<'a> {
+        self.mat.set_all(x);
+        self
+    }
+
+    /// This function sets all the elements of the view to zero.
+    pub fn set_zero(&mut self) -> &mut MatrixViewMut<'a> {
+        self.mat.set_zero();
+        self
+    }
+
+    /// This function sets the elements of the view to the corresponding elements of the identity matrix.
+    pub fn set_identity(&mut self) -> &mut MatrixViewMut<'a> {
+        self.mat.set_identity();
+        self
+    }
+
+    /// This function copies the elements of the other matrix into the view. The two must have the same size.
+    pub fn copy_from(&mut self, other: &MatrixF64) -> enums::Value {
+        self.mat.copy_from(other)
+    }
+
+    /// This function exchanges the elements of the view and of the other matrix by copying. The two must have the same size.
+    pub fn swap(&mut self, other: &mut MatrixF64) -> enums::Value {
+        self.mat.swap(other)
+    }
+
+    /// This function copies the elements of the vector v into the y-th row of the view.
+    pub fn set_row(&mut self, y: usize, v: &VectorF64) -> enums::Value {
+        self.mat.set_row(y, v)
+    }
+
+    /// This function copies the elements of the vector v into the x-th column of the view.
+    pub fn set_col(&mut self, x: usize, v: &VectorF64) -> enums::Value {
+        self.mat.set_col(x, v)
+    }
+
+    /// This function exchanges the y1-th and y2-th rows of the view in-place.
+    pub fn swap_rows(&mut self, y1: usize, y2: usize) -> enums::Value {
+        self.mat.swap_rows(y1, y2)
+    }
+
+    /// This function exchanges the x1-th and x2-th columns of the view in-place.
+    pub fn swap_columns(&mut self, x1: usize, x2: usize) -> enums::Value {
+        self.mat.swap_columns(x1, x2)
+    }
+
+    /// This function exchanges the i-th row and j-th column of the view in-place. The view must be square.
+    pub fn swap_row_col(&mut self, i: usize, j: usize) -> enums::Value {
+        self.mat.swap_row_col(i, j)
+    }
+
+    /// This function replaces the view by its transpose by copying the elements in-place. The view must be square.
+    pub fn transpose(&mut self) -> enums::Value {
+        self.mat.transpose()
+    }
+
+    /// This function adds the elements of the other matrix to the elements of the view. The two must have the same dimensions.
+    pub fn add(&mut self, other: &MatrixF64) -> enums::Value {
+        MatrixF64::add(&mut self.mat, other)
+    }
+
+    /// This function subtracts the elements of the other matrix from the elements of the view. The two must have the same dimensions.
+    pub fn sub(&mut self, other: &MatrixF64) -> enums::Value {
+        MatrixF64::sub(&mut self.mat, other)
+    }
+
+    /// This function multiplies the elements of the view by the elements of the other matrix. The two must have the same dimensions.
+    pub fn mul_elements(&mut self, other: &MatrixF64) -> enums::Value {
+        self.mat.mul_elements(other)
+    }
+
+    /// This function divides the elements of the view by the elements of the other matrix. The two must have the same dimensions.
+    pub fn div_elements(&mut self, other: &MatrixF64) -> enums::Value {
+        self.mat.div_elements(other)
+    }
+
+    /// This function multiplies the elements of the view by the constant factor x.
+    pub fn scale(&mut self, x: f64) -> enums::Value {
+        self.mat.scale(x)
+    }
+
+    /// This function adds the constant value x to the elements of the view.
+    pub fn add_constant(&mut self, x: f64) -> enums::Value {
+        self.mat.add_constant(x)
+    }
+
+    /// Returns an iterator over the elements of the view in row-major order, allowing to modify them.
+    pub fn iter_mut<'b>(&'b mut self) -> MatrixIterMut<'b, f64> {
+        self.mat.iter_mut()
+    }
+
+    /// Returns a view of the i-th row of the view through which it can be modified, or None if i is out of range.
+    pub fn row_mut<'b>(&'b mut self, i: usize) -> Option<VectorViewMut<'b>> {
+        self.mat.row_mut(i)
+    }
+
+    /// Returns a view of the j-th column of the view through which it can be modified, or None if j is out of range.
+    pub fn column_mut<'b>(&'b mut self, j: usize) -> Option<VectorViewMut<'b>> {
+        self.mat.column_mut(j)
+    }
+}
+
+impl<'a> Index<(usize, usize)> for MatrixViewMut<'a> {
+    type Output = f64;
+
+    fn index(&self, (i, j): (usize, usize)) -> &f64 {
+        &self.mat[(i, j)]
+    }
+}
+
+impl<'a> IndexMut<(usize, usize)> for MatrixViewMut<'a> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut f64 {
+        &mut self.mat[(i, j)]
+    }
 }
 
 pub struct MatrixF64 {
@@ -214,42 +408,42 @@ impl MatrixF64 {
 
     /// This function sets the value of the (i,j)-th element of the matrix to value.
     /// If y or x lies outside the allowed range of 0 to n1-1 and 0 to n2-1 then the error handler is invoked.
-    pub fn set(&self, y: usize, x: usize, value: f64) -> &MatrixF64 {
+    pub fn set(&mut self, y: usize, x: usize, value: f64) -> &mut MatrixF64 {
         unsafe { ffi::gsl_matrix_set(self.mat, y, x, value) };
         self
     }
 
     /// This function sets all the elements of the matrix to the value x.
-    pub fn set_all(&self, x: f64) -> &MatrixF64 {
+    pub fn set_all(&mut self, x: f64) -> &mut MatrixF64 {
         unsafe { ffi::gsl_matrix_set_all(self.mat, x) };
         self
     }
 
     /// This function sets all the elements of the matrix to zero.
-    pub fn set_zero(&self) -> &MatrixF64 {
+    pub fn set_zero(&mut self) -> &mut MatrixF64 {
         unsafe { ffi::gsl_matrix_set_zero(self.mat) };
         self
     }
 
     /// This function sets the elements of the matrix to the corresponding elements of the identity matrix, m(i,j) = \delta(i,j), i.e. a unit diagonal with all off-diagonal elements zero.
     /// This applies to both square and rectangular matrices.
-    pub fn set_identity(&self) -> &MatrixF64 {
+    pub fn set_identity(&mut self) -> &mut MatrixF64 {
         unsafe { ffi::gsl_matrix_set_identity(self.mat) };
         self
     }
 
     /// This function copies the elements of the other matrix into the self matrix. The two matrices must have the same size.
-    pub fn copy_from(&self, other: &MatrixF64) -> enums::Value {
+    pub fn copy_from(&mut self, other: &MatrixF64) -> enums::Value {
         unsafe { ffi::gsl_matrix_memcpy(self.mat, other.mat) }
     }
 
     /// This function copies the elements of the self matrix into the other matrix. The two matrices must have the same size.
-    pub fn copy_to(&self, other: &MatrixF64) -> enums::Value {
+    pub fn copy_to(&self, other: &mut MatrixF64) -> enums::Value {
         unsafe { ffi::gsl_matrix_memcpy(other.mat, self.mat) }
     }
 
     /// This function exchanges the elements of the matrices self and other by copying. The two matrices must have the same size.
-    pub fn swap(&self, other: &MatrixF64) -> enums::Value {
+    pub fn swap(&mut self, other: &mut MatrixF64) -> enums::Value {
         unsafe { ffi::gsl_matrix_swap(self.mat, other.mat) }
     }
 
@@ -281,29 +475,29 @@ impl MatrixF64 {
 
     /// This function copies the elements of the vector v into the y-th row of the matrix.
     /// The length of the vector must be the same as the length of the row.
-    pub fn set_row(&self, y: usize, v: &VectorF64) -> enums::Value {
+    pub fn set_row(&mut self, y: usize, v: &VectorF64) -> enums::Value {
         unsafe { ffi::gsl_matrix_set_row(self.mat, y, ffi::FFI::unwrap(v)) }
     }
 
     /// This function copies the elements of the vector v into the x-th column of the matrix.
     /// The length of the vector must be the same as the length of the column.
-    pub fn set_col(&self, x: usize, v: &VectorF64) -> enums::Value {
+    pub fn set_col(&mut self, x: usize, v: &VectorF64) -> enums::Value {
         unsafe { ffi::gsl_matrix_set_col(self.mat, x, ffi::FFI::unwrap(v)) }
     }
 
     /// This function exchanges the y1-th and y2-th rows of the matrix in-place.
-    pub fn swap_rows(&self, y1: usize, y2: usize) -> enums::Value {
+    pub fn swap_rows(&mut self, y1: usize, y2: usize) -> enums::Value {
         unsafe { ffi::gsl_matrix_swap_rows(self.mat, y1, y2) }
     }
 
     /// This function exchanges the x1-th and x2-th columns of the matrix in-place.
-    pub fn swap_columns(&self, x1: usize, x2: usize) -> enums::Value {
+    pub fn swap_columns(&mut self, x1: usize, x2: usize) -> enums::Value {
         unsafe { ffi::gsl_matrix_swap_columns(self.mat, x1, x2) }
     }
 
     /// This function exchanges the i-th row and j-th column of the matrix in-place.
     /// The matrix must be square for this operation to be possible.
-    pub fn swap_row_col(&self, i: usize, j: usize) -> enums::Value {
+    pub fn swap_row_col(&mut self, i: usize, j: usize) -> enums::Value {
         unsafe { ffi::gsl_matrix_swap_rowcol(self.mat, i, j) }
     }
 
@@ -323,41 +517,41 @@ impl MatrixF64 {
 
     /// This function replaces the matrix m by its transpose by copying the elements of the matrix in-place.
     /// The matrix must be square for this operation to be possible.
-    pub fn transpose(&self) -> enums::Value {
+    pub fn transpose(&mut self) -> enums::Value {
         unsafe { ffi::gsl_matrix_transpose(self.mat) }
     }
 
     /// This function adds the elements of the other matrix to the elements of the self matrix.
     /// The result self(i,j) <- self(i,j) + other(i,j) is stored in self and other remains unchanged. The two matrices must have the same dimensions.
-    pub fn add(&self, other: &MatrixF64) -> enums::Value {
+    pub fn add(&mut self, other: &MatrixF64) -> enums::Value {
         unsafe { ffi::gsl_matrix_add(self.mat, other.mat) }
     }
 
     /// This function subtracts the elements of the other matrix from the elements of the self matrix.
     /// The result self(i,j) <- self(i,j) - other(i,j) is stored in self and other remains unchanged. The two matrices must have the same dimensions.
-    pub fn sub(&self, other: &MatrixF64) -> enums::Value {
+    pub fn sub(&mut self, other: &MatrixF64) -> enums::Value {
         unsafe { ffi::gsl_matrix_sub(self.mat, other.mat) }
     }
 
     /// This function multiplies the elements of the self matrix by the elements of the other matrix.
     /// The result self(i,j) <- self(i,j) * other(i,j) is stored in self and other remains unchanged. The two matrices must have the same dimensions.
-    pub fn mul_elements(&self, other: &MatrixF64) -> enums::Value {
+    pub fn mul_elements(&mut self, other: &MatrixF64) -> enums::Value {
         unsafe { ffi::gsl_matrix_mul_elements(self.mat, other.mat) }
     }
 
     /// This function divides the elements of the self matrix by the elements of the other matrix.
     /// The result self(i,j) <- self(i,j) / other(i,j) is stored in self and other remains unchanged. The two matrices must have the same dimensions.
-    pub fn div_elements(&self, other: &MatrixF64) -> enums::Value {
+    pub fn div_elements(&mut self, other: &MatrixF64) -> enums::Value {
         unsafe { ffi::gsl_matrix_div_elements(self.mat, other.mat) }
     }
 
     /// This function multiplies the elements of the self matrix by the constant factor x. The result self(i,j) <- x self(i,j) is stored in self.
-    pub fn scale(&self, x: f64) -> enums::Value {
+    pub fn scale(&mut self, x: f64) -> enums::Value {
         unsafe { ffi::gsl_matrix_scale(self.mat, x) }
     }
 
     /// This function adds the constant value x to the elements of the self matrix. The result self(i,j) <- self(i,j) + x is stored in self.
-    pub fn add_constant(&self, x: f64) -> enums::Value {
+    pub fn add_constant(&mut self, x: f64) -> enums::Value {
         unsafe { ffi::gsl_matrix_add_constant(self.mat, x) }
     }
 
@@ -464,13 +658,116 @@ impl MatrixF64 {
         }
     }
 
+    /// Returns a view of the i-th row of the matrix, or None if i is out of range. The view borrows the matrix, so it cannot outlive it.
+    pub fn row<'a>(&'a self, i: usize) -> Option<VectorView<'a>> {
+        if i >= self.size1() {
+            return None;
+        }
+        Some(VectorView::new(unsafe { ffi::gsl_matrix_row(self.mat, i) }))
+    }
+
+    /// Returns a view of the i-th row of the matrix through which it can be modified, or None if i is out of range.
+    pub fn row_mut<'a>(&'a mut self, i: usize) -> Option<VectorViewMut<'a>> {
+        if i >= self.size1() {
+            return None;
+        }
+        Some(VectorViewMut::new(unsafe { ffi::gsl_matrix_row(self.mat, i) }))
+    }
+
+    /// Returns a view of the j-th column of the matrix, or None if j is out of range. The view borrows the matrix, so it cannot outlive
+    /// it.
+    pub fn column<'a>(&'a self, j: usize) -> Option<VectorView<'a>> {
+        if j >= self.size2() {
+            return None;
+        }
+        Some(VectorView::new(unsafe { ffi::gsl_matrix_column(self.mat, j) }))
+    }
+
+    /// Returns a view of the j-th column of the matrix through which it can be modified, or None if j is out of range.
+    pub fn column_mut<'a>(&'a mut self, j: usize) -> Option<VectorViewMut<'a>> {
+        if j >= self.size2() {
+            return None;
+        }
+        Some(VectorViewMut::new(unsafe { ffi::gsl_matrix_column(self.mat, j) }))
+    }
+
+    /// Returns a view of the diagonal of the matrix. The matrix is not required to be square. For a rectangular matrix the length of the
+    /// diagonal is the same as the smaller dimension of the matrix. The view borrows the matrix, so it cannot outlive it.
+    pub fn diagonal<'a>(&'a self) -> VectorView<'a> {
+        VectorView::new(unsafe { ffi::gsl_matrix_diagonal(self.mat) })
+    }
+
+    /// Returns a view of the diagonal of the matrix through which it can be modified.
+    pub fn diagonal_mut<'a>(&'a mut self) -> VectorViewMut<'a> {
+        VectorViewMut::new(unsafe { ffi::gsl_matrix_diagonal(self.mat) })
+    }
+
+    /// Returns a view of the k-th subdiagonal of the matrix, or None if k is not smaller than the number of rows. The matrix is not
+    /// required to be square. The diagonal of the matrix corresponds to k = 0. The view borrows the matrix, so it cannot outlive it.
+    pub fn subdiagonal<'a>(&'a self, k: usize) -> Option<VectorView<'a>> {
+        if k >= self.size1() {
+            return None;
+        }
+        Some(VectorView::new(unsafe { ffi::gsl_matrix_subdiagonal(self.mat, k) }))
+    }
+
+    /// Returns a view of the k-th subdiagonal of the matrix through which it can be modified, or None if k is not smaller than the number
+    /// of rows.
+    pub fn subdiagonal_mut<'a>(&'a mut self, k: usize) -> Option<VectorViewMut<'a>> {
+        if k >= self.size1() {
+            return None;
+        }
+        Some(VectorViewMut::new(unsafe { ffi::gsl_matrix_subdiagonal(self.mat, k) }))
+    }
+
+    /// Returns a view of the k-th superdiagonal of the matrix, or None if k is not smaller than the number of columns. The matrix is not
+    /// required to be square. The diagonal of the matrix corresponds to k = 0. The view borrows the matrix, so it cannot outlive it.
+    pub fn superdiagonal<'a>(&'a self, k: usize) -> Option<VectorView<'a>> {
+        if k >= self.size2() {
+            return None;
+        }
+        Some(VectorView::new(unsafe { ffi::gsl_matrix_superdiagonal(self.mat, k) }))
+    }
+
+    /// Returns a view of the k-th superdiagonal of the matrix through which it can be modified, or None if k is not smaller than the
+    /// number of columns.
+    pub fn superdiagonal_mut<'a>(&'a mut self, k: usize) -> Option<VectorViewMut<'a>> {
+        if k >= self.size2() {
+            return None;
+        }
+        Some(VectorViewMut::new(unsafe { ffi::gsl_matrix_superdiagonal(self.mat, k) }))
+    }
+
+    /// Returns a view of the n1-by-n2 submatrix whose upper-left element is the element (k1,k2) of the matrix, or None if the submatrix
+    /// is empty or does not fit in the matrix. The view borrows the matrix, so it cannot outlive it.
+    pub fn submatrix<'a>(&'a self, k1: usize, k2: usize, n1: usize, n2: usize) -> Option<MatrixView<'a>> {
+        if !self.contains_submatrix(k1, k2, n1, n2) {
+            return None;
+        }
+        Some(MatrixView::from_matrix(self, k1, k2, n1, n2))
+    }
+
+    /// Returns a view of the n1-by-n2 submatrix whose upper-left element is the element (k1,k2) of the matrix through which it can be
+    /// modified, or None if the submatrix is empty or does not fit in the matrix.
+    pub fn submatrix_mut<'a>(&'a mut self, k1: usize, k2: usize, n1: usize, n2: usize) -> Option<MatrixViewMut<'a>> {
+        if !self.contains_submatrix(k1, k2, n1, n2) {
+            return None;
+        }
+        Some(MatrixViewMut::from_matrix(self, k1, k2, n1, n2))
+    }
+
+    // GSL aborts on a submatrix which is empty or overruns the matrix, so it is checked beforehand.
+    fn contains_submatrix(&self, k1: usize, k2: usize, n1: usize, n2: usize) -> bool {
+        n1 > 0 && n2 > 0 && k1 < self.size1() && k2 < self.size2() && n1 <= self.size1() - k1 && n2 <= self.size2() - k2
+    }
+
     pub fn clone(&self) -> Option<MatrixF64> {
         unsafe {
             if self.mat.is_null() {
                 None
             } else {
                 match MatrixF64::new((*self.mat).size1, (*self.mat).size2) {
-                    Some(m) => {
+                    Some(mut m) => {
                         m.copy_from(self);
                         Some(m)
                     }
@@ -525,6 +822,13 @@ impl ffi::FFI<ffi::gsl_matrix> for MatrixF64 {
     }
 }
 
+pub fn wrap(m: *mut ffi::gsl_matrix) -> MatrixF64 {
+    MatrixF64 {
+        mat: m,
+        can_free: false
+    }
+}
+
 pub struct MatrixF32 {
     mat: *mut ffi::gsl_matrix_float,
     can_free: bool
@@ -559,42 +863,42 @@ impl MatrixF32 {
 
     /// This function sets the value of the (i,j)-th element of the matrix to value.
     /// If y or x lies outside the allowed range of 0 to n1-1 and 0 to n2-1 then the error handler is invoked.
-    pub fn set(&self, y: usize, x: usize, value: f32) -> &MatrixF32 {
+    pub fn set(&mut self, y: usize, x: usize, value: f32) -> &mut MatrixF32 {
         unsafe { ffi::gsl_matrix_float_set(self.mat, y, x, value) };
         self
     }
 
     /// This function sets all the elements of the matrix to the value x.
-    pub fn set_all(&self, x: f32) -> &MatrixF32 {
+    pub fn set_all(&mut self, x: f32) -> &mut MatrixF32 {
         unsafe { ffi::gsl_matrix_float_set_all(self.mat, x) };
         self
     }
 
     /// This function sets all the elements of the matrix to zero.
-    pub fn set_zero(&self) -> &MatrixF32 {
+    pub fn set_zero(&mut self) -> &mut MatrixF32 {
         unsafe { ffi::gsl_matrix_float_set_zero(self.mat) };
         self
     }
 
     /// This function sets the elements of the matrix to the corresponding elements of the identity matrix, m(i,j) = \delta(i,j), i.e. a unit diagonal with all off-diagonal elements zero.
     /// This applies to both square and rectangular matrices.
-    pub fn set_identity(&self) -> &MatrixF32 {
+    pub fn set_identity(&mut self) -> &mut MatrixF32 {
         unsafe { ffi::gsl_matrix_float_set_identity(self.mat) };
         self
     }
 
     /// This function copies the elements of the other matrix into the self matrix. The two matrices must have the same size.
-    pub fn copy_from(&self, other: &MatrixF32) -> enums::Value {
+    pub fn copy_from(&mut self, other: &MatrixF32) -> enums::Value {
         unsafe { ffi::gsl_matrix_float_memcpy(self.mat, other.mat) }
     }
 
     /// This function copies the elements of the self matrix into the other matrix. The two matrices must have the same size.
-    pub fn copy_to(&self, other: &MatrixF32) -> enums::Value {
+    pub fn copy_to(&self, other: &mut MatrixF32) -> enums::Value {
         unsafe { ffi::gsl_matrix_float_memcpy(other.mat, self.mat) }
     }
 
     /// This function exchanges the elements of the matrices self and other by copying. The two matrices must have the same size.
-    pub fn swap(&self, other: &MatrixF32) -> enums::Value {
+    pub fn swap(&mut self, other: &mut MatrixF32) -> enums::Value {
         unsafe { ffi::gsl_matrix_float_swap(self.mat, other.mat) }
     }
 
@@ -626,28 +930,28 @@ impl MatrixF32 {
 
     /// This function copies the elements of the vector v into the y-th row of the matrix.
     /// The length of the vector must be the same as the length of the row.
-    pub fn set_row(&self, y: usize, v: &VectorF32) -> enums::Value {
+    pub fn set_row(&mut self, y: usize, v: &VectorF32) -> enums::Value {
         unsafe { ffi::gsl_matrix_float_set_row(self.mat, y, ffi::FFI::unwrap(v)) }
     }
 
     /// This function copies the elements of the vector v into the x-th column of the matrix.
     /// The length of the vector must be the same as the length of the column.
-    pub fn set_col(&self, x: usize, v: &VectorF32) -> enums::Value {
+    pub fn set_col(&mut self, x: usize, v: &VectorF32) -> enums::Value {
         unsafe { ffi::gsl_matrix_float_set_col(self.mat, x, ffi::FFI::unwrap(v)) }
     }
 
     /// This function exchanges the y1-th and y2-th rows of the matrix in-place.
-    pub fn swap_rows(&self, y1: usize, y2: usize) -> enums::Value {
+    pub fn swap_rows(&mut self, y1: usize, y2: usize) -> enums::Value {
         unsafe { ffi::gsl_matrix_float_swap_rows(self.mat, y1, y2) }
     }
 
     /// This function exchanges the x1-th and x2-th columns of the matrix in-place.
-    pub fn swap_columns(&self, x1: usize, x2: usize) -> enums::Value {
+    pub fn swap_columns(&mut self, x1: usize, x2: usize) -> enums::Value {
         unsafe { ffi::gsl_matrix_float_swap_columns(self.mat, x1, x2) }
     }
 
     /// This function exchanges the i-th row and j-th column of the matrix in-place. The matrix must be square for this operation to be possible.
-    pub fn swap_row_col(&self, i: usize, j: usize) -> enums::Value {
+    pub fn swap_row_col(&mut self, i: usize, j: usize) -> enums::Value {
         unsafe { ffi::gsl_matrix_float_swap_rowcol(self.mat, i, j) }
     }
 
@@ -670,41 +974,41 @@ impl MatrixF32 {
 
     /// This function replaces the matrix m by its transpose by copying the elements of the matrix in-place.
     /// The matrix must be square for this operation to be possible.
-    pub fn transpose(&self) -> enums::Value {
+    pub fn transpose(&mut self) -> enums::Value {
         unsafe { ffi::gsl_matrix_float_transpose(self.mat) }
     }
 
     /// This function adds the elements of the other matrix to the elements of the self matrix.
     /// The result self(i,j) <- self(i,j) + other(i,j) is stored in self and other remains unchanged. The two matrices must have the same dimensions.
-    pub fn add(&self, other: &MatrixF32) -> enums::Value {
+    pub fn add(&mut self, other: &MatrixF32) -> enums::Value {
         unsafe { ffi::gsl_matrix_float_add(self.mat, other.mat) }
     }
 
     /// This function subtracts the elements of the other matrix from the elements of the self matrix.
     /// The result self(i,j) <- self(i,j) - other(i,j) is stored in self and other remains unchanged. The two matrices must have the same dimensions.
-    pub fn sub(&self, other: &MatrixF32) -> enums::Value {
+    pub fn sub(&mut self, other: &MatrixF32) -> enums::Value {
         unsafe { ffi::gsl_matrix_float_sub(self.mat, other.mat) }
     }
 
     /// This function multiplies the elements of the self matrix by the elements of the other matrix.
     /// The result self(i,j) <- self(i,j) * other(i,j) is stored in self and other remains unchanged. The two matrices must have the same dimensions.
-    pub fn mul_elements(&self, other: &MatrixF32) -> enums::Value {
+    pub fn mul_elements(&mut self, other: &MatrixF32) -> enums::Value {
         unsafe { ffi::gsl_matrix_float_mul_elements(self.mat, other.mat) }
     }
 
     /// This function divides the elements of the self matrix by the elements of the other matrix.
     /// The result self(i,j) <- self(i,j) / other(i,j) is stored in self and other remains unchanged. The two matrices must have the same dimensions.
-    pub fn div_elements(&self, other: &MatrixF32) -> enums::Value {
+    pub fn div_elements(&mut self, other: &MatrixF32) -> enums::Value {
         unsafe { ffi::gsl_matrix_float_div_elements(self.mat, other.mat) }
     }
 
     /// This function multiplies the elements of the self matrix by the constant factor x. The result self(i,j) <- x self(i,j) is stored in self.
-    pub fn scale(&self, x: f32) -> enums::Value {
+    pub fn scale(&mut self, x: f32) -> enums::Value {
         unsafe { ffi::gsl_matrix_float_scale(self.mat, x) }
     }
 
     /// This function adds the constant value x to the elements of the self matrix. The result self(i,j) <- self(i,j) + x is stored in self.
-    pub fn add_constant(&self, x: f32) -> enums::Value {
+    pub fn add_constant(&mut self, x: f32) -> enums::Value {
         unsafe { ffi::gsl_matrix_float_add_constant(self.mat, x) }
     }
 
@@ -801,7 +1105,7 @@ impl MatrixF32 {
                 None
             } else {
                 match MatrixF32::new((*self.mat).size1, (*self.mat).size2) {
-                    Some(m) => {
+                    Some(mut m) => {
                         m.copy_from(self);
                         Some(m)
                     }
@@ -0,0 +1,435 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+/*!
+#Integer matrices
+
+Like vectors, GSL provides a matrix type for each of the standard C numeric types. The integer ones are wrapped here as MatrixI32
+(gsl_matrix_int), MatrixU32 (gsl_matrix_uint), MatrixI64 (gsl_matrix_long) and MatrixU8 (gsl_matrix_uchar), with the same API as
+MatrixF64. Their rows and columns are copied to and from the integer vector of the same element type, VectorI32, VectorU32, VectorI64 and
+VectorU8.
+
+The matrices of long double (gsl_matrix_long_double) are not wrapped, as Rust has no floating point type matching long double.
+!*/
+
+use std::fmt;
+use std::fmt::{Formatter, Debug};
+use ffi;
+use enums;
+use types::{VectorI32, VectorU32, VectorI64, VectorU8};
+
+macro_rules! impl_matrix_int {
+    ($matrix:ident, $elem:ty, $ffi:ident, $vector:ident, vector_alloc: $vector_alloc:ident,
+     alloc: $alloc:ident, calloc: $calloc:ident, free: $free:ident, get: $get:ident, set: $set:ident, set_all: $set_all:ident,
+     set_zero: $set_zero:ident, set_identity: $set_identity:ident, memcpy: $memcpy:ident, swap: $swap:ident, get_row: $get_row:ident,
+     get_col: $get_col:ident, set_row: $set_row:ident, set_col: $set_col:ident, swap_rows: $swap_rows:ident,
+     swap_columns: $swap_columns:ident, swap_rowcol: $swap_rowcol:ident, transpose_memcpy: $transpose_memcpy:ident,
+     transpose: $transpose:ident, add: $add:ident, sub: $sub:ident, mul_elements: $mul_elements:ident,
+     div_elements: $div_elements:ident, scale: $scale:ident, add_constant: $add_constant:ident, max: $max:ident, min: $min:ident,
+     minmax: $minmax:ident, max_index: $max_index:ident, min_index: $min_index:ident, minmax_index: $minmax_index:ident,
+     isnull: $isnull:ident, ispos: $ispos:ident, isneg: $isneg:ident, isnonneg: $isnonneg:ident, equal: $equal:ident) => (
+        pub struct $matrix {
+            mat: *mut ffi::$ffi,
+            can_free: bool
+        }
+
+        impl $matrix {
+            /// Creates a new matrix of n1 rows and n2 columns with all elements set to zero.
+            pub fn new(n1: usize, n2: usize) -> Option<$matrix> {
+                let tmp = unsafe { ffi::$calloc(n1, n2) };
+
+                if tmp.is_null() {
+                    None
+                } else {
+                    Some($matrix {
+                        mat: tmp,
+                        can_free: true
+                    })
+                }
+            }
+
+            /// This function returns the (i,j)-th element of the matrix.
+            /// If y or x lie outside the allowed range of 0 to n1-1 and 0 to n2-1 then the error handler is invoked and 0 is returned.
+            pub fn get(&self, y: usize, x: usize) -> $elem {
+                unsafe { ffi::$get(self.mat, y, x) }
+            }
+
+            /// This function sets the value of the (i,j)-th element of the matrix to value.
+            /// If y or x lies outside the allowed range of 0 to n1-1 and 0 to n2-1 then the error handler is invoked.
+            pub fn set(&mut self, y: usize, x: usize, value: $elem) -> &mut $matrix {
+                unsafe { ffi::$set(self.mat, y, x, value) };
+                self
+            }
+
+            /// This function sets all the elements of the matrix to the value x.
+            pub fn set_all(&mut self, x: $elem) -> &mut $matrix {
+                unsafe { ffi::$set_all(self.mat, x) };
+                self
+            }
+
+            /// This function sets all the elements of the matrix to zero.
+            pub fn set_zero(&mut self) -> &mut $matrix {
+                unsafe { ffi::$set_zero(self.mat) };
+                self
+            }
+
+            /// This function sets the elements of the matrix to the corresponding elements of the identity matrix, m(i,j) = \delta(i,j),
+            /// i.e. a unit diagonal with all off-diagonal elements zero. This applies to both square and rectangular matrices.
+            pub fn set_identity(&mut self) -> &mut $matrix {
+                unsafe { ffi::$set_identity(self.mat) };
+                self
+            }
+
+            /// This function copies the elements of the other matrix into the self matrix. The two matrices must have the same size.
+            pub fn copy_from(&mut self, other: &$matrix) -> enums::Value {
+                unsafe { ffi::$memcpy(self.mat, other.mat) }
+            }
+
+            /// This function copies the elements of the self matrix into the other matrix. The two matrices must have the same size.
+            pub fn copy_to(&self, other: &mut $matrix) -> enums::Value {
+                unsafe { ffi::$memcpy(other.mat, self.mat) }
+            }
+
+            /// This function exchanges the elements of the matrices self and other by copying. The two matrices must have the same size.
+            pub fn swap(&mut self, other: &mut $matrix) -> enums::Value {
+                unsafe { ffi::$swap(self.mat, other.mat) }
+            }
+
+            /// This function copies the elements of the y-th row of the matrix into the returned vector.
+            pub fn get_row(&self, y: usize) -> Option<($vector, enums::Value)> {
+                let tmp = unsafe { ffi::$vector_alloc((*self.mat).size2) };
+
+                if tmp.is_null() {
+                    None
+                } else {
+                    let ret = unsafe { ffi::$get_row(tmp, self.mat, y) };
+
+                    Some((ffi::FFI::wrap(tmp), ret))
+                }
+            }
+
+            /// This function copies the elements of the x-th column of the matrix into the returned vector.
+            pub fn get_col(&self, x: usize) -> Option<($vector, enums::Value)> {
+                let tmp = unsafe { ffi::$vector_alloc((*self.mat).size1) };
+
+                if tmp.is_null() {
+                    None
+                } else {
+                    let ret = unsafe { ffi::$get_col(tmp, self.mat, x) };
+
+                    Some((ffi::FFI::wrap(tmp), ret))
+                }
+            }
+
+            /// This function copies the elements of the vector v into the y-th row of the matrix.
+            /// The length of the vector must be the same as the length of the row.
+            pub fn set_row(&mut self, y: usize, v: &$vector) -> enums::Value {
+                unsafe { ffi::$set_row(self.mat, y, ffi::FFI::unwrap(v)) }
+            }
+
+            /// This function copies the elements of the vector v into the x-th column of the matrix.
+            /// The length of the vector must be the same as the length of the column.
+            pub fn set_col(&mut self, x: usize, v: &$vector) -> enums::Value {
+                unsafe { ffi::$set_col(self.mat, x, ffi::FFI::unwrap(v)) }
+            }
+
+            /// This function exchanges the y1-th and y2-th rows of the matrix in-place.
+            pub fn swap_rows(&mut self, y1: usize, y2: usize) -> enums::Value {
+                unsafe { ffi::$swap_rows(self.mat, y1, y2) }
+            }
+
+            /// This function exchanges the x1-th and x2-th columns of the matrix in-place.
+            pub fn swap_columns(&mut self, x1: usize, x2: usize) -> enums::Value {
+                unsafe { ffi::$swap_columns(self.mat, x1, x2) }
+            }
+
+            /// This function exchanges the i-th row and j-th column of the matrix in-place.
+            /// The matrix must be square for this operation to be possible.
+            pub fn swap_row_col(&mut self, i: usize, j: usize) -> enums::Value {
+                unsafe { ffi::$swap_rowcol(self.mat, i, j) }
+            }
+
+            /// This function returns the transpose of the matrix by copying the elements into a new matrix.
+            pub fn transpose_memcpy(&self) -> Option<($matrix, enums::Value)> {
+                let dest = unsafe { ffi::$alloc((*self.mat).size2, (*self.mat).size1) };
+
+                if dest.is_null() {
+                    None
+                } else {
+                    let ret = unsafe { ffi::$transpose_memcpy(dest, self.mat) };
+
+                    Some(($matrix {mat: dest, can_free: true}, ret))
+                }
+            }
+
+            /// This function replaces the matrix m by its transpose by copying the elements of the matrix in-place.
+            /// The matrix must be square for this operation to be possible.
+            pub fn transpose(&mut self) -> enums::Value {
+                unsafe { ffi::$transpose(self.mat) }
+            }
+
+            /// This function adds the elements of the other matrix to the elements of the self matrix.
+            /// The result self(i,j) <- self(i,j) + other(i,j) is stored in self and other remains unchanged. The two matrices must have
+            /// the same dimensions.
+            pub fn add(&mut self, other: &$matrix) -> enums::Value {
+                unsafe { ffi::$add(self.mat, other.mat) }
+            }
+
+            /// This function subtracts the elements of the other matrix from the elements of the self matrix.
+            /// The result self(i,j) <- self(i,j) - other(i,j) is stored in self and other remains unchanged. The two matrices must have
+            /// the same dimensions.
+            pub fn sub(&mut self, other: &$matrix) -> enums::Value {
+                unsafe { ffi::$sub(self.mat, other.mat) }
+            }
+
+            /// This function multiplies the elements of the self matrix by the elements of the other matrix.
+            /// The result self(i,j) <- self(i,j) * other(i,j) is stored in self and other remains unchanged. The two matrices must have
+            /// the same dimensions.
+            pub fn mul_elements(&mut self, other: &$matrix) -> enums::Value {
+                unsafe { ffi::$mul_elements(self.mat, other.mat) }
+            }
+
+            /// This function divides the elements of the self matrix by the elements of the other matrix.
+            /// The result self(i,j) <- self(i,j) / other(i,j) is stored in self and other remains unchanged. The two matrices must have
+            /// the same dimensions.
+            pub fn div_elements(&mut self, other: &$matrix) -> enums::Value {
+                unsafe { ffi::$div_elements(self.mat, other.mat) }
+            }
+
+            /// This function multiplies the elements of the self matrix by the constant factor x. The result self(i,j) <- x self(i,j) is
+            /// stored in self.
+            /// x is converted to a double, as GSL takes a double for this function.
+            pub fn scale(&mut self, x: $elem) -> enums::Value {
+                unsafe { ffi::$scale(self.mat, x as f64) }
+            }
+
+            /// This function adds the constant value x to the elements of the self matrix. The result self(i,j) <- self(i,j) + x is stored
+            /// in self.
+            /// x is converted to a double, as GSL takes a double for this function.
+            pub fn add_constant(&mut self, x: $elem) -> enums::Value {
+                unsafe { ffi::$add_constant(self.mat, x as f64) }
+            }
+
+            /// This function returns the maximum value in the self matrix.
+            pub fn max(&self) -> $elem {
+                unsafe { ffi::$max(self.mat) }
+            }
+
+            /// This function returns the minimum value in the self matrix.
+            pub fn min(&self) -> $elem {
+                unsafe { ffi::$min(self.mat) }
+            }
+
+            /// This function returns the minimum and maximum values in the self matrix, storing them in min_out and max_out.
+            pub fn minmax(&self, min_out: &mut $elem, max_out: &mut $elem) {
+                unsafe { ffi::$minmax(self.mat, min_out, max_out) }
+            }
+
+            /// This function returns the indices of the maximum value in the self matrix, storing them in imax and jmax.
+            /// When there are several equal maximum elements then the first element found is returned, searching in row-major order.
+            pub fn max_index(&self) -> (usize, usize) {
+                let mut imax = 0usize;
+                let mut jmax = 0usize;
+
+                unsafe { ffi::$max_index(self.mat, &mut imax, &mut jmax) };
+                (imax, jmax)
+            }
+
+            /// This function returns the indices of the minimum value in the self matrix, storing them in imin and jmin.
+            /// When there are several equal minimum elements then the first element found is returned, searching in row-major order.
+            pub fn min_index(&self) -> (usize, usize) {
+                let mut imin = 0usize;
+                let mut jmin = 0usize;
+
+                unsafe { ffi::$min_index(self.mat, &mut imin, &mut jmin) };
+                (imin, jmin)
+            }
+
+            /// This function returns the indices of the minimum and maximum values in the self matrix, storing them in (imin,jmin) and
+            /// (imax,jmax). When there are several equal minimum or maximum elements then the first elements found are returned, searching
+            /// in row-major order.
+            pub fn minmax_index(&self) -> (usize, usize, usize, usize) {
+                let mut imin = 0usize;
+                let mut jmin = 0usize;
+                let mut imax = 0usize;
+                let mut jmax = 0usize;
+
+                unsafe { ffi::$minmax_index(self.mat, &mut imin, &mut jmin, &mut imax, &mut jmax) };
+                (imin, jmin, imax, jmax)
+            }
+
+            /// This function returns true if all the elements of the self matrix are stricly zero.
+            pub fn is_null(&self) -> bool {
+                match unsafe { ffi::$isnull(self.mat) } {
+                    1 => true,
+                    _ => false
+                }
+            }
+
+            /// This function returns true if all the elements of the self matrix are stricly positive.
+            pub fn is_pos(&self) -> bool {
+                match unsafe { ffi::$ispos(self.mat) } {
+                    1 => true,
+                    _ => false
+                }
+            }
+
+            /// This function returns true if all the elements of the self matrix are stricly negative.
+            pub fn is_neg(&self) -> bool {
+                match unsafe { ffi::$isneg(self.mat) } {
+                    1 => true,
+                    _ => false
+                }
+            }
+
+            /// This function returns true if all the elements of the self matrix are stricly non-negative.
+            pub fn is_non_neg(&self) -> bool {
+                match unsafe { ffi::$isnonneg(self.mat) } {
+                    1 => true,
+                    _ => false
+                }
+            }
+
+            /// This function returns true if all elements of the two matrix are equal.
+            pub fn equal(&self, other: &$matrix) -> bool {
+                match unsafe { ffi::$equal(self.mat, other.mat) } {
+                    1 => true,
+                    _ => false
+                }
+            }
+
+            pub fn size1(&self) -> usize {
+                if self.mat.is_null() {
+                    0usize
+                } else {
+                    unsafe { (*self.mat).size1 }
+                }
+            }
+
+            pub fn size2(&self) -> usize {
+                if self.mat.is_null() {
+                    0usize
+                } else {
+                    unsafe { (*self.mat).size2 }
+                }
+            }
+
+            pub fn clone(&self) -> Option<$matrix> {
+                unsafe {
+                    if self.mat.is_null() {
+                        None
+                    } else {
+                        match $matrix::new((*self.mat).size1, (*self.mat).size2) {
+                            Some(mut m) => {
+                                m.copy_from(self);
+                                Some(m)
+                            }
+                            None => None
+                        }
+                    }
+                }
+            }
+        }
+
+        impl Drop for $matrix {
+            fn drop(&mut self) {
+                if self.can_free {
+                    unsafe { ffi::$free(self.mat) };
+                    self.mat = ::std::ptr::null_mut();
+                }
+            }
+        }
+
+        impl Debug for $matrix {
+            #[allow(unused_must_use)]
+            fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+                unsafe {
+                    for y in 0usize..(*self.mat).size1 {
+                        write!(f, "[");
+                        for x in 0usize..(*self.mat).size2 {
+                            if x < (*self.mat).size2 - 1 {
+                                write!(f, "{}, ", self.get(y, x));
+                            } else {
+                                write!(f, "{}", self.get(y, x));
+                            }
+                        }
+                        if y < (*self.mat).size1 - 1 {
+                            write!(f, "]\n");
+                        }
+                    }
+                }
+                write!(f, "]")
+            }
+        }
+
+        impl ffi::FFI<ffi::$ffi> for $matrix {
+            fn wrap(r: *mut ffi::$ffi) -> $matrix {
+                $matrix {
+                    mat: r,
+                    can_free: true
+                }
+            }
+
+            fn unwrap(m: &$matrix) -> *mut ffi::$ffi {
+                m.mat
+            }
+        }
+    );
+}
+
+impl_matrix_int!(MatrixI32, i32, gsl_matrix_int, VectorI32, vector_alloc: gsl_vector_int_alloc,
+    alloc: gsl_matrix_int_alloc, calloc: gsl_matrix_int_calloc, free: gsl_matrix_int_free, get: gsl_matrix_int_get,
+    set: gsl_matrix_int_set, set_all: gsl_matrix_int_set_all, set_zero: gsl_matrix_int_set_zero,
+    set_identity: gsl_matrix_int_set_identity, memcpy: gsl_matrix_int_memcpy, swap: gsl_matrix_int_swap,
+    get_row: gsl_matrix_int_get_row, get_col: gsl_matrix_int_get_col, set_row: gsl_matrix_int_set_row,
+    set_col: gsl_matrix_int_set_col, swap_rows: gsl_matrix_int_swap_rows, swap_columns: gsl_matrix_int_swap_columns,
+    swap_rowcol: gsl_matrix_int_swap_rowcol, transpose_memcpy: gsl_matrix_int_transpose_memcpy,
+    transpose: gsl_matrix_int_transpose, add: gsl_matrix_int_add, sub: gsl_matrix_int_sub,
+    mul_elements: gsl_matrix_int_mul_elements, div_elements: gsl_matrix_int_div_elements, scale: gsl_matrix_int_scale,
+    add_constant: gsl_matrix_int_add_constant, max: gsl_matrix_int_max, min: gsl_matrix_int_min, minmax: gsl_matrix_int_minmax,
+    max_index: gsl_matrix_int_max_index, min_index: gsl_matrix_int_min_index, minmax_index: gsl_matrix_int_minmax_index,
+    isnull: gsl_matrix_int_isnull, ispos: gsl_matrix_int_ispos, isneg: gsl_matrix_int_isneg, isnonneg: gsl_matrix_int_isnonneg,
+    equal: gsl_matrix_int_equal);
+
+impl_matrix_int!(MatrixU32, u32, gsl_matrix_uint, VectorU32, vector_alloc: gsl_vector_uint_alloc,
+    alloc: gsl_matrix_uint_alloc, calloc: gsl_matrix_uint_calloc, free: gsl_matrix_uint_free, get: gsl_matrix_uint_get,
+    set: gsl_matrix_uint_set, set_all: gsl_matrix_uint_set_all, set_zero: gsl_matrix_uint_set_zero,
+    set_identity: gsl_matrix_uint_set_identity, memcpy: gsl_matrix_uint_memcpy, swap: gsl_matrix_uint_swap,
+    get_row: gsl_matrix_uint_get_row, get_col: gsl_matrix_uint_get_col, set_row: gsl_matrix_uint_set_row,
+    set_col: gsl_matrix_uint_set_col, swap_rows: gsl_matrix_uint_swap_rows, swap_columns: gsl_matrix_uint_swap_columns,
+    swap_rowcol: gsl_matrix_uint_swap_rowcol, transpose_memcpy: gsl_matrix_uint_transpose_memcpy,
+    transpose: gsl_matrix_uint_transpose, add: gsl_matrix_uint_add, sub: gsl_matrix_uint_sub,
+    mul_elements: gsl_matrix_uint_mul_elements, div_elements: gsl_matrix_uint_div_elements, scale: gsl_matrix_uint_scale,
+    add_constant: gsl_matrix_uint_add_constant, max: gsl_matrix_uint_max, min: gsl_matrix_uint_min,
+    minmax: gsl_matrix_uint_minmax, max_index: gsl_matrix_uint_max_index, min_index: gsl_matrix_uint_min_index,
+    minmax_index: gsl_matrix_uint_minmax_index, isnull: gsl_matrix_uint_isnull, ispos: gsl_matrix_uint_ispos,
+    isneg: gsl_matrix_uint_isneg, isnonneg: gsl_matrix_uint_isnonneg, equal: gsl_matrix_uint_equal);
+
+impl_matrix_int!(MatrixI64, i64, gsl_matrix_long, VectorI64, vector_alloc: gsl_vector_long_alloc,
+    alloc: gsl_matrix_long_alloc, calloc: gsl_matrix_long_calloc, free: gsl_matrix_long_free, get: gsl_matrix_long_get,
+    set: gsl_matrix_long_set, set_all: gsl_matrix_long_set_all, set_zero: gsl_matrix_long_set_zero,
+    set_identity: gsl_matrix_long_set_identity, memcpy: gsl_matrix_long_memcpy, swap: gsl_matrix_long_swap,
+    get_row: gsl_matrix_long_get_row, get_col: gsl_matrix_long_get_col, set_row: gsl_matrix_long_set_row,
+    set_col: gsl_matrix_long_set_col, swap_rows: gsl_matrix_long_swap_rows, swap_columns: gsl_matrix_long_swap_columns,
+    swap_rowcol: gsl_matrix_long_swap_rowcol, transpose_memcpy: gsl_matrix_long_transpose_memcpy,
+    transpose: gsl_matrix_long_transpose, add: gsl_matrix_long_add, sub: gsl_matrix_long_sub,
+    mul_elements: gsl_matrix_long_mul_elements, div_elements: gsl_matrix_long_div_elements, scale: gsl_matrix_long_scale,
+    add_constant: gsl_matrix_long_add_constant, max: gsl_matrix_long_max, min: gsl_matrix_long_min,
+    minmax: gsl_matrix_long_minmax, max_index: gsl_matrix_long_max_index, min_index: gsl_matrix_long_min_index,
+    minmax_index: gsl_matrix_long_minmax_index, isnull: gsl_matrix_long_isnull, ispos: gsl_matrix_long_ispos,
+    isneg: gsl_matrix_long_isneg, isnonneg: gsl_matrix_long_isnonneg, equal: gsl_matrix_long_equal);
+
+impl_matrix_int!(MatrixU8, u8, gsl_matrix_uchar, VectorU8, vector_alloc: gsl_vector_uchar_alloc,
+    alloc: gsl_matrix_uchar_alloc, calloc: gsl_matrix_uchar_calloc, free: gsl_matrix_uchar_free, get: gsl_matrix_uchar_get,
+    set: gsl_matrix_uchar_set, set_all: gsl_matrix_uchar_set_all, set_zero: gsl_matrix_uchar_set_zero,
+    set_identity: gsl_matrix_uchar_set_identity, memcpy: gsl_matrix_uchar_memcpy, swap: gsl_matrix_uchar_swap,
+    get_row: gsl_matrix_uchar_get_row, get_col: gsl_matrix_uchar_get_col, set_row: gsl_matrix_uchar_set_row,
+    set_col: gsl_matrix_uchar_set_col, swap_rows: gsl_matrix_uchar_swap_rows, swap_columns: gsl_matrix_uchar_swap_columns,
+    swap_rowcol: gsl_matrix_uchar_swap_rowcol, transpose_memcpy: gsl_matrix_uchar_transpose_memcpy,
+    transpose: gsl_matrix_uchar_transpose, add: gsl_matrix_uchar_add, sub: gsl_matrix_uchar_sub,
+    mul_elements: gsl_matrix_uchar_mul_elements, div_elements: gsl_matrix_uchar_div_elements, scale: gsl_matrix_uchar_scale,
+    add_constant: gsl_matrix_uchar_add_constant, max: gsl_matrix_uchar_max, min: gsl_matrix_uchar_min,
+    minmax: gsl_matrix_uchar_minmax, max_index: gsl_matrix_uchar_max_index, min_index: gsl_matrix_uchar_min_index,
+    minmax_index: gsl_matrix_uchar_minmax_index, isnull: gsl_matrix_uchar_isnull, ispos: gsl_matrix_uchar_ispos,
+    isneg: gsl_matrix_uchar_isneg, isnonneg: gsl_matrix_uchar_isnonneg, equal: gsl_matrix_uchar_equal);
@@ -10,28 +10,48 @@ pub use self::discrete_hankel::DiscreteHankel;
 pub use self::eigen_symmetric_workspace::{EigenSymmetricWorkspace, EigenSymmetricVWorkspace, EigenHermitianWorkspace,
     EigenHermitianVWorkspace, EigenNonSymmWorkspace, EigenNonSymmVWorkspace, EigenGenSymmWorkspace, EigenGenSymmVWorkspace,
     EigenGenHermWorkspace, EigenGenHermVWorkspace, EigenGenWorkspace, EigenGenVWorkspace};
-pub use self::fast_fourier_transforms::{FftComplexWaveTable, FftComplexWorkspace};
+pub use self::fast_fourier_transforms::{FftComplexWaveTable, FftComplexWorkspace, FftComplexWaveTableF32, FftComplexWorkspaceF32,
+    FftRealWaveTable, FftHalfComplexWaveTable, FftRealWorkspace, FftRealWaveTableF32, FftHalfComplexWaveTableF32, FftRealWorkspaceF32, FftPlan};
+pub use self::filter::{GaussianFilter, MedianFilter, RecursiveMedianFilter, ImpulseFilter};
+pub use self::generic::{GslVector, GslMatrix};
 pub use self::histograms::{Histogram, HistogramPdf, Histogram2D, Histogram2DPdf};
 pub use self::integration::{IntegrationWorkspace, IntegrationQawsTable, IntegrationQawoTable, CquadWorkspace, GLFixedTable};
-pub use self::interpolation::{InterpAccel, Interp, InterpType, Spline};
+pub use self::interpolation::{InterpAccel, Interp, InterpType, Spline, Interp2dAccel, Interp2d, Interp2dType, Spline2d};
 pub use self::mathieu::MathieuWorkspace;
-pub use self::matrix::{MatrixF32, MatrixF64, MatrixView};
+pub use self::matrix::{MatrixF32, MatrixF64, MatrixView, MatrixViewMut};
 pub use self::matrix_complex::{MatrixComplexF32, MatrixComplexF64};
+pub use self::matrix_int::{MatrixI32, MatrixU32, MatrixI64, MatrixU8};
 pub use self::minimizer::{Minimizer, MinimizerType};
 pub use self::monte_carlo::{PlainMonteCarlo, MiserMonteCarlo, VegasMonteCarlo, VegasParams};
+pub use self::movstat::MovStatWorkspace;
+pub use self::multifit_linear::MultifitLinearWorkspace;
+pub use self::multifit_nlinear::{MultifitNlinearFunction, MultifitNlinearParameters, MultifitNlinearWorkspace, MultifitNlinearType,
+    MultifitNlinearTrs, MultifitNlinearScale, MultifitNlinearSolver};
+pub use self::multifit_robust::{MultifitRobustWorkspace, MultifitRobustType, RobustStats};
 pub use self::multifit_solver::{MultiFitFdfSolver, MultiFitFunction, MultiFitFdfSolverType, MultiFitFunctionFdf};
+pub use self::multilarge::{MultilargeLinearWorkspace, MultilargeLinearType};
+pub use self::multimin::{MultiMinFMinimizer, MultiMinFMinimizerType, MultiMinFdfMinimizer, MultiMinFdfMinimizerType, MultiMinFunction,
+    MultiMinFunctionFdf};
+pub use self::multiroot::{MultiRootFSolver, MultiRootFSolverType, MultiRootFdfSolver, MultiRootFdfSolverType, MultiRootFunction,
+    MultiRootFunctionFdf};
 pub use self::multiset::MultiSet;
 pub use self::n_tuples::NTuples;
+pub use self::ops::{VectorIter, VectorIterMut, MatrixIter, MatrixIterMut, MatrixRows, MatrixColumns};
 pub use self::ordinary_differential_equations::{ODEiv2System, ODEiv2Step, ODEiv2StepType, ODEiv2Control, ODEiv2Evolve, ODEiv2Driver};
 pub use self::permutation::Permutation;
 pub use self::polynomial::PolyComplex;
 pub use self::qrng::{QRng, QRngType};
 pub use self::ran_discrete::RanDiscrete;
 pub use self::result::{Result, ResultE10};
+pub use self::roots::{RootFSolver, RootFSolverType, RootFdfSolver, RootFdfSolverType};
 pub use self::rng::{Rng, RngType};
+pub use self::rstat::{RStatWorkspace, RStatQuantile};
 pub use self::series_acceleration::{LevinUWorkspace, LevinUTruncWorkspace};
-pub use self::vector::{VectorF32, VectorF64, VectorView};
+pub use self::sparse_matrix::{SpMatrixF64, SpMatrixIter};
+pub use self::splinalg::{SpLinalgIterSolver, SpLinalgIterSolverType};
+pub use self::vector::{VectorF32, VectorF64, VectorView, VectorViewMut, VectorViewF32, VectorViewMutF32};
 pub use self::vector_complex::{VectorComplexF32, VectorComplexF64};
+pub use self::vector_int::{VectorI32, VectorU32, VectorI64, VectorU8};
 pub use self::wavelet_transforms::{Wavelet, WaveletType, WaveletWorkspace};
 
 pub mod basis_spline;
@@ -41,25 +61,41 @@ pub mod complex;
 pub mod discrete_hankel;
 pub mod eigen_symmetric_workspace;
 pub mod fast_fourier_transforms;
+pub mod filter;
+pub mod generic;
 pub mod histograms;
 pub mod integration;
 pub mod interpolation;
 pub mod mathieu;
 pub mod matrix;
 pub mod matrix_complex;
+pub mod matrix_int;
 pub mod minimizer;
 pub mod monte_carlo;
+pub mod movstat;
+pub mod multifit_linear;
+pub mod multifit_nlinear;
+pub mod multifit_robust;
 pub mod multifit_solver;
+pub mod multilarge;
+pub mod multimin;
+pub mod multiroot;
 pub mod multiset;
 pub mod n_tuples;
+pub mod ops;
 pub mod ordinary_differential_equations;
 pub mod permutation;
 pub mod polynomial;
 pub mod qrng;
 pub mod ran_discrete;
 pub mod result;
+pub mod roots;
+pub mod rstat;
 pub mod series_acceleration;
+pub mod sparse_matrix;
+pub mod splinalg;
 pub mod rng;
 pub mod vector;
 pub mod vector_complex;
+pub mod vector_int;
 pub mod wavelet_transforms;
\ No newline at end of file
@@ -0,0 +1,213 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+/*!
+#Moving Window Statistics
+
+This chapter describes routines for computing moving window statistics (also called rolling statistics and running statistics), using a
+window around a sample which is used to calculate various local statistical properties of an input data stream. The window is then slid
+forward by one sample to process the next data point and so on.
+
+##Introduction
+
+This chapter is concerned with calculating various statistics from subsets of a given dataset. The main idea is to compute statistics in
+the vicinity of a given data sample by defining a window which includes the sample itself as well as some specified number of samples
+before and after the sample in question. For a sample x_i, we define a window W_i^{H,J} as
+
+W_i^{H,J} = \left\{ x_{i-H}, \dots, x_i, \dots, x_{i+J} \right\}
+
+The parameters H and J are non-negative integers specifying the number of samples to include before and after the sample x_i. Statistics
+such as the mean and standard deviation of the window W_i^{H,J} may be computed, and then the window is shifted forward by one sample to
+focus on x_{i+1}. The total number of samples in the window is K = H + J + 1. To define a symmetric window centered on x_i, one would set
+H = J = \left\lfloor K / 2 \right\rfloor.
+
+##Handling Endpoints
+
+When processing samples near the ends of the input signal, there will not be enough samples to fill the window W_i^{H,J} defined above.
+Therefore the user must specify how to construct the windows near the end points. This is done with the MovStatEnd enum:
+
+ * PadZero: inserts zeros into the window near the signal end points.
+ * PadValue: pads the window with the first and last sample in the input signal.
+ * Truncate: no padding is performed, and the windows are simply truncated as the end points are approached.
+
+##User-defined Moving Statistics
+
+Any statistic which is not provided by this module can be computed with `apply`, which calls a user closure on the samples of each
+window.
+!*/
+
+use ffi;
+use enums::GSLResult;
+use libc::{c_double, c_void, size_t};
+
+pub struct MovStatWorkspace {
+    w: *mut ffi::gsl_movstat_workspace
+}
+
+impl MovStatWorkspace {
+    /// This function allocates a workspace for computing symmetric, centered moving statistics with a window length of K samples. In this
+    /// case, H = J = \left\lfloor K/2 \right\rfloor. The size of the workspace is O(7K).
+    pub fn new(k: usize) -> Option<MovStatWorkspace> {
+        let tmp = unsafe { ffi::gsl_movstat_alloc(k) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(MovStatWorkspace {
+                w: tmp
+            })
+        }
+    }
+
+    /// This function allocates a workspace for computing moving statistics using a window with H samples prior to the current sample, and
+    /// J samples after the current sample. The total window size is K = H + J + 1. The size of the workspace is O(7K).
+    pub fn new2(h: usize, j: usize) -> Option<MovStatWorkspace> {
+        let tmp = unsafe { ffi::gsl_movstat_alloc2(h, j) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(MovStatWorkspace {
+                w: tmp
+            })
+        }
+    }
+
+    /// This function returns the total window size K = H + J + 1.
+    pub fn window_size(&self) -> usize {
+        unsafe { (*self.w).K }
+    }
+
+    /// This function computes the moving window mean of the input vector x, storing the output in y. The parameter endtype specifies how
+    /// windows near the ends of the input should be handled.
+    pub fn mean(&mut self, endtype: ::MovStatEnd, x: &::VectorF64, y: &mut ::VectorF64) -> GSLResult<()> {
+        GSLResult::from(unsafe { ffi::gsl_movstat_mean(endtype, ffi::FFI::unwrap(x), ffi::FFI::unwrap(y), self.w) })
+    }
+
+    /// This function computes the moving window variance of the input vector x, storing the output in y. The parameter endtype specifies
+    /// how windows near the ends of the input should be handled.
+    pub fn variance(&mut self, endtype: ::MovStatEnd, x: &::VectorF64, y: &mut ::VectorF64) -> GSLResult<()> {
+        GSLResult::from(unsafe { ffi::gsl_movstat_variance(endtype, ffi::FFI::unwrap(x), ffi::FFI::unwrap(y), self.w) })
+    }
+
+    /// This function computes the moving window standard deviation of the input vector x, storing the output in y. The parameter endtype
+    /// specifies how windows near the ends of the input should be handled.
+    pub fn sd(&mut self, endtype: ::MovStatEnd, x: &::VectorF64, y: &mut ::VectorF64) -> GSLResult<()> {
+        GSLResult::from(unsafe { ffi::gsl_movstat_sd(endtype, ffi::FFI::unwrap(x), ffi::FFI::unwrap(y), self.w) })
+    }
+
+    /// This function computes the moving median of the input vector x, storing the output in y. The parameter endtype specifies how
+    /// windows near the ends of the input should be handled.
+    pub fn median(&mut self, endtype: ::MovStatEnd, x: &::VectorF64, y: &mut ::VectorF64) -> GSLResult<()> {
+        GSLResult::from(unsafe { ffi::gsl_movstat_median(endtype, ffi::FFI::unwrap(x), ffi::FFI::unwrap(y), self.w) })
+    }
+
+    /// This function computes the moving minimum of the input vector x, storing the result in y. The parameter endtype specifies how
+    /// windows near the ends of the input should be handled.
+    pub fn min(&mut self, endtype: ::MovStatEnd, x: &::VectorF64, y: &mut ::VectorF64) -> GSLResult<()> {
+        GSLResult::from(unsafe { ffi::gsl_movstat_min(endtype, ffi::FFI::unwrap(x), ffi::FFI::unwrap(y), self.w) })
+    }
+
+    /// This function computes the moving maximum of the input vector x, storing the result in y. The parameter endtype specifies how
+    /// windows near the ends of the input should be handled.
+    pub fn max(&mut self, endtype: ::MovStatEnd, x: &::VectorF64, y: &mut ::VectorF64) -> GSLResult<()> {
+        GSLResult::from(unsafe { ffi::gsl_movstat_max(endtype, ffi::FFI::unwrap(x), ffi::FFI::unwrap(y), self.w) })
+    }
+
+    /// This function computes the moving minimum and maximum of the input vector x, storing the window minimums in y_min and the window
+    /// maximums in y_max. The parameter endtype specifies how windows near the ends of the input should be handled.
+    pub fn minmax(&mut self, endtype: ::MovStatEnd, x: &::VectorF64, y_min: &mut ::VectorF64, y_max: &mut ::VectorF64) -> GSLResult<()> {
+        GSLResult::from(unsafe {
+            ffi::gsl_movstat_minmax(endtype, ffi::FFI::unwrap(x), ffi::FFI::unwrap(y_min), ffi::FFI::unwrap(y_max), self.w)
+        })
+    }
+
+    /// This function computes the moving window sum of the input vector x, storing the output in y. The parameter endtype specifies how
+    /// windows near the ends of the input should be handled.
+    pub fn sum(&mut self, endtype: ::MovStatEnd, x: &::VectorF64, y: &mut ::VectorF64) -> GSLResult<()> {
+        GSLResult::from(unsafe { ffi::gsl_movstat_sum(endtype, ffi::FFI::unwrap(x), ffi::FFI::unwrap(y), self.w) })
+    }
+
+    /// This function computes the moving MAD of the input vector x and stores the result in xmad. The medians of each window W_i^{H,J}
+    /// are stored in xmedian on output. The inputs x, xmedian, and xmad must all be the same length. The parameter endtype defines how
+    /// windows near the ends of the input should be handled. This function does not include the scale factor, so that the MAD is not
+    /// normalized to estimate the standard deviation of Gaussian data.
+    pub fn mad0(&mut self, endtype: ::MovStatEnd, x: &::VectorF64, xmedian: &mut ::VectorF64, xmad: &mut ::VectorF64) -> GSLResult<()> {
+        GSLResult::from(unsafe {
+            ffi::gsl_movstat_mad0(endtype, ffi::FFI::unwrap(x), ffi::FFI::unwrap(xmedian), ffi::FFI::unwrap(xmad), self.w)
+        })
+    }
+
+    /// This function computes the moving MAD of the input vector x and stores the result in xmad. The medians of each window W_i^{H,J}
+    /// are stored in xmedian on output. The inputs x, xmedian, and xmad must all be the same length. The parameter endtype defines how
+    /// windows near the ends of the input should be handled. This function includes the scale factor 1.4826, so that the MAD is an
+    /// unbiased estimator of the standard deviation of Gaussian data.
+    pub fn mad(&mut self, endtype: ::MovStatEnd, x: &::VectorF64, xmedian: &mut ::VectorF64, xmad: &mut ::VectorF64) -> GSLResult<()> {
+        GSLResult::from(unsafe {
+            ffi::gsl_movstat_mad(endtype, ffi::FFI::unwrap(x), ffi::FFI::unwrap(xmedian), ffi::FFI::unwrap(xmad), self.w)
+        })
+    }
+
+    /// This function computes the moving q-quantile range (QQR) of the input vector x and stores the result in xqqr. The quantile
+    /// parameter q must be between 0 and 0.5. The input q = 0.25 corresponds to the interquartile range, which is a robust estimate of
+    /// the scale of the data. The parameter endtype defines how windows near the ends of the input should be handled.
+    pub fn qqr(&mut self, endtype: ::MovStatEnd, x: &::VectorF64, q: f64, xqqr: &mut ::VectorF64) -> GSLResult<()> {
+        GSLResult::from(unsafe { ffi::gsl_movstat_qqr(endtype, ffi::FFI::unwrap(x), q, ffi::FFI::unwrap(xqqr), self.w) })
+    }
+
+    /// This function computes the moving S_n scale estimate of the input vector x and stores the result in xscale. The parameter endtype
+    /// defines how windows near the ends of the input should be handled.
+    pub fn Sn(&mut self, endtype: ::MovStatEnd, x: &::VectorF64, xscale: &mut ::VectorF64) -> GSLResult<()> {
+        GSLResult::from(unsafe { ffi::gsl_movstat_Sn(endtype, ffi::FFI::unwrap(x), ffi::FFI::unwrap(xscale), self.w) })
+    }
+
+    /// This function computes the moving Q_n scale estimate of the input vector x and stores the result in xscale. The parameter endtype
+    /// defines how windows near the ends of the input should be handled.
+    pub fn Qn(&mut self, endtype: ::MovStatEnd, x: &::VectorF64, xscale: &mut ::VectorF64) -> GSLResult<()> {
+        GSLResult::from(unsafe { ffi::gsl_movstat_Qn(endtype, ffi::FFI::unwrap(x), ffi::FFI::unwrap(xscale), self.w) })
+    }
+
+    /// This function applies the user-defined moving window statistic f to the input vector x, storing the output in y. The closure is
+    /// called once for each window with the samples of that window, and returns the value of the statistic. The samples may be
+    /// reordered freely by the closure, which for example allows computing order statistics in place. The parameter endtype specifies
+    /// how windows near the ends of the input should be handled.
+    pub fn apply(&mut self, endtype: ::MovStatEnd, f: &mut FnMut(&mut [f64]) -> f64, x: &::VectorF64,
+        y: &mut ::VectorF64) -> GSLResult<()> {
+        extern "C" fn handler(n: size_t, x: *mut c_double, params: *mut c_void) -> c_double {
+            let f: &mut &mut FnMut(&mut [f64]) -> f64 = unsafe { &mut *(params as *mut &mut FnMut(&mut [f64]) -> f64) };
+
+            if n == 0 {
+                f(&mut [])
+            } else {
+                f(unsafe { ::std::slice::from_raw_parts_mut(x, n) })
+            }
+        }
+
+        let mut f = f;
+        let function = ffi::gsl_movstat_function {
+            function: handler,
+            params: &mut f as *mut &mut FnMut(&mut [f64]) -> f64 as *mut c_void
+        };
+        GSLResult::from(unsafe { ffi::gsl_movstat_apply(endtype, &function, ffi::FFI::unwrap(x), ffi::FFI::unwrap(y), self.w) })
+    }
+}
+
+impl Drop for MovStatWorkspace {
+    fn drop(&mut self) {
+        unsafe { ffi::gsl_movstat_free(self.w) };
+        self.w = ::std::ptr::null_mut();
+    }
+}
+
+impl ffi::FFI<ffi::gsl_movstat_workspace> for MovStatWorkspace {
+    fn wrap(w: *mut ffi::gsl_movstat_workspace) -> MovStatWorkspace {
+        MovStatWorkspace {
+            w: w
+        }
+    }
+
+    fn unwrap(w: &MovStatWorkspace) -> *mut ffi::gsl_movstat_workspace {
+        w.w
+    }
+}
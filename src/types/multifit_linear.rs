@@ -0,0 +1,324 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+/*!
+#Linear Least-Squares Fitting
+
+This chapter describes routines for performing least squares fits of a general linear model to experimental data. The model is
+written as y = X c, where y is a vector of n observations, X is an n-by-p matrix of predictor variables (the design matrix) and c is the
+vector of p unknown best-fit parameters which are to be estimated. The straight line fits of the fit module are special cases of this
+model.
+
+The best-fit is found by minimizing the weighted sums of squared residuals, \chi^2,
+
+\chi^2 = (y - X c)^T W (y - X c)
+
+with respect to the parameters c. The weights are specified by the diagonal elements of the n-by-n matrix W. For unweighted data, W is
+replaced by the identity matrix.
+
+The solution is computed with the singular value decomposition of X, which is numerically robust even when the design matrix is badly
+conditioned, for example with polynomial models of high degree. Singular values which are small compared to the largest one correspond to
+linear combinations of the parameters which are not constrained by the data: the truncated SVD fits discard them, producing a solution of
+lower effective rank.
+
+The covariance matrix of the model parameters, cov = (X^T W X)^{-1}, is computed at the same time and can be used with
+`multifit::linear_est` to compute the fitted function and its standard deviation at a new point. For unweighted data it is scaled by the
+variance of the residuals, \sigma^2 = \chi^2 / (n - p).
+
+##Polynomial fitting
+
+A polynomial fit of degree p - 1 to the data points (x_i, y_i) uses the design matrix X_{ij} = x_i^j, for i = 0, ..., n - 1 and
+j = 0, ..., p - 1. Any other basis of functions can be used in the same way, X_{ij} being the j-th basis function evaluated at x_i.
+
+##Regularized regression
+
+Ill-conditioned problems, such as those arising from the discretization of inverse problems, have solutions which are dominated by the
+amplification of noise in the observations. Tikhonov regularization (also known as ridge regression) stabilizes them by minimizing
+
+\chi^2 = || y - X c ||^2 + \lambda^2 || L c ||^2
+
+where L is a regularization matrix and \lambda the regularization parameter. The system is first converted to standard form, with
+L = I, by `linear_stdform1` for diagonal L or `linear_stdform2` for a general L. The SVD of the standard form matrix is computed once with
+`linear_svd`, after which `linear_solve` solves the system for any \lambda, and the solution is converted back with `linear_genform1`
+or `linear_genform2`.
+
+The regularization parameter can be chosen automatically, either at the corner of the L-curve, the plot of the solution norm ||L c||
+against the residual norm ||y - X c|| computed by `linear_lcurve` and located by `multifit::linear_lcorner`, or at the minimum of the
+generalized cross validation function computed by `linear_gcv`.
+
+##References and Further Reading
+
+P. C. Hansen, Rank-Deficient and Discrete Ill-Posed Problems: Numerical Aspects of Linear Inversion, SIAM Monographs on Mathematical
+Modeling and Computation, 1998.
+
+M. Rezghi and S. M. Hosseini, A new variant of L-curve for Tikhonov regularization, Journal of Computational and Applied Mathematics,
+Volume 231, Number 2, 2009, p. 914-924.
+!*/
+
+use ffi;
+use enums::GSLResult;
+
+pub struct MultifitLinearWorkspace {
+    w: *mut ffi::gsl_multifit_linear_workspace
+}
+
+impl MultifitLinearWorkspace {
+    /// This function allocates a workspace for fitting a model to at most n observations with at most p parameters. The workspace can be
+    /// used for any smaller problem sizes.
+    pub fn new(n: usize, p: usize) -> Option<MultifitLinearWorkspace> {
+        let tmp = unsafe { ffi::gsl_multifit_linear_alloc(n, p) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(MultifitLinearWorkspace {
+                w: tmp
+            })
+        }
+    }
+
+    /// This function computes the best-fit parameters c of the model y = X c for the observations y and the matrix of predictor
+    /// variables X, using the preallocated workspace. The p-by-p variance-covariance matrix of the model parameters cov is set by
+    /// estimating the error in the observations from the scatter of the data around the best-fit. The sum of squares of the residuals
+    /// from the best-fit, \chi^2, is returned.
+    ///
+    /// The best-fit is found by singular value decomposition of the matrix X. Any components which have zero singular value (to machine
+    /// precision) are discarded from the fit.
+    pub fn linear(&mut self, X: &::MatrixF64, y: &::VectorF64, c: &mut ::VectorF64, cov: &mut ::MatrixF64) -> GSLResult<f64> {
+        let mut chisq = 0f64;
+
+        GSLResult::from(unsafe { ffi::gsl_multifit_linear(ffi::FFI::unwrap(X), ffi::FFI::unwrap(y), ffi::FFI::unwrap(c),
+            ffi::FFI::unwrap(cov), &mut chisq, self.w) })?;
+        Ok(chisq)
+    }
+
+    /// This function computes the best-fit parameters c of the weighted model y = X c for the observations y with weights w and the
+    /// matrix of predictor variables X. The covariance matrix of the model parameters cov is computed with the given weights. The weighted
+    /// sum of squares of the residuals from the best-fit, \chi^2, is returned.
+    ///
+    /// If the weights are taken as w_i = 1/\sigma_i^2, where \sigma_i is the error on the i-th observation, the covariance matrix is the
+    /// covariance of the parameters. If the weights are only known up to a scale factor, the covariance matrix should be multiplied by
+    /// \chi^2 / (n - p).
+    pub fn wlinear(&mut self, X: &::MatrixF64, w: &::VectorF64, y: &::VectorF64, c: &mut ::VectorF64,
+        cov: &mut ::MatrixF64) -> GSLResult<f64> {
+        let mut chisq = 0f64;
+
+        GSLResult::from(unsafe { ffi::gsl_multifit_wlinear(ffi::FFI::unwrap(X), ffi::FFI::unwrap(w), ffi::FFI::unwrap(y),
+            ffi::FFI::unwrap(c), ffi::FFI::unwrap(cov), &mut chisq, self.w) })?;
+        Ok(chisq)
+    }
+
+    /// This function computes the best-fit parameters c of the model y = X c like `linear`, but discards the singular values s_i of X
+    /// for which s_i <= tol * s_0, where s_0 is the largest singular value. The sum of squares of the residuals from the best-fit,
+    /// \chi^2, and the effective rank of X (the number of singular values kept) are returned.
+    pub fn linear_tsvd(&mut self, X: &::MatrixF64, y: &::VectorF64, tol: f64, c: &mut ::VectorF64,
+        cov: &mut ::MatrixF64) -> GSLResult<(f64, usize)> {
+        let mut chisq = 0f64;
+        let mut rank = 0usize;
+
+        GSLResult::from(unsafe { ffi::gsl_multifit_linear_tsvd(ffi::FFI::unwrap(X), ffi::FFI::unwrap(y), tol, ffi::FFI::unwrap(c),
+            ffi::FFI::unwrap(cov), &mut chisq, &mut rank, self.w) })?;
+        Ok((chisq, rank))
+    }
+
+    /// This function computes the best-fit parameters c of the weighted model y = X c like `wlinear`, but discards the singular values
+    /// s_i of the weighted design matrix for which s_i <= tol * s_0, where s_0 is the largest singular value. The weighted sum of squares
+    /// of the residuals from the best-fit, \chi^2, and the effective rank are returned.
+    pub fn wlinear_tsvd(&mut self, X: &::MatrixF64, w: &::VectorF64, y: &::VectorF64, tol: f64, c: &mut ::VectorF64,
+        cov: &mut ::MatrixF64) -> GSLResult<(f64, usize)> {
+        let mut chisq = 0f64;
+        let mut rank = 0usize;
+
+        GSLResult::from(unsafe { ffi::gsl_multifit_wlinear_tsvd(ffi::FFI::unwrap(X), ffi::FFI::unwrap(w), ffi::FFI::unwrap(y), tol,
+            ffi::FFI::unwrap(c), ffi::FFI::unwrap(cov), &mut chisq, &mut rank, self.w) })?;
+        Ok((chisq, rank))
+    }
+
+    /// This function returns the effective rank of the matrix X of the last fit, which is the number of singular values s_i for which
+    /// s_i > tol * s_0, where s_0 is the largest singular value.
+    pub fn linear_rank(&self, tol: f64) -> usize {
+        unsafe { ffi::gsl_multifit_linear_rank(tol, self.w) }
+    }
+
+    /// This function returns the reciprocal condition number of the matrix X of the last fit, s_min / s_max. A small value indicates an
+    /// ill-conditioned problem, whose solution is sensitive to errors in the observations.
+    pub fn rcond(&self) -> f64 {
+        unsafe { (*self.w).rcond }
+    }
+
+    /// This function performs the SVD decomposition of the matrix X and stores the singular values and vectors in the workspace. It
+    /// must be called before `linear_solve`, `linear_lcurve` and the GCV functions, which all reuse the decomposition for many values
+    /// of the regularization parameter. X must be in standard form, see `linear_stdform1` and `linear_stdform2`.
+    pub fn linear_svd(&mut self, X: &::MatrixF64) -> GSLResult<()> {
+        GSLResult::from(unsafe { ffi::gsl_multifit_linear_svd(ffi::FFI::unwrap(X), self.w) })
+    }
+
+    /// This function performs the SVD decomposition of the matrix X like `linear_svd`, but first balances the columns of X to improve
+    /// the accuracy of the singular values.
+    pub fn linear_bsvd(&mut self, X: &::MatrixF64) -> GSLResult<()> {
+        GSLResult::from(unsafe { ffi::gsl_multifit_linear_bsvd(ffi::FFI::unwrap(X), self.w) })
+    }
+
+    /// This function computes the regularized best-fit parameters c which minimize the cost function
+    ///
+    /// \chi^2 = || y - X c ||^2 + \lambda^2 || c ||^2
+    ///
+    /// which is suitable for systems in standard form. The SVD of X must have been computed first with `linear_svd`. It returns the
+    /// residual norm ||y - X c|| and the solution norm ||c||.
+    pub fn linear_solve(&mut self, lambda: f64, X: &::MatrixF64, y: &::VectorF64, c: &mut ::VectorF64) -> GSLResult<(f64, f64)> {
+        let mut rnorm = 0f64;
+        let mut snorm = 0f64;
+
+        GSLResult::from(unsafe { ffi::gsl_multifit_linear_solve(lambda, ffi::FFI::unwrap(X), ffi::FFI::unwrap(y), ffi::FFI::unwrap(c),
+            &mut rnorm, &mut snorm, self.w) })?;
+        Ok((rnorm, snorm))
+    }
+
+    /// This function converts the least squares system (X, y) with the diagonal regularization matrix L = diag(l_0, ..., l_{p-1}) to
+    /// standard form (Xs, ys), so that it can be solved with `linear_solve`. If L is None, L = I is used and the system is simply copied.
+    /// Xs and ys have the same dimensions as X and y. The solution is converted back with `linear_genform1`.
+    pub fn linear_stdform1(&mut self, L: Option<&::VectorF64>, X: &::MatrixF64, y: &::VectorF64, Xs: &mut ::MatrixF64,
+        ys: &mut ::VectorF64) -> GSLResult<()> {
+        GSLResult::from(unsafe { ffi::gsl_multifit_linear_stdform1(L.map_or(::std::ptr::null(), |l| ffi::FFI::unwrap(l) as *const _),
+            ffi::FFI::unwrap(X), ffi::FFI::unwrap(y), ffi::FFI::unwrap(Xs), ffi::FFI::unwrap(ys), self.w) })
+    }
+
+    /// This function converts the weighted least squares system (X, y, w) with the diagonal regularization matrix L to standard form
+    /// (Xs, ys), like `linear_stdform1`.
+    pub fn linear_wstdform1(&mut self, L: Option<&::VectorF64>, X: &::MatrixF64, w: &::VectorF64, y: &::VectorF64,
+        Xs: &mut ::MatrixF64, ys: &mut ::VectorF64) -> GSLResult<()> {
+        GSLResult::from(unsafe { ffi::gsl_multifit_linear_wstdform1(L.map_or(::std::ptr::null(), |l| ffi::FFI::unwrap(l) as *const _),
+            ffi::FFI::unwrap(X), ffi::FFI::unwrap(w), ffi::FFI::unwrap(y), ffi::FFI::unwrap(Xs), ffi::FFI::unwrap(ys), self.w) })
+    }
+
+    /// This function converts the least squares system (X, y) with the general m-by-p regularization matrix L to standard form
+    /// (Xs, ys). L must first be factored with `multifit::linear_L_decomp`, giving LQR and Ltau.
+    ///
+    /// If m >= p, Xs is n-by-p and ys has length n. If m < p, Xs is (n - p + m)-by-m and ys has length n - p + m, and the matrix M, of
+    /// size n-by-p, must be kept for `linear_genform2`.
+    pub fn linear_stdform2(&mut self, LQR: &::MatrixF64, Ltau: &::VectorF64, X: &::MatrixF64, y: &::VectorF64, Xs: &mut ::MatrixF64,
+        ys: &mut ::VectorF64, M: &mut ::MatrixF64) -> GSLResult<()> {
+        GSLResult::from(unsafe { ffi::gsl_multifit_linear_stdform2(ffi::FFI::unwrap(LQR), ffi::FFI::unwrap(Ltau), ffi::FFI::unwrap(X),
+            ffi::FFI::unwrap(y), ffi::FFI::unwrap(Xs), ffi::FFI::unwrap(ys), ffi::FFI::unwrap(M), self.w) })
+    }
+
+    /// This function converts the weighted least squares system (X, y, w) with the general regularization matrix L to standard form
+    /// (Xs, ys), like `linear_stdform2`.
+    pub fn linear_wstdform2(&mut self, LQR: &::MatrixF64, Ltau: &::VectorF64, X: &::MatrixF64, w: &::VectorF64, y: &::VectorF64,
+        Xs: &mut ::MatrixF64, ys: &mut ::VectorF64, M: &mut ::MatrixF64) -> GSLResult<()> {
+        GSLResult::from(unsafe { ffi::gsl_multifit_linear_wstdform2(ffi::FFI::unwrap(LQR), ffi::FFI::unwrap(Ltau), ffi::FFI::unwrap(X),
+            ffi::FFI::unwrap(w), ffi::FFI::unwrap(y), ffi::FFI::unwrap(Xs), ffi::FFI::unwrap(ys), ffi::FFI::unwrap(M), self.w) })
+    }
+
+    /// This function converts the solution cs of the standard form system back to the solution c of the original system, for the
+    /// diagonal regularization matrix L used with `linear_stdform1`. If L is None, L = I is used.
+    pub fn linear_genform1(&mut self, L: Option<&::VectorF64>, cs: &::VectorF64, c: &mut ::VectorF64) -> GSLResult<()> {
+        GSLResult::from(unsafe { ffi::gsl_multifit_linear_genform1(L.map_or(::std::ptr::null(), |l| ffi::FFI::unwrap(l) as *const _),
+            ffi::FFI::unwrap(cs), ffi::FFI::unwrap(c), self.w) })
+    }
+
+    /// This function converts the solution cs of the standard form system back to the solution c of the original system (X, y), for the
+    /// general regularization matrix L and the matrix M computed by `linear_stdform2`.
+    pub fn linear_genform2(&mut self, LQR: &::MatrixF64, Ltau: &::VectorF64, X: &::MatrixF64, y: &::VectorF64, cs: &::VectorF64,
+        M: &::MatrixF64, c: &mut ::VectorF64) -> GSLResult<()> {
+        GSLResult::from(unsafe { ffi::gsl_multifit_linear_genform2(ffi::FFI::unwrap(LQR), ffi::FFI::unwrap(Ltau), ffi::FFI::unwrap(X),
+            ffi::FFI::unwrap(y), ffi::FFI::unwrap(cs), ffi::FFI::unwrap(M), ffi::FFI::unwrap(c), self.w) })
+    }
+
+    /// This function converts the solution cs of the standard form system back to the solution c of the original weighted system
+    /// (X, y, w), like `linear_genform2`.
+    pub fn linear_wgenform2(&mut self, LQR: &::MatrixF64, Ltau: &::VectorF64, X: &::MatrixF64, w: &::VectorF64, y: &::VectorF64,
+        cs: &::VectorF64, M: &::MatrixF64, c: &mut ::VectorF64) -> GSLResult<()> {
+        GSLResult::from(unsafe { ffi::gsl_multifit_linear_wgenform2(ffi::FFI::unwrap(LQR), ffi::FFI::unwrap(Ltau), ffi::FFI::unwrap(X),
+            ffi::FFI::unwrap(w), ffi::FFI::unwrap(y), ffi::FFI::unwrap(cs), ffi::FFI::unwrap(M), ffi::FFI::unwrap(c), self.w) })
+    }
+
+    /// This function computes the p-by-p Sobolev regularization matrix L, for which
+    ///
+    /// ||L c||^2 = \sum_{k=0}^{kmax} \alpha_k^2 || L_k c ||^2
+    ///
+    /// where L_k is the discrete k-th derivative operator computed by `multifit::linear_Lk`. alpha has kmax + 1 elements.
+    pub fn linear_Lsobolev(&mut self, p: usize, kmax: usize, alpha: &::VectorF64, L: &mut ::MatrixF64) -> GSLResult<()> {
+        GSLResult::from(unsafe { ffi::gsl_multifit_linear_Lsobolev(p, kmax, ffi::FFI::unwrap(alpha), ffi::FFI::unwrap(L), self.w) })
+    }
+
+    /// This function computes the L-curve of the standard form system with observations y, whose SVD has been computed with
+    /// `linear_svd`. On output, reg_param holds the regularization parameters \lambda_i, sampled logarithmically between the smallest
+    /// and largest singular values, rho the corresponding residual norms ||y - X c|| and eta the solution norms ||c||. All three vectors
+    /// must have the same length, which is the number of points on the curve.
+    pub fn linear_lcurve(&mut self, y: &::VectorF64, reg_param: &mut ::VectorF64, rho: &mut ::VectorF64,
+        eta: &mut ::VectorF64) -> GSLResult<()> {
+        GSLResult::from(unsafe { ffi::gsl_multifit_linear_lcurve(ffi::FFI::unwrap(y), ffi::FFI::unwrap(reg_param), ffi::FFI::unwrap(rho),
+            ffi::FFI::unwrap(eta), self.w) })
+    }
+
+    /// This function performs the first step of the generalized cross validation (GCV) of the standard form system with observations y,
+    /// whose SVD has been computed with `linear_svd`. It fills reg_param with regularization parameters sampled logarithmically
+    /// between the smallest and largest singular values, UTy with the vector U^T y, of length p, and returns the constant \delta_0,
+    /// the squared norm of the component of y outside of the column space of X.
+    pub fn linear_gcv_init(&mut self, y: &::VectorF64, reg_param: &mut ::VectorF64, UTy: &mut ::VectorF64) -> GSLResult<f64> {
+        let mut delta0 = 0f64;
+
+        GSLResult::from(unsafe { ffi::gsl_multifit_linear_gcv_init(ffi::FFI::unwrap(y), ffi::FFI::unwrap(reg_param),
+            ffi::FFI::unwrap(UTy), &mut delta0, self.w) })?;
+        Ok(delta0)
+    }
+
+    /// This function computes the GCV curve
+    ///
+    /// G(\lambda) = || y - X c_\lambda ||^2 / Tr(I - X X_\lambda^I)^2
+    ///
+    /// for each regularization parameter of reg_param, using UTy and delta0 from `linear_gcv_init`, and stores it in G.
+    pub fn linear_gcv_curve(&mut self, reg_param: &::VectorF64, UTy: &::VectorF64, delta0: f64, G: &mut ::VectorF64) -> GSLResult<()> {
+        GSLResult::from(unsafe { ffi::gsl_multifit_linear_gcv_curve(ffi::FFI::unwrap(reg_param), ffi::FFI::unwrap(UTy), delta0,
+            ffi::FFI::unwrap(G), self.w) })
+    }
+
+    /// This function returns the regularization parameter \lambda which minimizes the GCV curve G computed by `linear_gcv_curve`.
+    /// The minimum is first located on the sampled curve, and then refined with a one-dimensional minimization.
+    pub fn linear_gcv_min(&mut self, reg_param: &::VectorF64, UTy: &::VectorF64, G: &::VectorF64, delta0: f64) -> GSLResult<f64> {
+        let mut lambda = 0f64;
+
+        GSLResult::from(unsafe { ffi::gsl_multifit_linear_gcv_min(ffi::FFI::unwrap(reg_param), ffi::FFI::unwrap(UTy),
+            ffi::FFI::unwrap(G), delta0, &mut lambda, self.w) })?;
+        Ok(lambda)
+    }
+
+    /// This function returns the value of the GCV curve G(\lambda) for the regularization parameter lambda, using UTy and delta0 from
+    /// `linear_gcv_init`.
+    pub fn linear_gcv_calc(&mut self, lambda: f64, UTy: &::VectorF64, delta0: f64) -> f64 {
+        unsafe { ffi::gsl_multifit_linear_gcv_calc(lambda, ffi::FFI::unwrap(UTy), delta0, self.w) }
+    }
+
+    /// This function combines the GCV steps above: it computes the GCV curve G of the standard form system with observations y,
+    /// sampled at the regularization parameters stored in reg_param on output, and returns the optimal regularization parameter
+    /// \lambda together with the value G(\lambda). The SVD of X must have been computed first with `linear_svd`.
+    pub fn linear_gcv(&mut self, y: &::VectorF64, reg_param: &mut ::VectorF64, G: &mut ::VectorF64) -> GSLResult<(f64, f64)> {
+        let mut lambda = 0f64;
+        let mut G_lambda = 0f64;
+
+        GSLResult::from(unsafe { ffi::gsl_multifit_linear_gcv(ffi::FFI::unwrap(y), ffi::FFI::unwrap(reg_param), ffi::FFI::unwrap(G),
+            &mut lambda, &mut G_lambda, self.w) })?;
+        Ok((lambda, G_lambda))
+    }
+}
+
+impl Drop for MultifitLinearWorkspace {
+    fn drop(&mut self) {
+        unsafe { ffi::gsl_multifit_linear_free(self.w) };
+        self.w = ::std::ptr::null_mut();
+    }
+}
+
+impl ffi::FFI<ffi::gsl_multifit_linear_workspace> for MultifitLinearWorkspace {
+    fn wrap(w: *mut ffi::gsl_multifit_linear_workspace) -> MultifitLinearWorkspace {
+        MultifitLinearWorkspace {
+            w: w
+        }
+    }
+
+    fn unwrap(w: &MultifitLinearWorkspace) -> *mut ffi::gsl_multifit_linear_workspace {
+        w.w
+    }
+}
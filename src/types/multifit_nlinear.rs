@@ -0,0 +1,561 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+/*!
+#Nonlinear Least-Squares Fitting with Trust Region Methods
+
+This chapter describes functions for multidimensional nonlinear least-squares fitting, using the trust region framework of GSL. It
+supersedes the MultiFitFdfSolver, and offers several methods for computing the trial step, different scaling strategies and the
+automatic computation of the Jacobian by finite differences.
+
+##Overview
+
+The problem of multidimensional nonlinear least-squares fitting requires the minimization of the squared residuals of n functions, f_i,
+in p parameters, x_i,
+
+\Phi(x) = (1/2) || f(x) ||^2 = (1/2) \sum_{i=1}^{n} f_i(x_1, ..., x_p)^2
+
+In trust region methods, the objective function \Phi(x) is approximated by a model function m_k(\delta) in the vicinity of some point
+x_k. The model function is often simply a second order Taylor series expansion around the point x_k, ie:
+
+\Phi(x_k + \delta) ~=~ m_k(\delta) = \Phi(x_k) + g_k^T \delta + 1/2 \delta^T B_k \delta
+
+where g_k = J_k^T f_k is the gradient vector at the point x_k and B_k is the Hessian matrix at x_k, or some approximation to it. We then
+solve the sub-problem of minimizing m_k(\delta) over the region || D_k \delta || <= \Delta_k, where D_k is a diagonal scaling matrix and
+\Delta_k > 0 is the trust region radius. If the step reduces \Phi(x) enough, it is accepted and the trust region may be expanded,
+otherwise the step is rejected and the trust region is shrunk.
+
+##Providing the function to minimize
+
+The residual function is given as a closure, in the same way as for `MultiRootFunction`. The closure receives the current position x and
+stores the n residuals f_i(x) in f. The Jacobian J_{ij} = d f_i / d x_j can be given as a second closure; when it is not provided, it
+is approximated with finite differences, as selected by the fdtype parameter. The geodesic acceleration method additionally uses the
+second directional derivative of f along the velocity v, fvv = \sum_{\alpha\beta} v_\alpha v_\beta d^2 f / dx_\alpha dx_\beta, which is
+also approximated with finite differences when its closure is not provided. Returning an error from a closure aborts the current
+iteration and the error is forwarded to the caller.
+
+##References and Further Reading
+
+J. J. Moré, The Levenberg-Marquardt Algorithm: Implementation and Theory, Lecture Notes in Mathematics, v630, 1978.
+
+M. K. Transtrum, B. B. Machta, and J. P. Sethna, Why are nonlinear fits to data so challenging?, Phys. Rev. Lett., 104, 060201, 2010.
+
+J. Nocedal and S. J. Wright, Numerical Optimization, 2nd edition, Springer, 2006.
+!*/
+
+use ffi;
+use enums::{self, GSLResult};
+use libc::{c_int, c_void};
+use types::{vector, matrix};
+
+pub struct MultifitNlinearFunction<'a> {
+    f: &'a mut FnMut(&::VectorF64, &mut ::VectorF64) -> GSLResult<()>,
+    df: Option<&'a mut FnMut(&::VectorF64, &mut ::MatrixF64) -> GSLResult<()>>,
+    fvv: Option<&'a mut FnMut(&::VectorF64, &::VectorF64, &mut ::VectorF64) -> GSLResult<()>>,
+    n: usize,
+    p: usize,
+}
+
+impl<'a> MultifitNlinearFunction<'a> {
+    /// Defines a system of n residual functions in p parameters. The closure f stores the vector result f(x) in its second argument for
+    /// the argument given in the first one. The Jacobian is approximated with finite differences.
+    pub fn new(n: usize, p: usize, f: &'a mut FnMut(&::VectorF64, &mut ::VectorF64) -> GSLResult<()>) -> MultifitNlinearFunction<'a> {
+        MultifitNlinearFunction {
+            f: f,
+            df: None,
+            fvv: None,
+            n: n,
+            p: p,
+        }
+    }
+
+    /// Same as `new` but with an additional closure df which stores the n-by-p Jacobian matrix J_ij = d f_i(x) / d x_j.
+    pub fn with_df(n: usize, p: usize,
+                   f: &'a mut FnMut(&::VectorF64, &mut ::VectorF64) -> GSLResult<()>,
+                   df: &'a mut FnMut(&::VectorF64, &mut ::MatrixF64) -> GSLResult<()>) -> MultifitNlinearFunction<'a> {
+        MultifitNlinearFunction {
+            f: f,
+            df: Some(df),
+            fvv: None,
+            n: n,
+            p: p,
+        }
+    }
+
+    /// Same as `with_df` but with an additional closure fvv which stores the second directional derivative of f along the vector v,
+    /// given as second argument, for the geodesic acceleration method. The Jacobian closure is optional here.
+    pub fn with_fvv(n: usize, p: usize,
+                    f: &'a mut FnMut(&::VectorF64, &mut ::VectorF64) -> GSLResult<()>,
+                    df: Option<&'a mut FnMut(&::VectorF64, &mut ::MatrixF64) -> GSLResult<()>>,
+                    fvv: &'a mut FnMut(&::VectorF64, &::VectorF64, &mut ::VectorF64) -> GSLResult<()>) -> MultifitNlinearFunction<'a> {
+        MultifitNlinearFunction {
+            f: f,
+            df: df,
+            fvv: Some(fvv),
+            n: n,
+            p: p,
+        }
+    }
+
+    fn to_raw(&mut self) -> ffi::gsl_multifit_nlinear_fdf {
+        ffi::gsl_multifit_nlinear_fdf {
+            f: f_handler,
+            df: if self.df.is_some() { Some(df_handler) } else { None },
+            fvv: if self.fvv.is_some() { Some(fvv_handler) } else { None },
+            n: self.n,
+            p: self.p,
+            params: self as *mut _ as *mut c_void,
+            nevalf: 0,
+            nevaldf: 0,
+            nevalfvv: 0,
+        }
+    }
+}
+
+extern fn f_handler(x: *const ffi::gsl_vector, params: *mut c_void, f: *mut ffi::gsl_vector) -> enums::Value {
+    let sys = unsafe { &mut *(params as *mut MultifitNlinearFunction) };
+    let x = vector::wrap(x as *mut ffi::gsl_vector);
+    let mut f = vector::wrap(f);
+
+    match (sys.f)(&x, &mut f) {
+        Ok(()) => enums::Value::Success,
+        Err(e) => e,
+    }
+}
+
+extern fn df_handler(x: *const ffi::gsl_vector, params: *mut c_void, j: *mut ffi::gsl_matrix) -> enums::Value {
+    let sys = unsafe { &mut *(params as *mut MultifitNlinearFunction) };
+    let x = vector::wrap(x as *mut ffi::gsl_vector);
+    let mut j = matrix::wrap(j);
+
+    let r = match sys.df {
+        Some(ref mut df) => df(&x, &mut j),
+        None => Err(enums::Value::Inval),
+    };
+    match r {
+        Ok(()) => enums::Value::Success,
+        Err(e) => e,
+    }
+}
+
+extern fn fvv_handler(x: *const ffi::gsl_vector, v: *const ffi::gsl_vector, params: *mut c_void,
+                      fvv: *mut ffi::gsl_vector) -> enums::Value {
+    let sys = unsafe { &mut *(params as *mut MultifitNlinearFunction) };
+    let x = vector::wrap(x as *mut ffi::gsl_vector);
+    let v = vector::wrap(v as *mut ffi::gsl_vector);
+    let mut fvv = vector::wrap(fvv);
+
+    let r = match sys.fvv {
+        Some(ref mut f) => f(&x, &v, &mut fvv),
+        None => Err(enums::Value::Inval),
+    };
+    match r {
+        Ok(()) => enums::Value::Success,
+        Err(e) => e,
+    }
+}
+
+/// The parameters of a nonlinear least-squares solver, given to `MultifitNlinearWorkspace::new`. The default values, as returned by
+/// `Default::default`, use the Levenberg-Marquardt method with More scaling, a QR decomposition of the Jacobian and forward finite
+/// differences.
+#[derive(Clone, Copy)]
+pub struct MultifitNlinearParameters {
+    /// The trust region method used to compute the trial step.
+    pub trs: MultifitNlinearTrs,
+    /// The scaling method used to build the matrix D_k, which defines the shape of the trust region.
+    pub scale: MultifitNlinearScale,
+    /// The method used to solve the linear least squares systems arising in the computation of the trial step.
+    pub solver: MultifitNlinearSolver,
+    /// The finite difference scheme used to approximate the Jacobian when no closure computes it.
+    pub fdtype: ::MultifitNlinearFdType,
+    /// The factor by which the trust region is increased after an accepted step.
+    pub factor_up: f64,
+    /// The factor by which the trust region is decreased after a rejected step.
+    pub factor_down: f64,
+    /// The maximum allowed ratio of the acceleration term to the velocity term for the geodesic acceleration method. Steps with a larger
+    /// ratio are rejected.
+    pub avmax: f64,
+    /// The relative step size used for the finite difference approximation of the Jacobian.
+    pub h_df: f64,
+    /// The step size used for the finite difference approximation of fvv.
+    pub h_fvv: f64
+}
+
+impl MultifitNlinearParameters {
+    fn to_raw(&self) -> ffi::gsl_multifit_nlinear_parameters {
+        ffi::gsl_multifit_nlinear_parameters {
+            trs: self.trs.t,
+            scale: self.scale.t,
+            solver: self.solver.t,
+            fdtype: self.fdtype,
+            factor_up: self.factor_up,
+            factor_down: self.factor_down,
+            avmax: self.avmax,
+            h_df: self.h_df,
+            h_fvv: self.h_fvv
+        }
+    }
+}
+
+impl Default for MultifitNlinearParameters {
+    fn default() -> MultifitNlinearParameters {
+        let p = unsafe { ffi::gsl_multifit_nlinear_default_parameters() };
+
+        MultifitNlinearParameters {
+            trs: MultifitNlinearTrs { t: p.trs },
+            scale: MultifitNlinearScale { t: p.scale },
+            solver: MultifitNlinearSolver { t: p.solver },
+            fdtype: p.fdtype,
+            factor_up: p.factor_up,
+            factor_down: p.factor_down,
+            avmax: p.avmax,
+            h_df: p.h_df,
+            h_fvv: p.h_fvv
+        }
+    }
+}
+
+// Holds the closure given to `MultifitNlinearWorkspace::driver` and the workspace it is called with.
+struct DriverCallback<'a, 'b: 'a> {
+    f: &'a mut FnMut(usize, &MultifitNlinearWorkspace<'b>),
+    w: *const MultifitNlinearWorkspace<'b>
+}
+
+extern fn driver_callback_handler(iter: usize, params: *mut c_void, _: *const ffi::gsl_multifit_nlinear_workspace) {
+    let cb = unsafe { &mut *(params as *mut DriverCallback) };
+
+    (cb.f)(iter, unsafe { &*cb.w });
+}
+
+pub struct MultifitNlinearWorkspace<'a> {
+    w: *mut ffi::gsl_multifit_nlinear_workspace,
+    function: Option<Box<MultifitNlinearFunction<'a>>>,
+    raw: Option<Box<ffi::gsl_multifit_nlinear_fdf>>,
+}
+
+impl<'a> MultifitNlinearWorkspace<'a> {
+    /// This function returns a newly allocated instance of a solver of type t for n observations and p parameters, using the given
+    /// parameters. The number of observations n must be greater than or equal to parameters p.
+    ///
+    /// If there is insufficient memory to create the solver then the function returns None.
+    pub fn new(t: &MultifitNlinearType, params: &MultifitNlinearParameters, n: usize, p: usize) -> Option<MultifitNlinearWorkspace<'a>> {
+        let raw = params.to_raw();
+        let tmp = unsafe { ffi::gsl_multifit_nlinear_alloc(t.t, &raw, n, p) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(MultifitNlinearWorkspace {
+                w: tmp,
+                function: None,
+                raw: None,
+            })
+        }
+    }
+
+    /// This function initializes, or reinitializes, the solver to use the function fdf and the initial guess x.
+    pub fn init(&mut self, fdf: MultifitNlinearFunction<'a>, x: &::VectorF64) -> GSLResult<()> {
+        let mut function = Box::new(fdf);
+        let mut raw = Box::new(function.to_raw());
+        let r = unsafe { ffi::gsl_multifit_nlinear_init(ffi::FFI::unwrap(x), &mut *raw, self.w) };
+
+        self.function = Some(function);
+        self.raw = Some(raw);
+        GSLResult::from(r)
+    }
+
+    /// This function initializes, or reinitializes, the solver like `init`, for a weighted least-squares problem with the weights wts,
+    /// of length n. The residuals minimized are then \sqrt{w_i} f_i(x).
+    pub fn winit(&mut self, fdf: MultifitNlinearFunction<'a>, x: &::VectorF64, wts: &::VectorF64) -> GSLResult<()> {
+        let mut function = Box::new(fdf);
+        let mut raw = Box::new(function.to_raw());
+        let r = unsafe { ffi::gsl_multifit_nlinear_winit(ffi::FFI::unwrap(x), ffi::FFI::unwrap(wts), &mut *raw, self.w) };
+
+        self.function = Some(function);
+        self.raw = Some(raw);
+        GSLResult::from(r)
+    }
+
+    /// This function performs a single iteration of the solver. If the iteration encounters an unexpected problem then an error code
+    /// will be returned. The solver maintains a current estimate of the best-fit parameters at all times.
+    pub fn iterate(&mut self) -> GSLResult<()> {
+        if self.raw.is_none() {
+            rgsl_error!("solver has not been initialized", ::Value::Inval);
+            return Err(::Value::Inval);
+        }
+        GSLResult::from(unsafe { ffi::gsl_multifit_nlinear_iterate(self.w) })
+    }
+
+    /// This function iterates the solver for a maximum of maxiter iterations. After each iteration, the system is tested for
+    /// convergence with the error tolerances xtol, gtol and ftol, as in `test`. The closure callback, if given, is called after each
+    /// iteration with the iteration number and the solver, to monitor the progress of the fit.
+    ///
+    /// On success, the reason for convergence is returned: 1 for a small step size, 2 for a small gradient. If the method fails to
+    /// converge within maxiter iterations, ::Value::MaxIter is returned.
+    pub fn driver(&mut self, maxiter: usize, xtol: f64, gtol: f64, ftol: f64,
+                  callback: Option<&mut FnMut(usize, &MultifitNlinearWorkspace<'a>)>) -> GSLResult<i32> {
+        if self.raw.is_none() {
+            rgsl_error!("solver has not been initialized", ::Value::Inval);
+            return Err(::Value::Inval);
+        }
+        let mut info: c_int = 0;
+        let r = match callback {
+            Some(f) => {
+                let mut cb = DriverCallback { f: f, w: self as *const MultifitNlinearWorkspace<'a> };
+
+                unsafe { ffi::gsl_multifit_nlinear_driver(maxiter, xtol, gtol, ftol, Some(driver_callback_handler),
+                    &mut cb as *mut DriverCallback as *mut c_void, &mut info, self.w) }
+            }
+            None => unsafe { ffi::gsl_multifit_nlinear_driver(maxiter, xtol, gtol, ftol, None, ::std::ptr::null_mut(), &mut info,
+                self.w) }
+        };
+
+        GSLResult::from(r)?;
+        Ok(info)
+    }
+
+    /// This function tests for convergence of the solver using the three following criteria:
+    ///
+    /// Testing for a small step size relative to the current parameter vector, |\delta_i| <= xtol (|x_i| + xtol) for each component.
+    ///
+    /// Testing for a small gradient, ||g||_inf <= gtol, where g is the gradient of \Phi(x) scaled by the parameters.
+    ///
+    /// Testing for a small relative change in the residual vector, ||f(x+\delta) - f(x)|| <= ftol max(||f(x)||, 1). This test is
+    /// currently disabled by GSL, ftol is accepted for future use.
+    ///
+    /// If convergence is achieved, the reason is returned: 1 for a small step size, 2 for a small gradient. Otherwise the error
+    /// ::Value::Continue is returned.
+    pub fn test(&self, xtol: f64, gtol: f64, ftol: f64) -> GSLResult<i32> {
+        let mut info: c_int = 0;
+
+        GSLResult::from(unsafe { ffi::gsl_multifit_nlinear_test(xtol, gtol, ftol, &mut info, self.w) })?;
+        Ok(info)
+    }
+
+    /// This function returns the name of the solver.
+    pub fn name(&self) -> Option<String> {
+        let tmp = unsafe { ffi::gsl_multifit_nlinear_name(self.w) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            unsafe { Some(String::from_utf8_lossy(::std::ffi::CStr::from_ptr(tmp).to_bytes()).to_string()) }
+        }
+    }
+
+    /// This function returns the name of the trust region method used by the solver.
+    pub fn trs_name(&self) -> Option<String> {
+        let tmp = unsafe { ffi::gsl_multifit_nlinear_trs_name(self.w) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            unsafe { Some(String::from_utf8_lossy(::std::ffi::CStr::from_ptr(tmp).to_bytes()).to_string()) }
+        }
+    }
+
+    /// This function returns the current position x (i.e. best-fit parameters) of the solver.
+    pub fn position(&self) -> ::VectorF64 {
+        vector::wrap(unsafe { ffi::gsl_multifit_nlinear_position(self.w) })
+    }
+
+    /// This function returns the current residual vector f(x) of the solver. For weighted systems, the residual vector includes the
+    /// weighting factor \sqrt{W}.
+    pub fn residual(&self) -> ::VectorF64 {
+        vector::wrap(unsafe { ffi::gsl_multifit_nlinear_residual(self.w) })
+    }
+
+    /// This function returns the current Jacobian matrix J of the solver. For weighted systems, the Jacobian matrix includes the
+    /// weighting factor \sqrt{W}.
+    pub fn jac(&self) -> ::MatrixF64 {
+        matrix::wrap(unsafe { ffi::gsl_multifit_nlinear_jac(self.w) })
+    }
+
+    /// This function returns the number of iterations performed by the solver since it was initialized.
+    pub fn niter(&self) -> usize {
+        unsafe { ffi::gsl_multifit_nlinear_niter(self.w) }
+    }
+
+    /// This function returns the reciprocal condition number of the Jacobian matrix at the current position. It is only available with
+    /// the QR and SVD solvers.
+    pub fn rcond(&self) -> GSLResult<f64> {
+        let mut rcond = 0f64;
+
+        GSLResult::from(unsafe { ffi::gsl_multifit_nlinear_rcond(&mut rcond, self.w) })?;
+        Ok(rcond)
+    }
+
+    /// This function returns the current ratio |a| / |v| of the acceleration term to the velocity term, when the geodesic acceleration
+    /// method is used.
+    pub fn avratio(&self) -> f64 {
+        unsafe { ffi::gsl_multifit_nlinear_avratio(self.w) }
+    }
+
+    /// This function returns the number of evaluations of the residual function, of the Jacobian and of fvv since the solver was
+    /// initialized. The evaluations made to compute finite differences are counted as residual function evaluations.
+    pub fn nevals(&self) -> (usize, usize, usize) {
+        match self.raw {
+            Some(ref raw) => (raw.nevalf, raw.nevaldf, raw.nevalfvv),
+            None => (0, 0, 0)
+        }
+    }
+}
+
+impl<'a> Drop for MultifitNlinearWorkspace<'a> {
+    fn drop(&mut self) {
+        unsafe { ffi::gsl_multifit_nlinear_free(self.w) };
+        self.w = ::std::ptr::null_mut();
+    }
+}
+
+impl<'a> ffi::FFI<ffi::gsl_multifit_nlinear_workspace> for MultifitNlinearWorkspace<'a> {
+    fn wrap(w: *mut ffi::gsl_multifit_nlinear_workspace) -> MultifitNlinearWorkspace<'a> {
+        MultifitNlinearWorkspace {
+            w: w,
+            function: None,
+            raw: None,
+        }
+    }
+
+    fn unwrap(w: &MultifitNlinearWorkspace) -> *mut ffi::gsl_multifit_nlinear_workspace {
+        w.w
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct MultifitNlinearType {
+    t: *const ffi::gsl_multifit_nlinear_type
+}
+
+impl MultifitNlinearType {
+    /// The trust region framework, which is currently the only type available. The method used to compute each step is selected with
+    /// the trs parameter.
+    pub fn trust() -> MultifitNlinearType {
+        MultifitNlinearType {
+            t: unsafe { ffi::gsl_multifit_nlinear_trust }
+        }
+    }
+}
+
+impl ffi::FFI<ffi::gsl_multifit_nlinear_type> for MultifitNlinearType {
+    fn wrap(t: *mut ffi::gsl_multifit_nlinear_type) -> MultifitNlinearType {
+        MultifitNlinearType {
+            t: t
+        }
+    }
+
+    fn unwrap(t: &MultifitNlinearType) -> *mut ffi::gsl_multifit_nlinear_type {
+        t.t as *mut ffi::gsl_multifit_nlinear_type
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct MultifitNlinearTrs {
+    t: *const ffi::gsl_multifit_nlinear_trs
+}
+
+impl MultifitNlinearTrs {
+    /// The Levenberg-Marquardt algorithm, which computes the trial step by solving the system
+    ///
+    /// (J^T J + \mu D^T D) \delta = -J^T f
+    ///
+    /// where \mu is related to the trust region radius. It is a robust and popular choice.
+    pub fn lm() -> MultifitNlinearTrs {
+        MultifitNlinearTrs {
+            t: unsafe { ffi::gsl_multifit_nlinear_trs_lm }
+        }
+    }
+
+    /// The Levenberg-Marquardt algorithm with geodesic acceleration, which adds a second order correction to the step using the
+    /// directional second derivative fvv. It can converge much faster on problems with long narrow valleys.
+    pub fn lmaccel() -> MultifitNlinearTrs {
+        MultifitNlinearTrs {
+            t: unsafe { ffi::gsl_multifit_nlinear_trs_lmaccel }
+        }
+    }
+
+    /// Powell's dogleg method, which approximates the optimal trajectory by two line segments, from the origin to the steepest descent
+    /// minimizer and from there to the Gauss-Newton step.
+    pub fn dogleg() -> MultifitNlinearTrs {
+        MultifitNlinearTrs {
+            t: unsafe { ffi::gsl_multifit_nlinear_trs_dogleg }
+        }
+    }
+
+    /// The double dogleg method, which moves the intermediate point of the dogleg path towards the Gauss-Newton step, often improving
+    /// the convergence.
+    pub fn ddogleg() -> MultifitNlinearTrs {
+        MultifitNlinearTrs {
+            t: unsafe { ffi::gsl_multifit_nlinear_trs_ddogleg }
+        }
+    }
+
+    /// The two dimensional subspace method, which minimizes the model function over the subspace spanned by the steepest descent and
+    /// Gauss-Newton directions, instead of following the dogleg path.
+    pub fn subspace2D() -> MultifitNlinearTrs {
+        MultifitNlinearTrs {
+            t: unsafe { ffi::gsl_multifit_nlinear_trs_subspace2D }
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct MultifitNlinearScale {
+    t: *const ffi::gsl_multifit_nlinear_scale
+}
+
+impl MultifitNlinearScale {
+    /// This damping strategy was originally suggested by Levenberg, and corresponds to D^T D = I. It is not scale invariant.
+    pub fn levenberg() -> MultifitNlinearScale {
+        MultifitNlinearScale {
+            t: unsafe { ffi::gsl_multifit_nlinear_scale_levenberg }
+        }
+    }
+
+    /// This damping strategy was suggested by Marquardt, and corresponds to D^T D = diag(J^T J). It is scale invariant, but is known to
+    /// increase susceptibility to parameter evaporation.
+    pub fn marquardt() -> MultifitNlinearScale {
+        MultifitNlinearScale {
+            t: unsafe { ffi::gsl_multifit_nlinear_scale_marquardt }
+        }
+    }
+
+    /// This damping strategy was suggested by Moré, and corresponds to D^T D = max(diag(J^T J)), the maximum being taken over all
+    /// previous iterations. It is scale invariant and is the default.
+    pub fn more() -> MultifitNlinearScale {
+        MultifitNlinearScale {
+            t: unsafe { ffi::gsl_multifit_nlinear_scale_more }
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct MultifitNlinearSolver {
+    t: *const ffi::gsl_multifit_nlinear_solver
+}
+
+impl MultifitNlinearSolver {
+    /// This method solves the normal equations with a Cholesky decomposition of J^T J + \mu D^T D. It is the fastest method, but is
+    /// the most sensitive to ill-conditioning of the Jacobian.
+    pub fn cholesky() -> MultifitNlinearSolver {
+        MultifitNlinearSolver {
+            t: unsafe { ffi::gsl_multifit_nlinear_solver_cholesky }
+        }
+    }
+
+    /// This method uses a QR decomposition of the Jacobian. It is more accurate than the Cholesky method, and is the default.
+    pub fn qr() -> MultifitNlinearSolver {
+        MultifitNlinearSolver {
+            t: unsafe { ffi::gsl_multifit_nlinear_solver_qr }
+        }
+    }
+
+    /// This method uses a singular value decomposition of the Jacobian. It is the most accurate and the slowest method, and can be
+    /// useful for badly conditioned problems.
+    pub fn svd() -> MultifitNlinearSolver {
+        MultifitNlinearSolver {
+            t: unsafe { ffi::gsl_multifit_nlinear_solver_svd }
+        }
+    }
+}
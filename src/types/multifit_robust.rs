@@ -0,0 +1,314 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+/*!
+#Robust Linear Regression
+
+Ordinary least squares (OLS) models are often heavily influenced by the presence of outliers. Outliers are data points which do not
+follow the general trend of the other observations, although there is strictly no precise definition of an outlier. Robust linear
+regression refers to regression algorithms which are robust to outliers. The most common type of robust regression is M-estimation.
+The general M-estimator minimizes the objective function
+
+\sum_i \rho(e_i) = \sum_i \rho (y_i - Y(c, x_i))
+
+where e_i = y_i - Y(c, x_i) is the residual of the ith data point, and \rho(e_i) is a function which should have the following
+properties:
+
+\rho(e) \ge 0
+
+\rho(0) = 0
+
+\rho(-e) = \rho(e)
+
+\rho(e_1) > \rho(e_2) for |e_1| > |e_2|
+
+The special case of ordinary least squares is given by \rho(e_i) = e_i^2. Letting \psi = \rho' be the derivative of \rho, differentiating
+the objective function with respect to the coefficients c and setting the partial derivatives to zero produces the system of equations
+
+\sum_i \psi(e_i) X_i = 0
+
+where X_i is a vector containing row i of the design matrix X. Next, we define a weight function w(e) = \psi(e)/e, and let w_i = w(e_i):
+
+\sum_i w_i e_i X_i = 0
+
+This system of equations is equivalent to solving a weighted ordinary least squares problem, minimizing \chi^2 = \sum_i w_i e_i^2. The
+weights however, depend on the residuals e_i, which depend on the coefficients c, which depend on the weights. Therefore, an iterative
+solution is used, called Iteratively Reweighted Least Squares (IRLS).
+
+ * Compute initial estimates of the coefficients c^{(0)} using ordinary least squares
+ * For iteration k, form the residuals e_i^{(k)} = (y_i - X_i c^{(k-1)})/(t \sigma^{(k)} \sqrt{1 - h_i}), where t is a tuning constant
+   depending on the choice of \psi, and h_i are the statistical leverages (diagonal elements of the matrix X (X^T X)^{-1} X^T).
+   Including t and h_i in the residual calculation has been shown to improve the convergence of the method. The residual standard
+   deviation is approximated as \sigma^{(k)} = MAD / 0.6745, where MAD is the Median-Absolute-Deviation of the n-p largest residuals
+   from the previous iteration.
+ * Compute new weights w_i^{(k)} = \psi(e_i^{(k)})/e_i^{(k)}.
+ * Compute new coefficients c^{(k)} by solving the weighted least squares problem with weights w_i^{(k)}.
+ * Steps 2 through 4 are iterated until the coefficients converge or until some maximum iteration limit is reached.
+
+##References and Further Reading
+
+P. J. Huber, Robust Statistics, Wiley, 1981.
+
+P. W. Holland and R. E. Welsch, Robust regression using iteratively reweighted least-squares, Communications in Statistics - Theory and
+Methods, Volume 6, Number 9, 1977, p. 813-827.
+!*/
+
+use ffi;
+use enums::GSLResult;
+
+// Copies the elements of a vector owned by the workspace.
+fn vector_to_vec(v: *const ffi::gsl_vector) -> Vec<f64> {
+    if v.is_null() {
+        return Vec::new();
+    }
+    unsafe { (0..(*v).size).map(|i| *(*v).data.offset((i * (*v).stride) as isize)).collect() }
+}
+
+/// The statistics of the last robust fit, as returned by `MultifitRobustWorkspace::statistics`.
+#[derive(Clone, Debug)]
+pub struct RobustStats {
+    /// Standard deviation of the residuals of the initial ordinary least squares fit.
+    pub sigma_ols: f64,
+    /// Estimate of the standard deviation of the residuals given by the median absolute deviation of the final residuals, divided
+    /// by 0.6745.
+    pub sigma_mad: f64,
+    /// Robust estimate of the standard deviation of the residuals, as given by Street et al.
+    pub sigma_rob: f64,
+    /// Final estimate of the standard deviation of the residuals, the maximum of sigma_rob and a combination of sigma_ols and
+    /// sigma_rob, which avoids underestimating it for small sample sizes.
+    pub sigma: f64,
+    /// Coefficient of determination, R^2 = 1 - SSE/SST.
+    pub Rsq: f64,
+    /// Adjusted coefficient of determination, 1 - (1 - R^2)(n - 1)/dof.
+    pub adj_Rsq: f64,
+    /// Root mean squared error of the final residuals, \sqrt{SSE/dof}.
+    pub rmse: f64,
+    /// Residual sum of squares, taking into account the robust covariance matrix.
+    pub sse: f64,
+    /// Number of degrees of freedom, n - p.
+    pub dof: usize,
+    /// Number of iterations performed by the IRLS algorithm if successful, otherwise the maximum number of iterations.
+    pub numit: usize,
+    /// The final weight of each observation.
+    pub weights: Vec<f64>,
+    /// The final residual y - X c of each observation.
+    pub residuals: Vec<f64>
+}
+
+pub struct MultifitRobustWorkspace {
+    w: *mut ffi::gsl_multifit_robust_workspace
+}
+
+impl MultifitRobustWorkspace {
+    /// This function allocates a workspace for fitting a model to n observations using p parameters. The type t specifies the function
+    /// \psi and can be selected from the constructors of MultifitRobustType.
+    pub fn new(t: &MultifitRobustType, n: usize, p: usize) -> Option<MultifitRobustWorkspace> {
+        let tmp = unsafe { ffi::gsl_multifit_robust_alloc(t.t, n, p) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(MultifitRobustWorkspace {
+                w: tmp
+            })
+        }
+    }
+
+    /// This function sets the tuning constant t used to adjust the residuals at each iteration to tune. Decreasing the tuning constant
+    /// increases the downweight assigned to large residuals, while increasing the tuning constant decreases the downweight assigned to
+    /// large residuals.
+    pub fn tune(&mut self, tune: f64) -> GSLResult<()> {
+        GSLResult::from(unsafe { ffi::gsl_multifit_robust_tune(tune, self.w) })
+    }
+
+    /// This function sets the maximum number of iterations in the iteratively reweighted least squares algorithm to maxiter. By default,
+    /// this value is set to 100.
+    pub fn maxiter(&mut self, maxiter: usize) -> GSLResult<()> {
+        GSLResult::from(unsafe { ffi::gsl_multifit_robust_maxiter(maxiter, self.w) })
+    }
+
+    /// This function returns the name of the robust type specified to `new`.
+    pub fn name(&self) -> Option<String> {
+        let tmp = unsafe { ffi::gsl_multifit_robust_name(self.w) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            unsafe { Some(String::from_utf8_lossy(::std::ffi::CStr::from_ptr(tmp).to_bytes()).to_string()) }
+        }
+    }
+
+    /// This function returns the statistics of the last robust fit computed with this workspace.
+    pub fn statistics(&self) -> RobustStats {
+        let stats = unsafe { ffi::gsl_multifit_robust_statistics(self.w) };
+
+        RobustStats {
+            sigma_ols: stats.sigma_ols,
+            sigma_mad: stats.sigma_mad,
+            sigma_rob: stats.sigma_rob,
+            sigma: stats.sigma,
+            Rsq: stats.Rsq,
+            adj_Rsq: stats.adj_Rsq,
+            rmse: stats.rmse,
+            sse: stats.sse,
+            dof: stats.dof,
+            numit: stats.numit,
+            weights: vector_to_vec(stats.weights),
+            residuals: vector_to_vec(stats.r)
+        }
+    }
+
+    /// This function computes the vector of weights wts corresponding to the residuals r, using the weighting function of the robust
+    /// type and the current tuning constant. The residuals are scaled by the tuning constant and an estimate of their standard
+    /// deviation before the weighting function is applied.
+    pub fn weights(&mut self, r: &::VectorF64, wts: &mut ::VectorF64) -> GSLResult<()> {
+        GSLResult::from(unsafe { ffi::gsl_multifit_robust_weights(ffi::FFI::unwrap(r), ffi::FFI::unwrap(wts), self.w) })
+    }
+
+    /// This function computes the best-fit parameters c of the model y = X c for the observations y and the matrix of predictor
+    /// variables X, attempting to reduce the influence of outliers using the iteratively reweighted least squares algorithm. The
+    /// p-by-p variance-covariance matrix of the model parameters cov is estimated as \sigma^2 (X^T X)^{-1}, where \sigma is an
+    /// approximation of the residual standard deviation using the theory of robust regression.
+    ///
+    /// If the coefficients do not converge within the maximum iteration limit, the function returns the error ::Value::MaxIter. In this
+    /// case, the current estimates of the coefficients and covariance matrix are still stored in c and cov, and the internal fit
+    /// statistics are computed with these estimates.
+    pub fn robust(&mut self, X: &::MatrixF64, y: &::VectorF64, c: &mut ::VectorF64, cov: &mut ::MatrixF64) -> GSLResult<()> {
+        GSLResult::from(unsafe { ffi::gsl_multifit_robust(ffi::FFI::unwrap(X), ffi::FFI::unwrap(y), ffi::FFI::unwrap(c),
+            ffi::FFI::unwrap(cov), self.w) })
+    }
+
+    /// This function computes the vector of studentized residuals r_i = (y_i - (X c)_i) / (\sigma \sqrt{1 - h_i}) for the observations
+    /// y, coefficients c and matrix of predictor variables X. The residual standard deviation \sigma and the leverages h_i are those of
+    /// the last robust fit, so this function must be called after `robust`.
+    pub fn robust_residuals(&mut self, X: &::MatrixF64, y: &::VectorF64, c: &::VectorF64, r: &mut ::VectorF64) -> GSLResult<()> {
+        GSLResult::from(unsafe { ffi::gsl_multifit_robust_residuals(ffi::FFI::unwrap(X), ffi::FFI::unwrap(y), ffi::FFI::unwrap(c),
+            ffi::FFI::unwrap(r), self.w) })
+    }
+}
+
+impl Drop for MultifitRobustWorkspace {
+    fn drop(&mut self) {
+        unsafe { ffi::gsl_multifit_robust_free(self.w) };
+        self.w = ::std::ptr::null_mut();
+    }
+}
+
+impl ffi::FFI<ffi::gsl_multifit_robust_workspace> for MultifitRobustWorkspace {
+    fn wrap(w: *mut ffi::gsl_multifit_robust_workspace) -> MultifitRobustWorkspace {
+        MultifitRobustWorkspace {
+            w: w
+        }
+    }
+
+    fn unwrap(w: &MultifitRobustWorkspace) -> *mut ffi::gsl_multifit_robust_workspace {
+        w.w
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct MultifitRobustType {
+    t: *const ffi::gsl_multifit_robust_type
+}
+
+impl MultifitRobustType {
+    /// This specifies the bisquare type, which is the default.
+    pub fn default() -> MultifitRobustType {
+        MultifitRobustType {
+            t: unsafe { ffi::gsl_multifit_robust_default }
+        }
+    }
+
+    /// This is Tukey's biweight (bisquare) function and is a good general purpose choice for robust regression. The weight function is
+    /// given by
+    ///
+    /// w(e) = (1 - e^2)^2 if |e| <= 1, 0 if |e| > 1
+    ///
+    /// and the default tuning constant is t = 4.685.
+    pub fn bisquare() -> MultifitRobustType {
+        MultifitRobustType {
+            t: unsafe { ffi::gsl_multifit_robust_bisquare }
+        }
+    }
+
+    /// This is Cauchy's function, also known as the Lorentzian function. This function does not guarantee a unique solution, meaning
+    /// different choices of the coefficient vector c could minimize the objective function. Therefore this option should be used with
+    /// care. The weight function is given by
+    ///
+    /// w(e) = 1 / (1 + e^2)
+    ///
+    /// and the default tuning constant is t = 2.385.
+    pub fn cauchy() -> MultifitRobustType {
+        MultifitRobustType {
+            t: unsafe { ffi::gsl_multifit_robust_cauchy }
+        }
+    }
+
+    /// This is the fair \rho function, which guarantees a unique solution and has continuous derivatives to three orders. The weight
+    /// function is given by
+    ///
+    /// w(e) = 1 / (1 + |e|)
+    ///
+    /// and the default tuning constant is t = 1.400.
+    pub fn fair() -> MultifitRobustType {
+        MultifitRobustType {
+            t: unsafe { ffi::gsl_multifit_robust_fair }
+        }
+    }
+
+    /// This specifies Huber's \rho function, which is a parabola in the vicinity of zero and increases linearly for a given threshold
+    /// |e| > t. This function is also considered an excellent general purpose robust estimator, however, occasional difficulties can
+    /// be encountered due to the discontinuous first derivative of the \psi function. The weight function is given by
+    ///
+    /// w(e) = 1 if |e| <= 1, 1/|e| if |e| > 1
+    ///
+    /// and the default tuning constant is t = 1.345.
+    pub fn huber() -> MultifitRobustType {
+        MultifitRobustType {
+            t: unsafe { ffi::gsl_multifit_robust_huber }
+        }
+    }
+
+    /// This specifies the ordinary least squares solution, which can be useful for quickly checking the difference between the various
+    /// robust and OLS solutions. The weight function is given by
+    ///
+    /// w(e) = 1
+    ///
+    /// and the default tuning constant is t = 1.
+    pub fn ols() -> MultifitRobustType {
+        MultifitRobustType {
+            t: unsafe { ffi::gsl_multifit_robust_ols }
+        }
+    }
+
+    /// This specifies the Welsch function which can perform well in cases where the residuals have an exponential distribution. The
+    /// weight function is given by
+    ///
+    /// w(e) = \exp(-e^2)
+    ///
+    /// and the default tuning constant is t = 2.985.
+    pub fn welsch() -> MultifitRobustType {
+        MultifitRobustType {
+            t: unsafe { ffi::gsl_multifit_robust_welsch }
+        }
+    }
+
+    /// This function returns the default tuning constant of this type, which is used until it is changed with
+    /// `MultifitRobustWorkspace::tune`.
+    pub fn tuning_default(&self) -> f64 {
+        unsafe { (*self.t).tuning_default }
+    }
+}
+
+impl ffi::FFI<ffi::gsl_multifit_robust_type> for MultifitRobustType {
+    fn wrap(t: *mut ffi::gsl_multifit_robust_type) -> MultifitRobustType {
+        MultifitRobustType {
+            t: t
+        }
+    }
+
+    fn unwrap(t: &MultifitRobustType) -> *mut ffi::gsl_multifit_robust_type {
+        t.t as *mut ffi::gsl_multifit_robust_type
+    }
+}
@@ -0,0 +1,196 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+/*!
+#Large Dense Linear Systems
+
+This module is concerned with solving large dense least squares systems X c = y where the n-by-p matrix X has n >> p (ie: many more
+rows than columns). This type of matrix is called a "tall skinny" matrix, and for some applications, it may not be possible to fit the
+entire matrix in memory at once to use the standard SVD approach. Therefore, the algorithms in this module are designed to allow the user
+to construct smaller blocks of the matrix X and accumulate those blocks into the larger system one at a time. The algorithms in this
+module never need to store the entire matrix X in memory. The large linear least squares routines support data weights and Tikhonov
+regularization, and are designed to minimize the residual
+
+\chi^2 = || y - X c ||_W^2 + \lambda^2 || L c ||^2
+
+where y is the n-by-1 observation vector, X is the n-by-p design matrix, c is the p-by-1 solution vector, W = diag(w_1,...,w_n) is the
+data weighting matrix, L is an m-by-p regularization matrix, and \lambda is a regularization parameter.
+
+##Normal Equations Approach
+
+The normal equations approach accumulates the p-by-p matrix X^T X and the vector X^T y, block by block, and solves
+
+(X^T X + \lambda^2 I) c = X^T y
+
+with a Cholesky decomposition. It is very fast and needs only O(p^2) memory, but squaring the condition number of X makes it
+unsuitable for ill-conditioned problems.
+
+##Tall Skinny QR (TSQR) Approach
+
+The TSQR approach updates the R factor of the QR decomposition of X, block by block, and solves the regularized system with it. It is
+slower than the normal equations approach, but it is numerically stable, and is the recommended choice when X may be ill-conditioned.
+
+##Usage
+
+The system is built by calling `accumulate` with each block of rows (X_i, y_i) of the full system in turn, after converting it to
+standard form with `stdform1` if a regularization matrix L other than the identity is needed. `solve` then computes the solution for
+a given \lambda, along with the residual norm, which can be repeated for several values of \lambda without accumulating the data again.
+
+##References and Further Reading
+
+J. Demmel, L. Grigori, M. Hoemmen and J. Langou, Communication-optimal parallel and sequential QR and LU factorizations, UC Berkeley
+Technical Report No. UCB/EECS-2008-89, 2008.
+!*/
+
+use ffi;
+use enums::GSLResult;
+
+pub struct MultilargeLinearWorkspace {
+    w: *mut ffi::gsl_multilarge_linear_workspace
+}
+
+impl MultilargeLinearWorkspace {
+    /// This function allocates a workspace for solving large linear least squares systems with p parameters. The type t specifies the
+    /// method used to accumulate and solve the system.
+    pub fn new(t: &MultilargeLinearType, p: usize) -> Option<MultilargeLinearWorkspace> {
+        let tmp = unsafe { ffi::gsl_multilarge_linear_alloc(t.t, p) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(MultilargeLinearWorkspace {
+                w: tmp
+            })
+        }
+    }
+
+    /// This function returns the name of the method used by the workspace.
+    pub fn name(&self) -> Option<String> {
+        let tmp = unsafe { ffi::gsl_multilarge_linear_name(self.w) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            unsafe { Some(String::from_utf8_lossy(::std::ffi::CStr::from_ptr(tmp).to_bytes()).to_string()) }
+        }
+    }
+
+    /// This function resets the workspace so it can begin to accumulate a new least squares system.
+    pub fn reset(&mut self) -> GSLResult<()> {
+        GSLResult::from(unsafe { ffi::gsl_multilarge_linear_reset(self.w) })
+    }
+
+    /// This function accumulates the standard form block (X, y) into the current least squares system. X and y have the same number of
+    /// rows, which can be different with each call. X and y are overwritten on output, so they can be refilled with the next block.
+    pub fn accumulate(&mut self, X: &mut ::MatrixF64, y: &mut ::VectorF64) -> GSLResult<()> {
+        GSLResult::from(unsafe { ffi::gsl_multilarge_linear_accumulate(ffi::FFI::unwrap(X), ffi::FFI::unwrap(y), self.w) })
+    }
+
+    /// This function computes the solution c of the accumulated least squares system, for the regularization parameter lambda. A
+    /// lambda of zero gives the unregularized solution. It returns the residual norm ||y - X c|| and the solution norm ||c||.
+    pub fn solve(&mut self, lambda: f64, c: &mut ::VectorF64) -> GSLResult<(f64, f64)> {
+        let mut rnorm = 0f64;
+        let mut snorm = 0f64;
+
+        GSLResult::from(unsafe { ffi::gsl_multilarge_linear_solve(lambda, ffi::FFI::unwrap(c), &mut rnorm, &mut snorm, self.w) })?;
+        Ok((rnorm, snorm))
+    }
+
+    /// This function returns the reciprocal condition number of the accumulated least squares matrix. For the normal equations method,
+    /// this is the square root of the reciprocal condition number of X^T X.
+    pub fn rcond(&mut self) -> GSLResult<f64> {
+        let mut rcond = 0f64;
+
+        GSLResult::from(unsafe { ffi::gsl_multilarge_linear_rcond(&mut rcond, self.w) })?;
+        Ok(rcond)
+    }
+
+    /// This function computes the L-curve of the accumulated least squares system. On output, reg_param holds the regularization
+    /// parameters \lambda_i, rho the corresponding residual norms ||y - X c|| and eta the solution norms ||c||. All three vectors must
+    /// have the same length, which is the number of points on the curve. The corner can be found with `multifit::linear_lcorner`.
+    pub fn lcurve(&mut self, reg_param: &mut ::VectorF64, rho: &mut ::VectorF64, eta: &mut ::VectorF64) -> GSLResult<()> {
+        GSLResult::from(unsafe { ffi::gsl_multilarge_linear_lcurve(ffi::FFI::unwrap(reg_param), ffi::FFI::unwrap(rho),
+            ffi::FFI::unwrap(eta), self.w) })
+    }
+
+    /// This function converts the block (X, y) with the diagonal regularization matrix L = diag(l_0, ..., l_{p-1}) to standard form
+    /// (Xs, ys), before it is given to `accumulate`. If L is None, L = I is used and the block is simply copied. Xs and ys have the same
+    /// dimensions as X and y.
+    pub fn stdform1(&mut self, L: Option<&::VectorF64>, X: &::MatrixF64, y: &::VectorF64, Xs: &mut ::MatrixF64,
+        ys: &mut ::VectorF64) -> GSLResult<()> {
+        GSLResult::from(unsafe { ffi::gsl_multilarge_linear_stdform1(L.map_or(::std::ptr::null(), |l| ffi::FFI::unwrap(l) as *const _),
+            ffi::FFI::unwrap(X), ffi::FFI::unwrap(y), ffi::FFI::unwrap(Xs), ffi::FFI::unwrap(ys), self.w) })
+    }
+
+    /// This function converts the weighted block (X, y, w) with the diagonal regularization matrix L to standard form (Xs, ys), like
+    /// `stdform1`.
+    pub fn wstdform1(&mut self, L: Option<&::VectorF64>, X: &::MatrixF64, w: &::VectorF64, y: &::VectorF64, Xs: &mut ::MatrixF64,
+        ys: &mut ::VectorF64) -> GSLResult<()> {
+        GSLResult::from(unsafe { ffi::gsl_multilarge_linear_wstdform1(L.map_or(::std::ptr::null(), |l| ffi::FFI::unwrap(l) as *const _),
+            ffi::FFI::unwrap(X), ffi::FFI::unwrap(w), ffi::FFI::unwrap(y), ffi::FFI::unwrap(Xs), ffi::FFI::unwrap(ys), self.w) })
+    }
+
+    /// This function converts the solution cs of the standard form system back to the solution c of the original system, for the
+    /// diagonal regularization matrix L used with `stdform1`. If L is None, L = I is used.
+    pub fn genform1(&mut self, L: Option<&::VectorF64>, cs: &::VectorF64, c: &mut ::VectorF64) -> GSLResult<()> {
+        GSLResult::from(unsafe { ffi::gsl_multilarge_linear_genform1(L.map_or(::std::ptr::null(), |l| ffi::FFI::unwrap(l) as *const _),
+            ffi::FFI::unwrap(cs), ffi::FFI::unwrap(c), self.w) })
+    }
+}
+
+impl Drop for MultilargeLinearWorkspace {
+    fn drop(&mut self) {
+        unsafe { ffi::gsl_multilarge_linear_free(self.w) };
+        self.w = ::std::ptr::null_mut();
+    }
+}
+
+impl ffi::FFI<ffi::gsl_multilarge_linear_workspace> for MultilargeLinearWorkspace {
+    fn wrap(w: *mut ffi::gsl_multilarge_linear_workspace) -> MultilargeLinearWorkspace {
+        MultilargeLinearWorkspace {
+            w: w
+        }
+    }
+
+    fn unwrap(w: &MultilargeLinearWorkspace) -> *mut ffi::gsl_multilarge_linear_workspace {
+        w.w
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct MultilargeLinearType {
+    t: *const ffi::gsl_multilarge_linear_type
+}
+
+impl MultilargeLinearType {
+    /// This specifies the normal equations approach to solving the least squares system. This method is suitable in cases where
+    /// performance is critical and it is known that the least squares matrix X is well conditioned. The size of this workspace is
+    /// O(p^2).
+    pub fn normal() -> MultilargeLinearType {
+        MultilargeLinearType {
+            t: unsafe { ffi::gsl_multilarge_linear_normal }
+        }
+    }
+
+    /// This specifies the sequential Tall Skinny QR (TSQR) approach to solving the least squares system. This method is a good
+    /// general purpose choice for large systems, but requires about twice as many operations as the normal equations method for
+    /// n >> p. The size of this workspace is O(p^2).
+    pub fn tsqr() -> MultilargeLinearType {
+        MultilargeLinearType {
+            t: unsafe { ffi::gsl_multilarge_linear_tsqr }
+        }
+    }
+}
+
+impl ffi::FFI<ffi::gsl_multilarge_linear_type> for MultilargeLinearType {
+    fn wrap(t: *mut ffi::gsl_multilarge_linear_type) -> MultilargeLinearType {
+        MultilargeLinearType {
+            t: t
+        }
+    }
+
+    fn unwrap(t: &MultilargeLinearType) -> *mut ffi::gsl_multilarge_linear_type {
+        t.t as *mut ffi::gsl_multilarge_linear_type
+    }
+}
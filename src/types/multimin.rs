@@ -0,0 +1,516 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+/*!
+#Multidimensional Minimization
+
+This chapter describes routines for finding minima of arbitrary multidimensional functions. The library provides low level components for
+a variety of iterative minimizers and convergence tests. These can be combined by the user to achieve the desired solution, while providing
+full access to the intermediate steps of the algorithms. Each class of methods uses the same framework, so that you can switch between
+minimizers at runtime without needing to recompile your program. Each instance of a minimizer keeps track of its own state, allowing the
+minimizers to be used in multi-threaded programs. The minimization algorithms can be used to maximize a function by inverting its sign.
+
+##Overview
+
+The problem of multidimensional minimization requires finding a point x such that the scalar function,
+
+f(x_1, ..., x_n)
+
+takes a value which is lower than at any neighboring point. For smooth functions the gradient g = \nabla f vanishes at the minimum. In
+general there are no bracketing methods available for the minimization of n-dimensional functions. The algorithms proceed from an initial
+guess using a search algorithm which attempts to move in a downhill direction.
+
+Algorithms making use of the gradient of the function perform a one-dimensional line minimisation along this direction until the lowest
+point is found to a suitable tolerance. The search direction is then updated with local information from the function and its derivatives,
+and the whole process repeated until the true n-dimensional minimum is found.
+
+Algorithms which do not require the gradient of the function use different strategies. For example, the Nelder-Mead Simplex algorithm
+maintains n+1 trial parameter vectors as the vertices of a n-dimensional simplex. On each iteration it tries to improve the worst vertex
+of the simplex by geometrical transformations. The iterations are continued until the overall size of the simplex has decreased
+sufficiently.
+
+Both types of algorithms use a standard framework. The user provides a high-level driver for the algorithms, and the library provides the
+individual functions necessary for each of the steps. There are three main phases of the iteration. The steps are,
+
+ * initialize minimizer state, s, for algorithm T
+ * update s using the iteration T
+ * test s for convergence, and repeat iteration if necessary
+
+Each iteration step consists either of an improvement to the line-minimisation in the current direction or an update to the search
+direction itself. The state for the minimizers is held in a MultiMinFdfMinimizer or a MultiMinFMinimizer.
+
+##Providing a function to minimize
+
+The objective function, its gradient and the optional combined evaluation are given as closures over `&VectorF64`, in the same way as
+the system of `MultiRootFSolver`.
+
+##Caveats
+
+Note that the minimization algorithms can only search for one local minimum at a time. When there are several local minima in the search
+area, the first minimum to be found will be returned; however it is difficult to predict which of the minima this will be. In most cases,
+no error will be reported if you try to find a local minimum in an area where there is more than one.
+
+It is also important to note that the minimization algorithms find local minima; there is no way to determine whether a minimum is a
+global minimum of the function in question.
+!*/
+
+use ffi;
+use enums::GSLResult;
+use libc::c_void;
+use types::vector;
+
+pub struct MultiMinFunction<'a> {
+    f: &'a mut FnMut(&::VectorF64) -> f64,
+    n: usize,
+}
+
+impl<'a> MultiMinFunction<'a> {
+    /// Defines a function of n variables to minimize. The closure returns the value of the function at the given point.
+    pub fn new(n: usize, f: &'a mut FnMut(&::VectorF64) -> f64) -> MultiMinFunction<'a> {
+        MultiMinFunction {
+            f: f,
+            n: n,
+        }
+    }
+
+    fn to_raw(&mut self) -> ffi::gsl_multimin_function {
+        ffi::gsl_multimin_function {
+            f: function_handler,
+            n: self.n,
+            params: self as *mut _ as *mut c_void,
+        }
+    }
+}
+
+extern fn function_handler(x: *const ffi::gsl_vector, params: *mut c_void) -> f64 {
+    let sys = unsafe { &mut *(params as *mut MultiMinFunction) };
+    let x = vector::wrap(x as *mut ffi::gsl_vector);
+
+    (sys.f)(&x)
+}
+
+pub struct MultiMinFunctionFdf<'a> {
+    f: &'a mut FnMut(&::VectorF64) -> f64,
+    df: &'a mut FnMut(&::VectorF64, &mut ::VectorF64),
+    fdf: Option<&'a mut FnMut(&::VectorF64, &mut ::VectorF64) -> f64>,
+    n: usize,
+}
+
+impl<'a> MultiMinFunctionFdf<'a> {
+    /// Defines a function of n variables together with its gradient. The closure f returns the value of the function at the given point
+    /// and df stores the gradient \nabla f in its second argument.
+    pub fn new(n: usize,
+               f: &'a mut FnMut(&::VectorF64) -> f64,
+               df: &'a mut FnMut(&::VectorF64, &mut ::VectorF64)) -> MultiMinFunctionFdf<'a> {
+        MultiMinFunctionFdf {
+            f: f,
+            df: df,
+            fdf: None,
+            n: n,
+        }
+    }
+
+    /// Same as `new` but with an additional closure fdf which stores the gradient and returns the value of the function at the same time.
+    /// This allows the two to be computed more efficiently by taking advantage of any common subexpressions.
+    pub fn with_fdf(n: usize,
+                    f: &'a mut FnMut(&::VectorF64) -> f64,
+                    df: &'a mut FnMut(&::VectorF64, &mut ::VectorF64),
+                    fdf: &'a mut FnMut(&::VectorF64, &mut ::VectorF64) -> f64) -> MultiMinFunctionFdf<'a> {
+        MultiMinFunctionFdf {
+            f: f,
+            df: df,
+            fdf: Some(fdf),
+            n: n,
+        }
+    }
+
+    fn to_raw(&mut self) -> ffi::gsl_multimin_function_fdf {
+        ffi::gsl_multimin_function_fdf {
+            f: fdf_f_handler,
+            df: fdf_df_handler,
+            fdf: fdf_fdf_handler,
+            n: self.n,
+            params: self as *mut _ as *mut c_void,
+        }
+    }
+}
+
+extern fn fdf_f_handler(x: *const ffi::gsl_vector, params: *mut c_void) -> f64 {
+    let sys = unsafe { &mut *(params as *mut MultiMinFunctionFdf) };
+    let x = vector::wrap(x as *mut ffi::gsl_vector);
+
+    (sys.f)(&x)
+}
+
+extern fn fdf_df_handler(x: *const ffi::gsl_vector, params: *mut c_void, g: *mut ffi::gsl_vector) {
+    let sys = unsafe { &mut *(params as *mut MultiMinFunctionFdf) };
+    let x = vector::wrap(x as *mut ffi::gsl_vector);
+    let mut g = vector::wrap(g);
+
+    (sys.df)(&x, &mut g)
+}
+
+extern fn fdf_fdf_handler(x: *const ffi::gsl_vector, params: *mut c_void, f: *mut f64, g: *mut ffi::gsl_vector) {
+    let sys = unsafe { &mut *(params as *mut MultiMinFunctionFdf) };
+    let x = vector::wrap(x as *mut ffi::gsl_vector);
+    let mut g = vector::wrap(g);
+
+    let value = match sys.fdf {
+        Some(ref mut fdf) => fdf(&x, &mut g),
+        None => {
+            (sys.df)(&x, &mut g);
+            (sys.f)(&x)
+        }
+    };
+    unsafe { *f = value };
+}
+
+pub struct MultiMinFMinimizer<'a> {
+    s: *mut ffi::gsl_multimin_fminimizer,
+    function: Option<Box<MultiMinFunction<'a>>>,
+    raw: Option<Box<ffi::gsl_multimin_function>>,
+}
+
+impl<'a> MultiMinFMinimizer<'a> {
+    /// This function returns a newly allocated instance of a minimizer of type T for an n-dimension function.
+    ///
+    /// If there is insufficient memory to create the minimizer then the function returns None.
+    pub fn new(t: &MultiMinFMinimizerType, n: usize) -> Option<MultiMinFMinimizer<'a>> {
+        let tmp = unsafe { ffi::gsl_multimin_fminimizer_alloc(t.t, n) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(MultiMinFMinimizer {
+                s: tmp,
+                function: None,
+                raw: None,
+            })
+        }
+    }
+
+    /// This function initializes the minimizer s to minimize the function f, starting from the initial point x. The size of the initial
+    /// trial steps is given in vector step_size. The precise meaning of this parameter depends on the method used.
+    pub fn set(&mut self, f: MultiMinFunction<'a>, x: &::VectorF64, step_size: &::VectorF64) -> GSLResult<()> {
+        let mut function = Box::new(f);
+        let mut raw = Box::new(function.to_raw());
+        let r = unsafe {
+            ffi::gsl_multimin_fminimizer_set(self.s, &mut *raw, ffi::FFI::unwrap(x), ffi::FFI::unwrap(step_size))
+        };
+
+        self.function = Some(function);
+        self.raw = Some(raw);
+        GSLResult::from(r)
+    }
+
+    /// This function performs a single iteration of the minimizer s. If the iteration encounters an unexpected problem then an error code
+    /// will be returned. The error code ::Value::NoProg signifies that the minimizer is unable to improve on its current estimate, either
+    /// due to numerical difficulty or because a genuine local minimum has been reached.
+    ///
+    /// The minimizer maintains a current best estimate of the minimum at all times.
+    pub fn iterate(&mut self) -> GSLResult<()> {
+        if self.raw.is_none() {
+            rgsl_error!("minimizer has not been set", ::Value::Inval);
+            return Err(::Value::Inval);
+        }
+        GSLResult::from(unsafe { ffi::gsl_multimin_fminimizer_iterate(self.s) })
+    }
+
+    /// This function returns the name of the minimizer.
+    pub fn name(&self) -> Option<String> {
+        let tmp = unsafe { ffi::gsl_multimin_fminimizer_name(self.s) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            unsafe { Some(String::from_utf8_lossy(::std::ffi::CStr::from_ptr(tmp).to_bytes()).to_string()) }
+        }
+    }
+
+    /// This function returns the current best estimate of the location of the minimum.
+    pub fn x(&self) -> ::VectorF64 {
+        vector::wrap(unsafe { ffi::gsl_multimin_fminimizer_x(self.s) })
+    }
+
+    /// This function returns the value of the function at the current best estimate of the location of the minimum.
+    pub fn minimum(&self) -> f64 {
+        unsafe { ffi::gsl_multimin_fminimizer_minimum(self.s) }
+    }
+
+    /// This function returns a minimizer-specific characteristic size for the minimizer s. For the simplex methods it is the average
+    /// distance from the geometrical center of the simplex to all its vertices.
+    pub fn size(&self) -> f64 {
+        unsafe { ffi::gsl_multimin_fminimizer_size(self.s) }
+    }
+}
+
+impl<'a> Drop for MultiMinFMinimizer<'a> {
+    fn drop(&mut self) {
+        unsafe { ffi::gsl_multimin_fminimizer_free(self.s) };
+        self.s = ::std::ptr::null_mut();
+    }
+}
+
+impl<'a> ffi::FFI<ffi::gsl_multimin_fminimizer> for MultiMinFMinimizer<'a> {
+    fn wrap(s: *mut ffi::gsl_multimin_fminimizer) -> MultiMinFMinimizer<'a> {
+        MultiMinFMinimizer {
+            s: s,
+            function: None,
+            raw: None,
+        }
+    }
+
+    fn unwrap(s: &MultiMinFMinimizer) -> *mut ffi::gsl_multimin_fminimizer {
+        s.s
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct MultiMinFMinimizerType {
+    t: *const ffi::gsl_multimin_fminimizer_type
+}
+
+impl MultiMinFMinimizerType {
+    /// This is the original Simplex algorithm of Nelder and Mead. It uses n+1 vertices which are stored internally and updated on each
+    /// iteration; the size of the simplex is computed directly from the distances of the vertices to its center.
+    pub fn nmsimplex() -> MultiMinFMinimizerType {
+        MultiMinFMinimizerType {
+            t: unsafe { ffi::gsl_multimin_fminimizer_nmsimplex }
+        }
+    }
+
+    /// This method uses the Simplex algorithm of Nelder and Mead. Starting from the initial vector x = p_0, the algorithm constructs an
+    /// additional n vectors p_i using the step size vector s = step_size as follows:
+    ///
+    /// p_0 = (x_0, x_1, ... , x_n)
+    /// p_1 = (x_0 + s_0, x_1, ... , x_n)
+    /// p_2 = (x_0, x_1 + s_1, ... , x_n)
+    /// ... = ...
+    /// p_n = (x_0, x_1, ... , x_n + s_n)
+    ///
+    /// These vectors form the n+1 vertices of a simplex in n dimensions. On each iteration the algorithm uses simple geometrical
+    /// transformations to update the vector corresponding to the highest function value. The geometric transformations are reflection,
+    /// reflection followed by expansion, contraction and multiple contraction. Using these transformations the simplex moves through the
+    /// space towards the minimum, where it contracts itself.
+    ///
+    /// After each iteration, the best vertex is returned. Note, that due to the nature of the algorithm not every step improves the
+    /// current best parameter vector. Usually several iterations are required.
+    ///
+    /// The minimizer-specific characteristic size is calculated as the average distance from the geometrical center of the simplex to
+    /// all its vertices. This size can be used as a stopping criteria, as the simplex contracts itself near the minimum. The size is
+    /// returned by the function `MultiMinFMinimizer::size`.
+    ///
+    /// This version of the algorithm computes the size of the simplex in O(N) operations instead of O(N^2) for nmsimplex.
+    pub fn nmsimplex2() -> MultiMinFMinimizerType {
+        MultiMinFMinimizerType {
+            t: unsafe { ffi::gsl_multimin_fminimizer_nmsimplex2 }
+        }
+    }
+
+    /// This method is a variant of nmsimplex2 which initialises the simplex around the starting point x using a randomly-oriented set of
+    /// basis vectors instead of the fixed coordinate axes. The final dimensions of the simplex are scaled along the coordinate axes by
+    /// the vector step_size. The randomisation uses a simple deterministic generator so that repeated calls to `set` for a given solver
+    /// object will vary the orientation in a well-defined way.
+    pub fn nmsimplex2rand() -> MultiMinFMinimizerType {
+        MultiMinFMinimizerType {
+            t: unsafe { ffi::gsl_multimin_fminimizer_nmsimplex2rand }
+        }
+    }
+}
+
+impl ffi::FFI<ffi::gsl_multimin_fminimizer_type> for MultiMinFMinimizerType {
+    fn wrap(t: *mut ffi::gsl_multimin_fminimizer_type) -> MultiMinFMinimizerType {
+        MultiMinFMinimizerType {
+            t: t
+        }
+    }
+
+    fn unwrap(t: &MultiMinFMinimizerType) -> *mut ffi::gsl_multimin_fminimizer_type {
+        t.t as *mut ffi::gsl_multimin_fminimizer_type
+    }
+}
+
+pub struct MultiMinFdfMinimizer<'a> {
+    s: *mut ffi::gsl_multimin_fdfminimizer,
+    function: Option<Box<MultiMinFunctionFdf<'a>>>,
+    raw: Option<Box<ffi::gsl_multimin_function_fdf>>,
+}
+
+impl<'a> MultiMinFdfMinimizer<'a> {
+    /// This function returns a newly allocated instance of a minimizer of type T for an n-dimension function.
+    ///
+    /// If there is insufficient memory to create the minimizer then the function returns None.
+    pub fn new(t: &MultiMinFdfMinimizerType, n: usize) -> Option<MultiMinFdfMinimizer<'a>> {
+        let tmp = unsafe { ffi::gsl_multimin_fdfminimizer_alloc(t.t, n) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(MultiMinFdfMinimizer {
+                s: tmp,
+                function: None,
+                raw: None,
+            })
+        }
+    }
+
+    /// This function initializes the minimizer s to minimize the function fdf starting from the initial point x. The size of the first
+    /// trial step is given by step_size. The accuracy of the line minimization is specified by tol. The precise meaning of this
+    /// parameter depends on the method used. Typically the line minimization is considered successful if the gradient of the function g
+    /// is orthogonal to the current search direction p to a relative accuracy of tol, where p . g < tol |p| |g|. A tol value of 0.1 is
+    /// suitable for most purposes, since line minimization only needs to be carried out approximately. Note that setting tol to zero will
+    /// force the use of “exact” line-searches, which are extremely expensive.
+    pub fn set(&mut self, fdf: MultiMinFunctionFdf<'a>, x: &::VectorF64, step_size: f64, tol: f64) -> GSLResult<()> {
+        let mut function = Box::new(fdf);
+        let mut raw = Box::new(function.to_raw());
+        let r = unsafe { ffi::gsl_multimin_fdfminimizer_set(self.s, &mut *raw, ffi::FFI::unwrap(x), step_size, tol) };
+
+        self.function = Some(function);
+        self.raw = Some(raw);
+        GSLResult::from(r)
+    }
+
+    /// This function performs a single iteration of the minimizer s. If the iteration encounters an unexpected problem then an error code
+    /// will be returned. The error code ::Value::NoProg signifies that the minimizer is unable to improve on its current estimate, either
+    /// due to numerical difficulty or because a genuine local minimum has been reached.
+    ///
+    /// The minimizer maintains a current best estimate of the minimum at all times.
+    pub fn iterate(&mut self) -> GSLResult<()> {
+        if self.raw.is_none() {
+            rgsl_error!("minimizer has not been set", ::Value::Inval);
+            return Err(::Value::Inval);
+        }
+        GSLResult::from(unsafe { ffi::gsl_multimin_fdfminimizer_iterate(self.s) })
+    }
+
+    /// This function resets the minimizer s to use the current point as a new starting point.
+    pub fn restart(&mut self) -> GSLResult<()> {
+        GSLResult::from(unsafe { ffi::gsl_multimin_fdfminimizer_restart(self.s) })
+    }
+
+    /// This function returns the name of the minimizer.
+    pub fn name(&self) -> Option<String> {
+        let tmp = unsafe { ffi::gsl_multimin_fdfminimizer_name(self.s) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            unsafe { Some(String::from_utf8_lossy(::std::ffi::CStr::from_ptr(tmp).to_bytes()).to_string()) }
+        }
+    }
+
+    /// This function returns the current best estimate of the location of the minimum.
+    pub fn x(&self) -> ::VectorF64 {
+        vector::wrap(unsafe { ffi::gsl_multimin_fdfminimizer_x(self.s) })
+    }
+
+    /// This function returns the value of the function at the current best estimate of the location of the minimum.
+    pub fn minimum(&self) -> f64 {
+        unsafe { ffi::gsl_multimin_fdfminimizer_minimum(self.s) }
+    }
+
+    /// This function returns the gradient of the function at the current best estimate of the location of the minimum.
+    pub fn gradient(&self) -> ::VectorF64 {
+        vector::wrap(unsafe { ffi::gsl_multimin_fdfminimizer_gradient(self.s) })
+    }
+
+    /// This function returns the last step increment of the current best estimate of the location of the minimum.
+    pub fn dx(&self) -> ::VectorF64 {
+        vector::wrap(unsafe { ffi::gsl_multimin_fdfminimizer_dx(self.s) })
+    }
+}
+
+impl<'a> Drop for MultiMinFdfMinimizer<'a> {
+    fn drop(&mut self) {
+        unsafe { ffi::gsl_multimin_fdfminimizer_free(self.s) };
+        self.s = ::std::ptr::null_mut();
+    }
+}
+
+impl<'a> ffi::FFI<ffi::gsl_multimin_fdfminimizer> for MultiMinFdfMinimizer<'a> {
+    fn wrap(s: *mut ffi::gsl_multimin_fdfminimizer) -> MultiMinFdfMinimizer<'a> {
+        MultiMinFdfMinimizer {
+            s: s,
+            function: None,
+            raw: None,
+        }
+    }
+
+    fn unwrap(s: &MultiMinFdfMinimizer) -> *mut ffi::gsl_multimin_fdfminimizer {
+        s.s
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct MultiMinFdfMinimizerType {
+    t: *const ffi::gsl_multimin_fdfminimizer_type
+}
+
+impl MultiMinFdfMinimizerType {
+    /// This is the Fletcher-Reeves conjugate gradient algorithm. The conjugate gradient algorithm proceeds as a succession of line
+    /// minimizations. The sequence of search directions is used to build up an approximation to the curvature of the function in the
+    /// neighborhood of the minimum.
+    ///
+    /// An initial search direction p is chosen using the gradient, and line minimization is carried out in that direction. The accuracy
+    /// of the line minimization is specified by the parameter tol. The minimum along this line occurs when the function gradient g and
+    /// the search direction p are orthogonal. The line minimization terminates when p . g < tol |p| |g|. The search direction is updated
+    /// using the Fletcher-Reeves formula p' = g' - \beta g where \beta=-|g'|^2/|g|^2, and the line minimization is then repeated for the
+    /// new search direction.
+    pub fn conjugate_fr() -> MultiMinFdfMinimizerType {
+        MultiMinFdfMinimizerType {
+            t: unsafe { ffi::gsl_multimin_fdfminimizer_conjugate_fr }
+        }
+    }
+
+    /// This is the Polak-Ribiere conjugate gradient algorithm. It is similar to the Fletcher-Reeves method, differing only in the choice
+    /// of the coefficient \beta. Both methods work well when the evaluation point is close enough to the minimum of the objective function
+    /// that it is well approximated by a quadratic hypersurface.
+    pub fn conjugate_pr() -> MultiMinFdfMinimizerType {
+        MultiMinFdfMinimizerType {
+            t: unsafe { ffi::gsl_multimin_fdfminimizer_conjugate_pr }
+        }
+    }
+
+    /// This is the original vector Broyden-Fletcher-Goldfarb-Shanno (BFGS) algorithm. It is superseded by `vector_bfgs2`.
+    pub fn vector_bfgs() -> MultiMinFdfMinimizerType {
+        MultiMinFdfMinimizerType {
+            t: unsafe { ffi::gsl_multimin_fdfminimizer_vector_bfgs }
+        }
+    }
+
+    /// These methods use the vector Broyden-Fletcher-Goldfarb-Shanno (BFGS) algorithm. This is a quasi-Newton method which builds up an
+    /// approximation to the second derivatives of the function f using the difference between successive gradient vectors. By combining
+    /// the first and second derivatives the algorithm is able to take Newton-type steps towards the function minimum, assuming quadratic
+    /// behavior in that region.
+    ///
+    /// This version is the most efficient version available, and is a faithful implementation of the line minimization scheme described
+    /// in Fletcher's Practical Methods of Optimization, Algorithms 2.6.2 and 2.6.4. A value of 0.1 is recommended for tol.
+    pub fn vector_bfgs2() -> MultiMinFdfMinimizerType {
+        MultiMinFdfMinimizerType {
+            t: unsafe { ffi::gsl_multimin_fdfminimizer_vector_bfgs2 }
+        }
+    }
+
+    /// The steepest descent algorithm follows the downhill gradient of the function at each step. When a downhill step is successful the
+    /// step-size is increased by a factor of two. If the downhill step leads to a higher function value then the algorithm backtracks
+    /// and the step size is decreased using the parameter tol. A suitable value of tol for most applications is 0.1. The steepest descent
+    /// method is inefficient and is included only for demonstration purposes.
+    pub fn steepest_descent() -> MultiMinFdfMinimizerType {
+        MultiMinFdfMinimizerType {
+            t: unsafe { ffi::gsl_multimin_fdfminimizer_steepest_descent }
+        }
+    }
+}
+
+impl ffi::FFI<ffi::gsl_multimin_fdfminimizer_type> for MultiMinFdfMinimizerType {
+    fn wrap(t: *mut ffi::gsl_multimin_fdfminimizer_type) -> MultiMinFdfMinimizerType {
+        MultiMinFdfMinimizerType {
+            t: t
+        }
+    }
+
+    fn unwrap(t: &MultiMinFdfMinimizerType) -> *mut ffi::gsl_multimin_fdfminimizer_type {
+        t.t as *mut ffi::gsl_multimin_fdfminimizer_type
+    }
+}
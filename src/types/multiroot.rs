@@ -0,0 +1,538 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+/*!
+#Multidimensional Root-Finding
+
+This chapter describes functions for multidimensional root-finding (solving nonlinear systems with n equations in n unknowns). The library
+provides low level components for a variety of iterative solvers and convergence tests. These can be combined by the user to achieve the
+desired solution, with full access to the intermediate steps of the iteration. Each class of methods uses the same framework, so that you
+can switch between solvers at runtime without needing to recompile your program. Each instance of a solver keeps track of its own state,
+allowing the solvers to be used in multi-threaded programs.
+
+##Overview
+
+The problem of multidimensional root finding requires the simultaneous solution of n equations, f_i, in n variables, x_i,
+
+f_i (x_1, ..., x_n) = 0 for i = 1 ... n.
+
+In general there are no bracketing methods available for n dimensional systems, and no way of knowing whether any solutions exist. All
+algorithms proceed from an initial guess using a variant of the Newton iteration,
+
+x -> x' = x - J^{-1} f(x)
+
+where x, f are vector quantities and J is the Jacobian matrix J_{ij} = d f_i / d x_j. Additional strategies can be used to enlarge the
+region of convergence. These include requiring a decrease in the norm |f| on each step proposed by Newton's method, or taking
+steepest-descent steps in the direction of the negative gradient of |f|.
+
+Several root-finding algorithms are available within a single framework. The user provides a high-level driver for the algorithms, and
+the library provides the individual functions necessary for each of the steps. There are three main phases of the iteration. The steps
+are,
+
+ * initialize solver state, s, for algorithm T
+ * update s using the iteration T
+ * test s for convergence, and repeat iteration if necessary
+
+The evaluation of the Jacobian matrix can be problematic, either because programming the derivatives is intractable or because
+computation of the n^2 terms of the matrix becomes too expensive. For these reasons the algorithms provided by the library are divided
+into two classes according to whether the derivatives are available or not.
+
+The state for solvers with an analytic Jacobian matrix is held in a MultiRootFdfSolver. The updating procedure requires both the
+function and its derivatives to be supplied by the user.
+
+The state for solvers which do not use an analytic Jacobian matrix is held in a MultiRootFSolver. The updating procedure uses only
+function evaluations (not derivatives). The algorithms estimate the matrix J or J^{-1} by approximate methods.
+
+##Providing the function to solve
+
+The system of equations is given as a closure, in the same way as for `ODEiv2System`. The closure receives the current position x and
+stores the value of the n functions in f. Returning an error from the closure aborts the current iteration and the error is forwarded
+to the caller of `iterate`.
+!*/
+
+use ffi;
+use enums::{self, GSLResult};
+use libc::c_void;
+use types::{vector, matrix};
+
+pub struct MultiRootFunction<'a> {
+    function: &'a mut FnMut(&::VectorF64, &mut ::VectorF64) -> GSLResult<()>,
+    n: usize,
+}
+
+impl<'a> MultiRootFunction<'a> {
+    /// Defines a system of n functions in n variables. The closure stores the vector result f(x) in its second argument for the argument
+    /// given in the first one.
+    pub fn new(n: usize, function: &'a mut FnMut(&::VectorF64, &mut ::VectorF64) -> GSLResult<()>) -> MultiRootFunction<'a> {
+        MultiRootFunction {
+            function: function,
+            n: n,
+        }
+    }
+
+    fn to_raw(&mut self) -> ffi::gsl_multiroot_function {
+        ffi::gsl_multiroot_function {
+            f: function_handler,
+            n: self.n,
+            params: self as *mut _ as *mut c_void,
+        }
+    }
+}
+
+extern fn function_handler(x: *const ffi::gsl_vector, params: *mut c_void, f: *mut ffi::gsl_vector) -> enums::Value {
+    let sys = unsafe { &mut *(params as *mut MultiRootFunction) };
+    let x = vector::wrap(x as *mut ffi::gsl_vector);
+    let mut f = vector::wrap(f);
+
+    match (sys.function)(&x, &mut f) {
+        Ok(()) => enums::Value::Success,
+        Err(e) => e,
+    }
+}
+
+pub struct MultiRootFunctionFdf<'a> {
+    f: &'a mut FnMut(&::VectorF64, &mut ::VectorF64) -> GSLResult<()>,
+    df: &'a mut FnMut(&::VectorF64, &mut ::MatrixF64) -> GSLResult<()>,
+    fdf: Option<&'a mut FnMut(&::VectorF64, &mut ::VectorF64, &mut ::MatrixF64) -> GSLResult<()>>,
+    n: usize,
+}
+
+impl<'a> MultiRootFunctionFdf<'a> {
+    /// Defines a system of n functions in n variables together with its Jacobian. The closure f stores the vector result f(x) and df
+    /// stores the n-by-n matrix result J_ij = d f_i(x) / d x_j. When both are needed at the same point they are evaluated one after the
+    /// other.
+    pub fn new(n: usize,
+               f: &'a mut FnMut(&::VectorF64, &mut ::VectorF64) -> GSLResult<()>,
+               df: &'a mut FnMut(&::VectorF64, &mut ::MatrixF64) -> GSLResult<()>) -> MultiRootFunctionFdf<'a> {
+        MultiRootFunctionFdf {
+            f: f,
+            df: df,
+            fdf: None,
+            n: n,
+        }
+    }
+
+    /// Same as `new` but with an additional closure fdf which sets the values of the function and its Jacobian at the same time. This
+    /// allows the two to be computed more efficiently by taking advantage of any common subexpressions.
+    pub fn with_fdf(n: usize,
+                    f: &'a mut FnMut(&::VectorF64, &mut ::VectorF64) -> GSLResult<()>,
+                    df: &'a mut FnMut(&::VectorF64, &mut ::MatrixF64) -> GSLResult<()>,
+                    fdf: &'a mut FnMut(&::VectorF64, &mut ::VectorF64, &mut ::MatrixF64) -> GSLResult<()>) -> MultiRootFunctionFdf<'a> {
+        MultiRootFunctionFdf {
+            f: f,
+            df: df,
+            fdf: Some(fdf),
+            n: n,
+        }
+    }
+
+    fn to_raw(&mut self) -> ffi::gsl_multiroot_function_fdf {
+        ffi::gsl_multiroot_function_fdf {
+            f: fdf_f_handler,
+            df: fdf_df_handler,
+            fdf: fdf_fdf_handler,
+            n: self.n,
+            params: self as *mut _ as *mut c_void,
+        }
+    }
+}
+
+extern fn fdf_f_handler(x: *const ffi::gsl_vector, params: *mut c_void, f: *mut ffi::gsl_vector) -> enums::Value {
+    let sys = unsafe { &mut *(params as *mut MultiRootFunctionFdf) };
+    let x = vector::wrap(x as *mut ffi::gsl_vector);
+    let mut f = vector::wrap(f);
+
+    match (sys.f)(&x, &mut f) {
+        Ok(()) => enums::Value::Success,
+        Err(e) => e,
+    }
+}
+
+extern fn fdf_df_handler(x: *const ffi::gsl_vector, params: *mut c_void, j: *mut ffi::gsl_matrix) -> enums::Value {
+    let sys = unsafe { &mut *(params as *mut MultiRootFunctionFdf) };
+    let x = vector::wrap(x as *mut ffi::gsl_vector);
+    let mut j = matrix::wrap(j);
+
+    match (sys.df)(&x, &mut j) {
+        Ok(()) => enums::Value::Success,
+        Err(e) => e,
+    }
+}
+
+extern fn fdf_fdf_handler(x: *const ffi::gsl_vector, params: *mut c_void, f: *mut ffi::gsl_vector,
+                          j: *mut ffi::gsl_matrix) -> enums::Value {
+    let sys = unsafe { &mut *(params as *mut MultiRootFunctionFdf) };
+    let x = vector::wrap(x as *mut ffi::gsl_vector);
+    let mut f = vector::wrap(f);
+    let mut j = matrix::wrap(j);
+
+    let r = match sys.fdf {
+        Some(ref mut fdf) => fdf(&x, &mut f, &mut j),
+        None => (sys.f)(&x, &mut f).and_then(|_| (sys.df)(&x, &mut j)),
+    };
+    match r {
+        Ok(()) => enums::Value::Success,
+        Err(e) => e,
+    }
+}
+
+pub struct MultiRootFSolver<'a> {
+    s: *mut ffi::gsl_multiroot_fsolver,
+    function: Option<Box<MultiRootFunction<'a>>>,
+    raw: Option<Box<ffi::gsl_multiroot_function>>,
+}
+
+impl<'a> MultiRootFSolver<'a> {
+    /// This function returns a newly allocated instance of a solver of type T for a system of n dimensions. For example, the following
+    /// code creates an instance of a hybrid solver, to solve a 3-dimensional system of equations.
+    ///
+    /// ```Rust
+    /// let s = MultiRootFSolver::new(&MultiRootFSolverType::hybrid(), 3).unwrap();
+    /// ```
+    ///
+    /// If there is insufficient memory to create the solver then the function returns None.
+    pub fn new(t: &MultiRootFSolverType, n: usize) -> Option<MultiRootFSolver<'a>> {
+        let tmp = unsafe { ffi::gsl_multiroot_fsolver_alloc(t.t, n) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(MultiRootFSolver {
+                s: tmp,
+                function: None,
+                raw: None,
+            })
+        }
+    }
+
+    /// This function sets, or resets, an existing solver s to use the function f and the initial guess x.
+    ///
+    /// Note that the initial position is copied from x, this argument is not modified by subsequent iterations.
+    pub fn set(&mut self, f: MultiRootFunction<'a>, x: &::VectorF64) -> GSLResult<()> {
+        let mut function = Box::new(f);
+        let mut raw = Box::new(function.to_raw());
+        let r = unsafe { ffi::gsl_multiroot_fsolver_set(self.s, &mut *raw, ffi::FFI::unwrap(x)) };
+
+        self.function = Some(function);
+        self.raw = Some(raw);
+        GSLResult::from(r)
+    }
+
+    /// This function performs a single iteration of the solver s. If the iteration encounters an unexpected problem then an error code
+    /// will be returned,
+    ///
+    /// ::Value::BadFunc
+    /// the iteration encountered a singular point where the function or its derivative evaluated to Inf or NaN.
+    ///
+    /// ::Value::NoProg
+    /// the iteration is not making any progress, preventing the algorithm from continuing.
+    ///
+    /// The solver maintains a current best estimate of the root s.x and its function value s.f at all times.
+    pub fn iterate(&mut self) -> GSLResult<()> {
+        if self.raw.is_none() {
+            rgsl_error!("solver has not been set", ::Value::Inval);
+            return Err(::Value::Inval);
+        }
+        GSLResult::from(unsafe { ffi::gsl_multiroot_fsolver_iterate(self.s) })
+    }
+
+    /// This function returns the name of the solver.
+    pub fn name(&self) -> Option<String> {
+        let tmp = unsafe { ffi::gsl_multiroot_fsolver_name(self.s) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            unsafe { Some(String::from_utf8_lossy(::std::ffi::CStr::from_ptr(tmp).to_bytes()).to_string()) }
+        }
+    }
+
+    /// This function returns the current estimate of the root for the solver s, given by s.x.
+    pub fn root(&self) -> ::VectorF64 {
+        vector::wrap(unsafe { ffi::gsl_multiroot_fsolver_root(self.s) })
+    }
+
+    /// This function returns the function value f(x) at the current estimate of the root for the solver s, given by s.f.
+    pub fn f(&self) -> ::VectorF64 {
+        vector::wrap(unsafe { ffi::gsl_multiroot_fsolver_f(self.s) })
+    }
+
+    /// This function returns the last step dx taken by the solver s, given by s.dx.
+    pub fn dx(&self) -> ::VectorF64 {
+        vector::wrap(unsafe { ffi::gsl_multiroot_fsolver_dx(self.s) })
+    }
+}
+
+impl<'a> Drop for MultiRootFSolver<'a> {
+    fn drop(&mut self) {
+        unsafe { ffi::gsl_multiroot_fsolver_free(self.s) };
+        self.s = ::std::ptr::null_mut();
+    }
+}
+
+impl<'a> ffi::FFI<ffi::gsl_multiroot_fsolver> for MultiRootFSolver<'a> {
+    fn wrap(s: *mut ffi::gsl_multiroot_fsolver) -> MultiRootFSolver<'a> {
+        MultiRootFSolver {
+            s: s,
+            function: None,
+            raw: None,
+        }
+    }
+
+    fn unwrap(s: &MultiRootFSolver) -> *mut ffi::gsl_multiroot_fsolver {
+        s.s
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct MultiRootFSolverType {
+    t: *const ffi::gsl_multiroot_fsolver_type
+}
+
+impl MultiRootFSolverType {
+    /// This is a version of the Hybrid algorithm which replaces calls to the Jacobian function by its finite difference approximation.
+    /// The finite difference approximation is computed using gsl_multiroots_fdjac() with a relative step size of GSL_SQRT_DBL_EPSILON.
+    /// Note that this step size will not be suitable for all problems.
+    pub fn hybrids() -> MultiRootFSolverType {
+        MultiRootFSolverType {
+            t: unsafe { ffi::gsl_multiroot_fsolver_hybrids }
+        }
+    }
+
+    /// This is a finite difference version of the Hybrid algorithm without internal scaling.
+    pub fn hybrid() -> MultiRootFSolverType {
+        MultiRootFSolverType {
+            t: unsafe { ffi::gsl_multiroot_fsolver_hybrid }
+        }
+    }
+
+    /// The discrete Newton algorithm is the simplest method of solving a multidimensional system. It uses the Newton iteration
+    ///
+    /// x -> x - J^{-1} f(x)
+    ///
+    /// where the Jacobian matrix J is approximated by taking finite differences of the function f. The approximation scheme used by this
+    /// implementation is,
+    ///
+    /// J_{ij} = (f_i(x + \delta_j) - f_i(x)) /  \delta_j
+    ///
+    /// where \delta_j is a step of size \sqrt\epsilon |x_j| with \epsilon being the machine precision (\epsilon \approx 2.22 \times
+    /// 10^-16). The order of convergence of Newton's algorithm is quadratic, but the finite differences require n^2 function evaluations
+    /// on each iteration. The algorithm may become unstable if the finite differences are not a good approximation to the true
+    /// derivatives.
+    pub fn dnewton() -> MultiRootFSolverType {
+        MultiRootFSolverType {
+            t: unsafe { ffi::gsl_multiroot_fsolver_dnewton }
+        }
+    }
+
+    /// The Broyden algorithm is a version of the discrete Newton algorithm which attempts to avoids the expensive update of the Jacobian
+    /// matrix on each iteration. The changes to the Jacobian are also approximated, using a rank-1 update,
+    ///
+    /// J^{-1} \to J^{-1} - (J^{-1} df - dx) dx^T J^{-1} / dx^T J^{-1} df
+    ///
+    /// where the vectors dx and df are the changes in x and f. On the first iteration the inverse Jacobian is estimated using finite
+    /// differences, as in the discrete Newton algorithm.
+    ///
+    /// This approximation gives a fast update but is unreliable if the changes are not small, and the estimate of the inverse Jacobian
+    /// becomes worse as time passes. The algorithm has a tendency to become unstable unless it starts close to the root. The Jacobian is
+    /// refreshed if this instability is detected (consult the source for details).
+    ///
+    /// This algorithm is included only for demonstration purposes, and is not recommended for serious use.
+    pub fn broyden() -> MultiRootFSolverType {
+        MultiRootFSolverType {
+            t: unsafe { ffi::gsl_multiroot_fsolver_broyden }
+        }
+    }
+}
+
+impl ffi::FFI<ffi::gsl_multiroot_fsolver_type> for MultiRootFSolverType {
+    fn wrap(t: *mut ffi::gsl_multiroot_fsolver_type) -> MultiRootFSolverType {
+        MultiRootFSolverType {
+            t: t
+        }
+    }
+
+    fn unwrap(t: &MultiRootFSolverType) -> *mut ffi::gsl_multiroot_fsolver_type {
+        t.t as *mut ffi::gsl_multiroot_fsolver_type
+    }
+}
+
+pub struct MultiRootFdfSolver<'a> {
+    s: *mut ffi::gsl_multiroot_fdfsolver,
+    function: Option<Box<MultiRootFunctionFdf<'a>>>,
+    raw: Option<Box<ffi::gsl_multiroot_function_fdf>>,
+}
+
+impl<'a> MultiRootFdfSolver<'a> {
+    /// This function returns a newly allocated instance of a derivative solver of type T for a system of n dimensions. For example, the
+    /// following code creates an instance of a Newton-Raphson solver, for a 2-dimensional system of equations.
+    ///
+    /// ```Rust
+    /// let s = MultiRootFdfSolver::new(&MultiRootFdfSolverType::newton(), 2).unwrap();
+    /// ```
+    ///
+    /// If there is insufficient memory to create the solver then the function returns None.
+    pub fn new(t: &MultiRootFdfSolverType, n: usize) -> Option<MultiRootFdfSolver<'a>> {
+        let tmp = unsafe { ffi::gsl_multiroot_fdfsolver_alloc(t.t, n) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(MultiRootFdfSolver {
+                s: tmp,
+                function: None,
+                raw: None,
+            })
+        }
+    }
+
+    /// This function sets, or resets, an existing solver s to use the function and derivative fdf and the initial guess x.
+    ///
+    /// Note that the initial position is copied from x, this argument is not modified by subsequent iterations.
+    pub fn set(&mut self, fdf: MultiRootFunctionFdf<'a>, x: &::VectorF64) -> GSLResult<()> {
+        let mut function = Box::new(fdf);
+        let mut raw = Box::new(function.to_raw());
+        let r = unsafe { ffi::gsl_multiroot_fdfsolver_set(self.s, &mut *raw, ffi::FFI::unwrap(x)) };
+
+        self.function = Some(function);
+        self.raw = Some(raw);
+        GSLResult::from(r)
+    }
+
+    /// This function performs a single iteration of the solver s. If the iteration encounters an unexpected problem then an error code
+    /// will be returned,
+    ///
+    /// ::Value::BadFunc
+    /// the iteration encountered a singular point where the function or its derivative evaluated to Inf or NaN.
+    ///
+    /// ::Value::NoProg
+    /// the iteration is not making any progress, preventing the algorithm from continuing.
+    ///
+    /// The solver maintains a current best estimate of the root s.x and its function value s.f at all times.
+    pub fn iterate(&mut self) -> GSLResult<()> {
+        if self.raw.is_none() {
+            rgsl_error!("solver has not been set", ::Value::Inval);
+            return Err(::Value::Inval);
+        }
+        GSLResult::from(unsafe { ffi::gsl_multiroot_fdfsolver_iterate(self.s) })
+    }
+
+    /// This function returns the name of the solver.
+    pub fn name(&self) -> Option<String> {
+        let tmp = unsafe { ffi::gsl_multiroot_fdfsolver_name(self.s) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            unsafe { Some(String::from_utf8_lossy(::std::ffi::CStr::from_ptr(tmp).to_bytes()).to_string()) }
+        }
+    }
+
+    /// This function returns the current estimate of the root for the solver s, given by s.x.
+    pub fn root(&self) -> ::VectorF64 {
+        vector::wrap(unsafe { ffi::gsl_multiroot_fdfsolver_root(self.s) })
+    }
+
+    /// This function returns the function value f(x) at the current estimate of the root for the solver s, given by s.f.
+    pub fn f(&self) -> ::VectorF64 {
+        vector::wrap(unsafe { ffi::gsl_multiroot_fdfsolver_f(self.s) })
+    }
+
+    /// This function returns the last step dx taken by the solver s, given by s.dx.
+    pub fn dx(&self) -> ::VectorF64 {
+        vector::wrap(unsafe { ffi::gsl_multiroot_fdfsolver_dx(self.s) })
+    }
+}
+
+impl<'a> Drop for MultiRootFdfSolver<'a> {
+    fn drop(&mut self) {
+        unsafe { ffi::gsl_multiroot_fdfsolver_free(self.s) };
+        self.s = ::std::ptr::null_mut();
+    }
+}
+
+impl<'a> ffi::FFI<ffi::gsl_multiroot_fdfsolver> for MultiRootFdfSolver<'a> {
+    fn wrap(s: *mut ffi::gsl_multiroot_fdfsolver) -> MultiRootFdfSolver<'a> {
+        MultiRootFdfSolver {
+            s: s,
+            function: None,
+            raw: None,
+        }
+    }
+
+    fn unwrap(s: &MultiRootFdfSolver) -> *mut ffi::gsl_multiroot_fdfsolver {
+        s.s
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct MultiRootFdfSolverType {
+    t: *const ffi::gsl_multiroot_fdfsolver_type
+}
+
+impl MultiRootFdfSolverType {
+    /// This is a modified version of Powell's Hybrid method as implemented in the hybrj algorithm in minpack. Minpack was written by
+    /// Jorge J. Moré, Burton S. Garbow and Kenneth E. Hillstrom. The Hybrid algorithm retains the fast convergence of Newton's method but
+    /// will also reduce the residual when Newton's method is unreliable.
+    ///
+    /// The algorithm uses a generalized trust region to keep each step under control. In order to be accepted a proposed new position x'
+    /// must satisfy the condition |D (x' - x)| < \delta, where D is a diagonal scaling matrix and \delta is the size of the trust region.
+    /// The components of D are computed internally, using the column norms of the Jacobian to estimate the sensitivity of the residual
+    /// to each component of x. This improves the behavior of the algorithm for badly scaled functions.
+    pub fn hybridsj() -> MultiRootFdfSolverType {
+        MultiRootFdfSolverType {
+            t: unsafe { ffi::gsl_multiroot_fdfsolver_hybridsj }
+        }
+    }
+
+    /// This algorithm is an unscaled version of hybridsj. The steps are controlled by a spherical trust region |x' - x| < \delta, instead
+    /// of a generalized region. This can be useful if the generalized region estimated by hybridsj is inappropriate.
+    pub fn hybridj() -> MultiRootFdfSolverType {
+        MultiRootFdfSolverType {
+            t: unsafe { ffi::gsl_multiroot_fdfsolver_hybridj }
+        }
+    }
+
+    /// Newton's Method is the standard root-polishing algorithm. The algorithm begins with an initial guess for the location of the
+    /// solution. On each iteration a linear approximation to the function F is used to estimate the step which will zero all the
+    /// components of the residual. The iteration is defined by the following sequence,
+    ///
+    /// x -> x' = x - J^{-1} f(x)
+    ///
+    /// where the Jacobian matrix J is computed from the derivative functions provided by f. The step dx is obtained by solving the linear
+    /// system,
+    ///
+    /// J dx = - f(x)
+    ///
+    /// using LU decomposition. If the Jacobian matrix is singular, an error code of ::Value::Dom is returned.
+    pub fn newton() -> MultiRootFdfSolverType {
+        MultiRootFdfSolverType {
+            t: unsafe { ffi::gsl_multiroot_fdfsolver_newton }
+        }
+    }
+
+    /// This is a modified version of Newton's method which attempts to improve global convergence by requiring every step to reduce the
+    /// Euclidean norm of the residual, |f(x)|. If the Newton step leads to an increase in the norm then a reduced step of relative size,
+    ///
+    /// t = (\sqrt(1 + 6 r) - 1) / (3 r)
+    ///
+    /// is proposed, with r being the ratio of norms |f(x')|^2/|f(x)|^2. This procedure is repeated until a suitable step size is found.
+    pub fn gnewton() -> MultiRootFdfSolverType {
+        MultiRootFdfSolverType {
+            t: unsafe { ffi::gsl_multiroot_fdfsolver_gnewton }
+        }
+    }
+}
+
+impl ffi::FFI<ffi::gsl_multiroot_fdfsolver_type> for MultiRootFdfSolverType {
+    fn wrap(t: *mut ffi::gsl_multiroot_fdfsolver_type) -> MultiRootFdfSolverType {
+        MultiRootFdfSolverType {
+            t: t
+        }
+    }
+
+    fn unwrap(t: &MultiRootFdfSolverType) -> *mut ffi::gsl_multiroot_fdfsolver_type {
+        t.t as *mut ffi::gsl_multiroot_fdfsolver_type
+    }
+}
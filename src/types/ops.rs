@@ -0,0 +1,585 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+/*!
+#Operators and iterators
+
+The vector and matrix types implement the standard Rust traits for indexing, iteration and arithmetic, so that numerical code can be
+written with the usual notation instead of explicit method calls.
+
+##Indexing
+
+Vectors are indexed with `v[i]` and matrices with `m[(i, j)]`, where i is the row and j the column. The elements are read and written
+directly in memory, without going through `get` and `set`. An index out of range panics, like for Rust slices, instead of calling the GSL
+error handler.
+
+##Iterators
+
+`iter` and `iter_mut` return iterators over the elements of a vector, or over the elements of a matrix in row-major order. They are also
+available through `IntoIterator` on references, so that a vector or a matrix can be used directly in a `for` loop. The rows and the
+columns of a MatrixF64 can be iterated as read-only vector views with `rows` and `columns`.
+
+##Arithmetic operators
+
+The operators `+`, `-` between two vectors or matrices of the same type, `*` with a scalar and the unary `-` are implemented on
+references and allocate a new object for the result. As the operands can have different dimensions, they return a GSLResult: the error
+::Value::BadLen is returned when the dimensions do not match, and ::Value::NoMem if the result cannot be allocated. The GSL error handler
+is not called, so a mismatch never aborts the program. For example, `let c = (&a + &b)?;` allocates the sum of a and b and propagates
+the error if their lengths differ.
+
+The assignment operators `+=`, `-=` and `*=` update the left operand in place without allocating. They cannot return an error, so they
+panic if the dimensions do not match.
+!*/
+
+use std::marker::PhantomData;
+use std::ops::{Index, IndexMut, Add, Sub, Mul, Neg, AddAssign, SubAssign, MulAssign};
+use ffi;
+use enums::GSLResult;
+use types::{ComplexF32, ComplexF64, VectorF32, VectorF64, VectorComplexF32, VectorComplexF64, MatrixF32, MatrixF64, MatrixComplexF32,
+    MatrixComplexF64, VectorView};
+
+/// Iterator over the elements of a vector, returned by `iter`.
+pub struct VectorIter<'a, T: 'a> {
+    data: *const T,
+    stride: usize,
+    pos: usize,
+    len: usize,
+    phantom: PhantomData<&'a T>
+}
+
+impl<'a, T> Iterator for VectorIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.pos >= self.len {
+            None
+        } else {
+            let r = unsafe { &*self.data.offset((self.pos * self.stride) as isize) };
+
+            self.pos += 1;
+            Some(r)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len - self.pos, Some(self.len - self.pos))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for VectorIter<'a, T> {}
+
+/// Iterator over the mutable elements of a vector, returned by `iter_mut`.
+pub struct VectorIterMut<'a, T: 'a> {
+    data: *mut T,
+    stride: usize,
+    pos: usize,
+    len: usize,
+    phantom: PhantomData<&'a mut T>
+}
+
+impl<'a, T> Iterator for VectorIterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        if self.pos >= self.len {
+            None
+        } else {
+            let r = unsafe { &mut *self.data.offset((self.pos * self.stride) as isize) };
+
+            self.pos += 1;
+            Some(r)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len - self.pos, Some(self.len - self.pos))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for VectorIterMut<'a, T> {}
+
+/// Iterator over the elements of a matrix in row-major order, returned by `iter`.
+pub struct MatrixIter<'a, T: 'a> {
+    data: *const T,
+    size1: usize,
+    size2: usize,
+    tda: usize,
+    pos: usize,
+    phantom: PhantomData<&'a T>
+}
+
+impl<'a, T> Iterator for MatrixIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.pos >= self.size1 * self.size2 {
+            None
+        } else {
+            let (i, j) = (self.pos / self.size2, self.pos % self.size2);
+            let r = unsafe { &*self.data.offset((i * self.tda + j) as isize) };
+
+            self.pos += 1;
+            Some(r)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.size1 * self.size2 - self.pos;
+
+        (n, Some(n))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for MatrixIter<'a, T> {}
+
+/// Iterator over the mutable elements of a matrix in row-major order, returned by `iter_mut`.
+pub struct MatrixIterMut<'a, T: 'a> {
+    data: *mut T,
+    size1: usize,
+    size2: usize,
+    tda: usize,
+    pos: usize,
+    phantom: PhantomData<&'a mut T>
+}
+
+impl<'a, T> Iterator for MatrixIterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        if self.pos >= self.size1 * self.size2 {
+            None
+        } else {
+            let (i, j) = (self.pos / self.size2, self.pos % self.size2);
+            let r = unsafe { &mut *self.data.offset((i * self.tda + j) as isize) };
+
+            self.pos += 1;
+            Some(r)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.size1 * self.size2 - self.pos;
+
+        (n, Some(n))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for MatrixIterMut<'a, T> {}
+
+/// Iterator over the rows of a MatrixF64, returned by `rows`. Each row is a vector view of the matrix.
+pub struct MatrixRows<'a> {
+    m: &'a MatrixF64,
+    pos: usize
+}
+
+impl<'a> Iterator for MatrixRows<'a> {
+    type Item = VectorView<'a>;
+
+    fn next(&mut self) -> Option<VectorView<'a>> {
+        let v = self.m.row(self.pos);
+
+        if v.is_some() {
+            self.pos += 1;
+        }
+        v
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.m.size1() - self.pos;
+
+        (n, Some(n))
+    }
+}
+
+impl<'a> ExactSizeIterator for MatrixRows<'a> {}
+
+/// Iterator over the columns of a MatrixF64, returned by `columns`. Each column is a vector view of the matrix.
+pub struct MatrixColumns<'a> {
+    m: &'a MatrixF64,
+    pos: usize
+}
+
+impl<'a> Iterator for MatrixColumns<'a> {
+    type Item = VectorView<'a>;
+
+    fn next(&mut self) -> Option<VectorView<'a>> {
+        let v = self.m.column(self.pos);
+
+        if v.is_some() {
+            self.pos += 1;
+        }
+        v
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.m.size2() - self.pos;
+
+        (n, Some(n))
+    }
+}
+
+impl<'a> ExactSizeIterator for MatrixColumns<'a> {}
+
+impl MatrixF64 {
+    /// Returns an iterator over the rows of the matrix, as vector views which cannot outlive the matrix.
+    pub fn rows<'a>(&'a self) -> MatrixRows<'a> {
+        MatrixRows {
+            m: self,
+            pos: 0
+        }
+    }
+
+    /// Returns an iterator over the columns of the matrix, as vector views which cannot outlive the matrix.
+    pub fn columns<'a>(&'a self) -> MatrixColumns<'a> {
+        MatrixColumns {
+            m: self,
+            pos: 0
+        }
+    }
+}
+
+macro_rules! impl_vector_ops {
+    ($vector:ident, $elem:ty, $scalar:ty, $minus_one:expr) => (
+        impl $vector {
+            // A null vector is seen as an empty one, so that it never gets dereferenced.
+            fn raw_parts(&self) -> (*mut $elem, usize, usize) {
+                let v = ffi::FFI::unwrap(self);
+
+                if v.is_null() {
+                    (::std::ptr::null_mut(), 1, 0)
+                } else {
+                    unsafe { ((*v).data as *mut $elem, (*v).stride, (*v).size) }
+                }
+            }
+
+            fn elem_ptr(&self, i: usize) -> *mut $elem {
+                let (data, stride, len) = self.raw_parts();
+
+                if i >= len {
+                    panic!("index out of bounds: the len is {} but the index is {}", len, i);
+                }
+                unsafe { data.offset((i * stride) as isize) }
+            }
+
+            /// Returns an iterator over the elements of the vector.
+            pub fn iter<'a>(&'a self) -> VectorIter<'a, $elem> {
+                let (data, stride, len) = self.raw_parts();
+
+                VectorIter {
+                    data: data as *const $elem,
+                    stride: stride,
+                    pos: 0,
+                    len: len,
+                    phantom: PhantomData
+                }
+            }
+
+            /// Returns an iterator over the elements of the vector, allowing to modify them.
+            pub fn iter_mut<'a>(&'a mut self) -> VectorIterMut<'a, $elem> {
+                let (data, stride, len) = self.raw_parts();
+
+                VectorIterMut {
+                    data: data,
+                    stride: stride,
+                    pos: 0,
+                    len: len,
+                    phantom: PhantomData
+                }
+            }
+        }
+
+        impl Index<usize> for $vector {
+            type Output = $elem;
+
+            fn index(&self, i: usize) -> &$elem {
+                unsafe { &*self.elem_ptr(i) }
+            }
+        }
+
+        impl IndexMut<usize> for $vector {
+            fn index_mut(&mut self, i: usize) -> &mut $elem {
+                unsafe { &mut *self.elem_ptr(i) }
+            }
+        }
+
+        impl<'a> IntoIterator for &'a $vector {
+            type Item = &'a $elem;
+            type IntoIter = VectorIter<'a, $elem>;
+
+            fn into_iter(self) -> VectorIter<'a, $elem> {
+                self.iter()
+            }
+        }
+
+        impl<'a> IntoIterator for &'a mut $vector {
+            type Item = &'a mut $elem;
+            type IntoIter = VectorIterMut<'a, $elem>;
+
+            fn into_iter(self) -> VectorIterMut<'a, $elem> {
+                self.iter_mut()
+            }
+        }
+
+        impl<'a, 'b> Add<&'b $vector> for &'a $vector {
+            type Output = GSLResult<$vector>;
+
+            fn add(self, other: &'b $vector) -> GSLResult<$vector> {
+                if self.len() != other.len() {
+                    return Err(::Value::BadLen);
+                }
+                let mut r = match self.clone() {
+                    Some(r) => r,
+                    None => return Err(::Value::NoMem)
+                };
+
+                GSLResult::from($vector::add(&mut r, other))?;
+                Ok(r)
+            }
+        }
+
+        impl<'a, 'b> Sub<&'b $vector> for &'a $vector {
+            type Output = GSLResult<$vector>;
+
+            fn sub(self, other: &'b $vector) -> GSLResult<$vector> {
+                if self.len() != other.len() {
+                    return Err(::Value::BadLen);
+                }
+                let mut r = match self.clone() {
+                    Some(r) => r,
+                    None => return Err(::Value::NoMem)
+                };
+
+                GSLResult::from($vector::sub(&mut r, other))?;
+                Ok(r)
+            }
+        }
+
+        impl<'a> Mul<$scalar> for &'a $vector {
+            type Output = GSLResult<$vector>;
+
+            fn mul(self, x: $scalar) -> GSLResult<$vector> {
+                let mut r = match self.clone() {
+                    Some(r) => r,
+                    None => return Err(::Value::NoMem)
+                };
+
+                GSLResult::from(r.scale(x))?;
+                Ok(r)
+            }
+        }
+
+        impl<'a> Neg for &'a $vector {
+            type Output = GSLResult<$vector>;
+
+            fn neg(self) -> GSLResult<$vector> {
+                self * $minus_one
+            }
+        }
+
+        impl<'a> AddAssign<&'a $vector> for $vector {
+            fn add_assign(&mut self, other: &'a $vector) {
+                if self.len() != other.len() {
+                    panic!("vector lengths do not match: {} and {}", self.len(), other.len());
+                }
+                $vector::add(self, other);
+            }
+        }
+
+        impl<'a> SubAssign<&'a $vector> for $vector {
+            fn sub_assign(&mut self, other: &'a $vector) {
+                if self.len() != other.len() {
+                    panic!("vector lengths do not match: {} and {}", self.len(), other.len());
+                }
+                $vector::sub(self, other);
+            }
+        }
+
+        impl<'a> MulAssign<$scalar> for $vector {
+            fn mul_assign(&mut self, x: $scalar) {
+                self.scale(x);
+            }
+        }
+    );
+}
+
+macro_rules! impl_matrix_ops {
+    ($matrix:ident, $elem:ty, $scalar:ty, $minus_one:expr) => (
+        impl $matrix {
+            // A null matrix is seen as an empty one, so that it never gets dereferenced.
+            fn raw_parts(&self) -> (*mut $elem, usize, usize, usize) {
+                let m = ffi::FFI::unwrap(self);
+
+                if m.is_null() {
+                    (::std::ptr::null_mut(), 0, 0, 0)
+                } else {
+                    unsafe { ((*m).data as *mut $elem, (*m).size1, (*m).size2, (*m).tda) }
+                }
+            }
+
+            fn elem_ptr(&self, i: usize, j: usize) -> *mut $elem {
+                let (data, size1, size2, tda) = self.raw_parts();
+
+                if i >= size1 || j >= size2 {
+                    panic!("index out of bounds: the size is ({}, {}) but the index is ({}, {})", size1, size2, i, j);
+                }
+                unsafe { data.offset((i * tda + j) as isize) }
+            }
+
+            fn dimensions(&self) -> (usize, usize) {
+                let (_, size1, size2, _) = self.raw_parts();
+
+                (size1, size2)
+            }
+
+            /// Returns an iterator over the elements of the matrix, in row-major order.
+            pub fn iter<'a>(&'a self) -> MatrixIter<'a, $elem> {
+                let (data, size1, size2, tda) = self.raw_parts();
+
+                MatrixIter {
+                    data: data as *const $elem,
+                    size1: size1,
+                    size2: size2,
+                    tda: tda,
+                    pos: 0,
+                    phantom: PhantomData
+                }
+            }
+
+            /// Returns an iterator over the elements of the matrix in row-major order, allowing to modify them.
+            pub fn iter_mut<'a>(&'a mut self) -> MatrixIterMut<'a, $elem> {
+                let (data, size1, size2, tda) = self.raw_parts();
+
+                MatrixIterMut {
+                    data: data,
+                    size1: size1,
+                    size2: size2,
+                    tda: tda,
+                    pos: 0,
+                    phantom: PhantomData
+                }
+            }
+        }
+
+        impl Index<(usize, usize)> for $matrix {
+            type Output = $elem;
+
+            fn index(&self, (i, j): (usize, usize)) -> &$elem {
+                unsafe { &*self.elem_ptr(i, j) }
+            }
+        }
+
+        impl IndexMut<(usize, usize)> for $matrix {
+            fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut $elem {
+                unsafe { &mut *self.elem_ptr(i, j) }
+            }
+        }
+
+        impl<'a> IntoIterator for &'a $matrix {
+            type Item = &'a $elem;
+            type IntoIter = MatrixIter<'a, $elem>;
+
+            fn into_iter(self) -> MatrixIter<'a, $elem> {
+                self.iter()
+            }
+        }
+
+        impl<'a> IntoIterator for &'a mut $matrix {
+            type Item = &'a mut $elem;
+            type IntoIter = MatrixIterMut<'a, $elem>;
+
+            fn into_iter(self) -> MatrixIterMut<'a, $elem> {
+                self.iter_mut()
+            }
+        }
+
+        impl<'a, 'b> Add<&'b $matrix> for &'a $matrix {
+            type Output = GSLResult<$matrix>;
+
+            fn add(self, other: &'b $matrix) -> GSLResult<$matrix> {
+                if self.dimensions() != other.dimensions() {
+                    return Err(::Value::BadLen);
+                }
+                let mut r = match self.clone() {
+                    Some(r) => r,
+                    None => return Err(::Value::NoMem)
+                };
+
+                GSLResult::from($matrix::add(&mut r, other))?;
+                Ok(r)
+            }
+        }
+
+        impl<'a, 'b> Sub<&'b $matrix> for &'a $matrix {
+            type Output = GSLResult<$matrix>;
+
+            fn sub(self, other: &'b $matrix) -> GSLResult<$matrix> {
+                if self.dimensions() != other.dimensions() {
+                    return Err(::Value::BadLen);
+                }
+                let mut r = match self.clone() {
+                    Some(r) => r,
+                    None => return Err(::Value::NoMem)
+                };
+
+                GSLResult::from($matrix::sub(&mut r, other))?;
+                Ok(r)
+            }
+        }
+
+        impl<'a> Mul<$scalar> for &'a $matrix {
+            type Output = GSLResult<$matrix>;
+
+            fn mul(self, x: $scalar) -> GSLResult<$matrix> {
+                let mut r = match self.clone() {
+                    Some(r) => r,
+                    None => return Err(::Value::NoMem)
+                };
+
+                GSLResult::from(r.scale(x))?;
+                Ok(r)
+            }
+        }
+
+        impl<'a> Neg for &'a $matrix {
+            type Output = GSLResult<$matrix>;
+
+            fn neg(self) -> GSLResult<$matrix> {
+                self * $minus_one
+            }
+        }
+
+        impl<'a> AddAssign<&'a $matrix> for $matrix {
+            fn add_assign(&mut self, other: &'a $matrix) {
+                if self.dimensions() != other.dimensions() {
+                    panic!("matrix dimensions do not match: {:?} and {:?}", self.dimensions(), other.dimensions());
+                }
+                $matrix::add(self, other);
+            }
+        }
+
+        impl<'a> SubAssign<&'a $matrix> for $matrix {
+            fn sub_assign(&mut self, other: &'a $matrix) {
+                if self.dimensions() != other.dimensions() {
+                    panic!("matrix dimensions do not match: {:?} and {:?}", self.dimensions(), other.dimensions());
+                }
+                $matrix::sub(self, other);
+            }
+        }
+
+        impl<'a> MulAssign<$scalar> for $matrix {
+            fn mul_assign(&mut self, x: $scalar) {
+                self.scale(x);
+            }
+        }
+    );
+}
+
+impl_vector_ops!(VectorF64, f64, f64, -1f64);
+impl_vector_ops!(VectorF32, f32, f32, -1f32);
+impl_vector_ops!(VectorComplexF64, ComplexF64, &'a ComplexF64, &ComplexF64::rect(-1f64, 0f64));
+impl_vector_ops!(VectorComplexF32, ComplexF32, &'a ComplexF32, &ComplexF32 { data: [-1f32, 0f32] });
+impl_matrix_ops!(MatrixF64, f64, f64, -1f64);
+impl_matrix_ops!(MatrixF32, f32, f32, -1f32);
+impl_matrix_ops!(MatrixComplexF64, ComplexF64, &'a ComplexF64, &ComplexF64::rect(-1f64, 0f64));
+impl_matrix_ops!(MatrixComplexF32, ComplexF32, &'a ComplexF32, &ComplexF32 { data: [-1f32, 0f32] });
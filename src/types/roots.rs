@@ -0,0 +1,988 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+/*!
+#One dimensional Root-Finding
+
+This chapter describes routines for finding roots of arbitrary one-dimensional functions. The library provides low level components for a
+variety of iterative solvers and convergence tests. These can be combined by the user to achieve the desired solution, with full access
+to the intermediate steps of the iteration. Each class of methods uses the same framework, so that you can switch between solvers at
+runtime without needing to recompile your program. Each instance of a solver keeps track of its own state, allowing the solvers to be
+used in multi-threaded programs.
+
+##Overview
+
+One-dimensional root finding algorithms can be divided into two classes, root bracketing and root polishing. Algorithms which proceed by
+bracketing a root are guaranteed to converge. Bracketing algorithms begin with a bounded region known to contain a root. The size of this
+bounded region is reduced, iteratively, until it encloses the root to a desired tolerance. This provides a rigorous error estimate for the
+location of the root.
+
+The technique of root polishing attempts to improve an initial guess to the root. These algorithms converge only if started “close enough”
+to a root, and sacrifice a rigorous error bound for speed. By approximating the behavior of a function in the vicinity of a root they
+attempt to find a higher order improvement of an initial guess. When the behavior of the function is compatible with the algorithm and a
+good initial guess is available a polishing algorithm can provide rapid convergence.
+
+In GSL both types of algorithm are available in similar frameworks. The user provides a high-level driver for the algorithms, and the
+library provides the individual functions necessary for each of the steps. There are three main phases of the iteration. The steps are,
+
+ * initialize solver state, s, for algorithm T
+ * update s using the iteration T
+ * test s for convergence, and repeat iteration if necessary
+
+The state for bracketing solvers is held in a RootFSolver. The updating procedure uses only function evaluations (not derivatives). The
+state for root polishing solvers is held in a RootFdfSolver. The updates require both the function and its derivative (hence the name
+fdf) to be supplied by the user.
+
+##Search Bounds and Guesses
+
+You provide either search bounds or an initial guess; this section explains how search bounds and guesses work and how function arguments
+control them.
+
+A guess is simply an x value which is iterated until it is within the desired precision of a root. It takes the form of a f64.
+
+Search bounds are the endpoints of an interval which is iterated until the length of the interval is smaller than the requested precision.
+The interval is defined by two values, the lower limit and the upper limit. Whether the endpoints are intended to be included in the
+interval or not depends on the context in which the interval is used.
+
+##Stopping Parameters
+
+A root finding procedure should stop when one of the following conditions is true:
+
+ * A root has been found to within the user-specified precision.
+ * A user-specified maximum number of iterations has been reached.
+ * An error has occurred.
+
+The handling of these conditions is under user control. The functions in the `roots` module allow the user to test the precision of the
+current result in several standard ways.
+!*/
+
+use ffi;
+use libc::{c_void, malloc, free};
+
+fn safe_func_call<T>(f: ::function<T>, arg: &mut T, x: f64, yp: &mut f64) -> ::Value {
+    *yp = f(x, arg);
+    if !yp.is_finite() {
+        rgsl_error!("function value is not finite", ::Value::BadFunc);
+        return ::Value::BadFunc;
+    }
+    ::Value::Success
+}
+
+pub struct RootFSolver<T> {
+    type_: RootFSolverType<T>,
+    function: Option<::function<T>>,
+    arg: Option<*mut c_void>,
+    root: f64,
+    x_lower: f64,
+    x_upper: f64,
+    state: *mut c_void
+}
+
+impl<T> RootFSolver<T> {
+    /// This function returns a newly allocated instance of a solver of type T. For example, the following code creates an instance of a
+    /// bisection solver,
+    ///
+    /// ```Rust
+    /// let t : RootFSolverType<f64> = RootFSolverType::bisection();
+    /// let s = RootFSolver::new(&t).unwrap();
+    /// ```
+    ///
+    /// If there is insufficient memory to create the solver then the function returns None.
+    pub fn new(t: &RootFSolverType<T>) -> Option<RootFSolver<T>> {
+        let state = unsafe { malloc(t.size) };
+
+        if state.is_null() {
+            None
+        } else {
+            Some(RootFSolver {
+                type_: t.clone(),
+                function: None,
+                arg: None,
+                root: 0f64,
+                x_lower: 0f64,
+                x_upper: 0f64,
+                state: state
+            })
+        }
+    }
+
+    /// This function initializes, or reinitializes, an existing solver s to use the function f and the initial search interval
+    /// [x_lower, x_upper].
+    pub fn set(&mut self, f: ::function<T>, arg: &mut T, x_lower: f64, x_upper: f64) -> ::Value {
+        if x_lower > x_upper {
+            rgsl_error!("invalid interval (lower > upper)", ::Value::Inval);
+            return ::Value::Inval;
+        }
+
+        self.function = Some(f);
+        self.arg = unsafe { Some(::std::mem::transmute(arg)) };
+        self.root = 0f64;
+        self.x_lower = x_lower;
+        self.x_upper = x_upper;
+
+        unsafe {
+            (self.type_.set)(self.state, f, ::std::mem::transmute(self.arg.unwrap()), &mut self.root, x_lower, x_upper)
+        }
+    }
+
+    /// This function performs a single iteration of the solver s. If the iteration encounters an unexpected problem then an error code
+    /// will be returned,
+    ///
+    /// ::Value::BadFunc
+    /// the iteration encountered a singular point where the function evaluated to Inf or NaN.
+    ///
+    /// The solver maintains a current best estimate of the root at all times. The bracketing solvers also keep track of the current best
+    /// interval bounding the root.
+    pub fn iterate(&mut self) -> ::Value {
+        match (self.function, self.arg) {
+            (Some(f), Some(arg)) => unsafe {
+                (self.type_.iterate)(self.state, f, ::std::mem::transmute(arg), &mut self.root, &mut self.x_lower, &mut self.x_upper)
+            },
+            _ => {
+                rgsl_error!("solver has not been set", ::Value::Inval);
+                ::Value::Inval
+            }
+        }
+    }
+
+    /// This function returns the name of the solver. For example,
+    ///
+    /// ```Rust
+    /// println!("s is a '{}' solver", s.name());
+    /// ```
+    ///
+    /// would print something like s is a 'bisection' solver.
+    pub fn name(&self) -> String {
+        self.type_.name.clone()
+    }
+
+    /// This function returns the current estimate of the root for the solver s.
+    pub fn root(&self) -> f64 {
+        self.root
+    }
+
+    /// This function returns the current lower bound of the bracketing interval for the solver s.
+    pub fn x_lower(&self) -> f64 {
+        self.x_lower
+    }
+
+    /// This function returns the current upper bound of the bracketing interval for the solver s.
+    pub fn x_upper(&self) -> f64 {
+        self.x_upper
+    }
+}
+
+impl<T> Drop for RootFSolver<T> {
+    fn drop(&mut self) {
+        unsafe { free(self.state) };
+        self.state = ::std::ptr::null_mut();
+    }
+}
+
+pub struct RootFSolverType<T> {
+    pub name: String,
+    size: usize,
+    set: fn(state: *mut c_void, f: ::function<T>, arg: &mut T, root: &mut f64, x_lower: f64, x_upper: f64) -> ::Value,
+    iterate: fn(state: *mut c_void, f: ::function<T>, arg: &mut T, root: &mut f64, x_lower: &mut f64, x_upper: &mut f64) -> ::Value
+}
+
+impl<T> RootFSolverType<T> {
+    /// The bisection algorithm is the simplest method of bracketing the roots of a function. It is the slowest algorithm provided by the
+    /// library, with linear convergence.
+    ///
+    /// On each iteration, the interval is bisected and the value of the function at the midpoint is calculated. The sign of this value is
+    /// used to determine which half of the interval does not contain a root. That half is discarded to give a new, smaller interval
+    /// containing the root. This procedure can be continued indefinitely until the interval is sufficiently small.
+    ///
+    /// At any time the current estimate of the root is taken as the midpoint of the interval.
+    pub fn bisection() -> RootFSolverType<T> {
+        RootFSolverType {
+            name: "bisection".to_string(),
+            size: ::std::mem::size_of::<bisection_state_t>() as usize,
+            set: bisection_init,
+            iterate: bisection_iterate
+        }
+    }
+
+    /// The false position algorithm is a method of finding roots based on linear interpolation. Its convergence is linear, but it is
+    /// usually faster than bisection.
+    ///
+    /// On each iteration a line is drawn between the endpoints (a,f(a)) and (b,f(b)) and the point where this line crosses the x-axis
+    /// taken as a “midpoint”. The value of the function at this point is calculated and its sign is used to determine which side of the
+    /// interval does not contain a root. That side is discarded to give a new, smaller interval containing the root. This procedure can
+    /// be continued indefinitely until the interval is sufficiently small.
+    ///
+    /// The best estimate of the root is taken from the linear interpolation of the interval on the current iteration.
+    pub fn falsepos() -> RootFSolverType<T> {
+        RootFSolverType {
+            name: "falsepos".to_string(),
+            size: ::std::mem::size_of::<falsepos_state_t>() as usize,
+            set: falsepos_init,
+            iterate: falsepos_iterate
+        }
+    }
+
+    /// The Brent-Dekker method (referred to here as Brent's method) combines an interpolation strategy with the bisection algorithm. This
+    /// produces a fast algorithm which is still robust.
+    ///
+    /// On each iteration Brent's method approximates the function using an interpolating curve. On the first iteration this is a linear
+    /// interpolation of the two endpoints. For subsequent iterations the algorithm uses an inverse quadratic fit to the last three points,
+    /// for higher accuracy. The intercept of the interpolating curve with the x-axis is taken as a guess for the root. If it lies within
+    /// the bounds of the current interval then the interpolating point is accepted, and used to generate a smaller interval. If the
+    /// interpolating point is not accepted then the algorithm falls back to an ordinary bisection step.
+    ///
+    /// The best estimate of the root is taken from the most recent interpolation or bisection.
+    pub fn brent() -> RootFSolverType<T> {
+        RootFSolverType {
+            name: "brent".to_string(),
+            size: ::std::mem::size_of::<brent_state_t>() as usize,
+            set: brent_init,
+            iterate: brent_iterate
+        }
+    }
+}
+
+impl<T> Clone for RootFSolverType<T> {
+    fn clone(&self) -> RootFSolverType<T> {
+        RootFSolverType {
+            name: self.name.clone(),
+            size: self.size,
+            set: self.set,
+            iterate: self.iterate
+        }
+    }
+}
+
+pub struct RootFdfSolver<T> {
+    type_: RootFdfSolverType<T>,
+    f: Option<::function<T>>,
+    df: Option<::function<T>>,
+    fdf: Option<::fdf_function<T>>,
+    arg: Option<*mut c_void>,
+    root: f64,
+    state: *mut c_void
+}
+
+impl<T> RootFdfSolver<T> {
+    /// This function returns a newly allocated instance of a derivative-based solver of type T. For example, the following code creates
+    /// an instance of a Newton-Raphson solver,
+    ///
+    /// ```Rust
+    /// let t : RootFdfSolverType<f64> = RootFdfSolverType::newton();
+    /// let s = RootFdfSolver::new(&t).unwrap();
+    /// ```
+    ///
+    /// If there is insufficient memory to create the solver then the function returns None.
+    pub fn new(t: &RootFdfSolverType<T>) -> Option<RootFdfSolver<T>> {
+        let state = unsafe { malloc(t.size) };
+
+        if state.is_null() {
+            None
+        } else {
+            Some(RootFdfSolver {
+                type_: t.clone(),
+                f: None,
+                df: None,
+                fdf: None,
+                arg: None,
+                root: 0f64,
+                state: state
+            })
+        }
+    }
+
+    /// This function initializes, or reinitializes, an existing solver s to use the function f, its derivative df, the combined
+    /// function fdf (which computes both values at once) and the initial guess root.
+    pub fn set(&mut self, f: ::function<T>, df: ::function<T>, fdf: ::fdf_function<T>, arg: &mut T, root: f64) -> ::Value {
+        self.f = Some(f);
+        self.df = Some(df);
+        self.fdf = Some(fdf);
+        self.arg = unsafe { Some(::std::mem::transmute(arg)) };
+        self.root = root;
+
+        unsafe {
+            (self.type_.set)(self.state, f, df, fdf, ::std::mem::transmute(self.arg.unwrap()), &mut self.root)
+        }
+    }
+
+    /// This function performs a single iteration of the solver s. If the iteration encounters an unexpected problem then an error code
+    /// will be returned,
+    ///
+    /// ::Value::BadFunc
+    /// the iteration encountered a singular point where the function or its derivative evaluated to Inf or NaN.
+    ///
+    /// ::Value::ZeroDiv
+    /// the derivative of the function vanished at the iteration point, preventing the algorithm from continuing without a division by
+    /// zero.
+    pub fn iterate(&mut self) -> ::Value {
+        match (self.f, self.df, self.fdf, self.arg) {
+            (Some(f), Some(df), Some(fdf), Some(arg)) => unsafe {
+                (self.type_.iterate)(self.state, f, df, fdf, ::std::mem::transmute(arg), &mut self.root)
+            },
+            _ => {
+                rgsl_error!("solver has not been set", ::Value::Inval);
+                ::Value::Inval
+            }
+        }
+    }
+
+    /// This function returns the name of the solver.
+    pub fn name(&self) -> String {
+        self.type_.name.clone()
+    }
+
+    /// This function returns the current estimate of the root for the solver s.
+    pub fn root(&self) -> f64 {
+        self.root
+    }
+}
+
+impl<T> Drop for RootFdfSolver<T> {
+    fn drop(&mut self) {
+        unsafe { free(self.state) };
+        self.state = ::std::ptr::null_mut();
+    }
+}
+
+pub struct RootFdfSolverType<T> {
+    pub name: String,
+    size: usize,
+    set: fn(state: *mut c_void, f: ::function<T>, df: ::function<T>, fdf: ::fdf_function<T>, arg: &mut T, root: &mut f64) -> ::Value,
+    iterate: fn(state: *mut c_void, f: ::function<T>, df: ::function<T>, fdf: ::fdf_function<T>, arg: &mut T, root: &mut f64) -> ::Value
+}
+
+impl<T> RootFdfSolverType<T> {
+    /// Newton's Method is the standard root-polishing algorithm. The algorithm begins with an initial guess for the location of the root.
+    /// On each iteration, a line tangent to the function f is drawn at that position. The point where this line crosses the x-axis
+    /// becomes the new guess. The iteration is defined by the following sequence,
+    ///
+    /// x_{i+1} = x_i - f(x_i)/f'(x_i)
+    ///
+    /// Newton's method converges quadratically for single roots, and linearly for multiple roots.
+    pub fn newton() -> RootFdfSolverType<T> {
+        RootFdfSolverType {
+            name: "newton".to_string(),
+            size: ::std::mem::size_of::<newton_state_t>() as usize,
+            set: newton_init,
+            iterate: newton_iterate
+        }
+    }
+
+    /// The secant method is a simplified version of Newton's method which does not require the computation of the derivative on every
+    /// step.
+    ///
+    /// On its first iteration the algorithm begins with Newton's method, using the derivative to compute a first step,
+    ///
+    /// x_1 = x_0 - f(x_0)/f'(x_0)
+    ///
+    /// Subsequent iterations avoid the evaluation of the derivative by replacing it with a numerical estimate, the slope of the line
+    /// through the previous two points,
+    ///
+    /// x_{i+1} = x_i f(x_i) / f'_{est} where f'_{est} = (f(x_i) - f(x_{i-1})/(x_i - x_{i-1})
+    ///
+    /// When the derivative does not change significantly in the vicinity of the root the secant method gives a useful saving.
+    /// Asymptotically the secant method is faster than Newton's method whenever the cost of evaluating the derivative is more than 0.44
+    /// times the cost of evaluating the function itself.
+    pub fn secant() -> RootFdfSolverType<T> {
+        RootFdfSolverType {
+            name: "secant".to_string(),
+            size: ::std::mem::size_of::<secant_state_t>() as usize,
+            set: secant_init,
+            iterate: secant_iterate
+        }
+    }
+
+    /// The Steffenson Method provides the fastest convergence of all the routines. It combines the basic Newton algorithm with an Aitken
+    /// “delta-squared” acceleration. If the Newton iterates are x_i then the acceleration procedure generates a new sequence R_i,
+    ///
+    /// R_i = x_i - (x_{i+1} - x_i)^2 / (x_{i+2} - 2 x_{i+1} + x_{i})
+    ///
+    /// which converges faster than the original sequence under reasonable conditions. The new sequence requires three terms before it can
+    /// produce its first value so the method returns accelerated values on the second and subsequent iterations. On the first iteration
+    /// it returns the ordinary Newton estimate.
+    pub fn steffenson() -> RootFdfSolverType<T> {
+        RootFdfSolverType {
+            name: "steffenson".to_string(),
+            size: ::std::mem::size_of::<steffenson_state_t>() as usize,
+            set: steffenson_init,
+            iterate: steffenson_iterate
+        }
+    }
+}
+
+impl<T> Clone for RootFdfSolverType<T> {
+    fn clone(&self) -> RootFdfSolverType<T> {
+        RootFdfSolverType {
+            name: self.name.clone(),
+            size: self.size,
+            set: self.set,
+            iterate: self.iterate
+        }
+    }
+}
+
+struct bisection_state_t {
+    f_upper: f64,
+    f_lower: f64
+}
+
+fn bisection_init<T>(vstate: *mut c_void, f: ::function<T>, arg: &mut T, root: &mut f64, x_lower: f64, x_upper: f64) -> ::Value {
+    let state : &mut bisection_state_t = unsafe { ::std::mem::transmute(vstate) };
+    let mut f_lower = 0f64;
+    let mut f_upper = 0f64;
+
+    *root = 0.5f64 * (x_lower + x_upper);
+
+    let status = safe_func_call(f, arg, x_lower, &mut f_lower);
+    if status != ::Value::Success {
+        return status;
+    }
+    let status = safe_func_call(f, arg, x_upper, &mut f_upper);
+    if status != ::Value::Success {
+        return status;
+    }
+
+    state.f_lower = f_lower;
+    state.f_upper = f_upper;
+
+    if (f_lower < 0f64 && f_upper < 0f64) || (f_lower > 0f64 && f_upper > 0f64) {
+        rgsl_error!("endpoints do not straddle y=0", ::Value::Inval);
+        return ::Value::Inval;
+    }
+
+    ::Value::Success
+}
+
+fn bisection_iterate<T>(vstate: *mut c_void, f: ::function<T>, arg: &mut T, root: &mut f64, x_lower: &mut f64, x_upper: &mut f64)
+    -> ::Value {
+    let state : &mut bisection_state_t = unsafe { ::std::mem::transmute(vstate) };
+
+    let x_left = *x_lower;
+    let x_right = *x_upper;
+
+    let f_lower = state.f_lower;
+    let f_upper = state.f_upper;
+
+    if f_lower == 0f64 {
+        *root = x_left;
+        *x_upper = x_left;
+        return ::Value::Success;
+    }
+
+    if f_upper == 0f64 {
+        *root = x_right;
+        *x_lower = x_right;
+        return ::Value::Success;
+    }
+
+    let x_bisect = (x_left + x_right) / 2f64;
+    let mut f_bisect = 0f64;
+
+    let status = safe_func_call(f, arg, x_bisect, &mut f_bisect);
+    if status != ::Value::Success {
+        return status;
+    }
+
+    if f_bisect == 0f64 {
+        *root = x_bisect;
+        *x_lower = x_bisect;
+        *x_upper = x_bisect;
+        return ::Value::Success;
+    }
+
+    /* Discard the half of the interval which doesn't contain the root. */
+    if (f_lower > 0f64 && f_bisect < 0f64) || (f_lower < 0f64 && f_bisect > 0f64) {
+        *root = 0.5f64 * (x_left + x_bisect);
+        *x_upper = x_bisect;
+        state.f_upper = f_bisect;
+    } else {
+        *root = 0.5f64 * (x_bisect + x_right);
+        *x_lower = x_bisect;
+        state.f_lower = f_bisect;
+    }
+
+    ::Value::Success
+}
+
+struct falsepos_state_t {
+    f_upper: f64,
+    f_lower: f64
+}
+
+fn falsepos_init<T>(vstate: *mut c_void, f: ::function<T>, arg: &mut T, root: &mut f64, x_lower: f64, x_upper: f64) -> ::Value {
+    let state : &mut falsepos_state_t = unsafe { ::std::mem::transmute(vstate) };
+    let mut f_lower = 0f64;
+    let mut f_upper = 0f64;
+
+    *root = 0.5f64 * (x_lower + x_upper);
+
+    let status = safe_func_call(f, arg, x_lower, &mut f_lower);
+    if status != ::Value::Success {
+        return status;
+    }
+    let status = safe_func_call(f, arg, x_upper, &mut f_upper);
+    if status != ::Value::Success {
+        return status;
+    }
+
+    state.f_lower = f_lower;
+    state.f_upper = f_upper;
+
+    if (f_lower < 0f64 && f_upper < 0f64) || (f_lower > 0f64 && f_upper > 0f64) {
+        rgsl_error!("endpoints do not straddle y=0", ::Value::Inval);
+        return ::Value::Inval;
+    }
+
+    ::Value::Success
+}
+
+fn falsepos_iterate<T>(vstate: *mut c_void, f: ::function<T>, arg: &mut T, root: &mut f64, x_lower: &mut f64, x_upper: &mut f64)
+    -> ::Value {
+    let state : &mut falsepos_state_t = unsafe { ::std::mem::transmute(vstate) };
+
+    let x_left = *x_lower;
+    let x_right = *x_upper;
+
+    let f_lower = state.f_lower;
+    let f_upper = state.f_upper;
+
+    if f_lower == 0f64 {
+        *root = x_left;
+        *x_upper = x_left;
+        return ::Value::Success;
+    }
+
+    if f_upper == 0f64 {
+        *root = x_right;
+        *x_lower = x_right;
+        return ::Value::Success;
+    }
+
+    /* Draw a line between f(*lower_bound) and f(*upper_bound) and note where it crosses the X axis; that's where we will split the
+       interval. */
+    let x_linear = x_right - (f_upper * (x_left - x_right) / (f_lower - f_upper));
+    let mut f_linear = 0f64;
+
+    let status = safe_func_call(f, arg, x_linear, &mut f_linear);
+    if status != ::Value::Success {
+        return status;
+    }
+
+    if f_linear == 0f64 {
+        *root = x_linear;
+        *x_lower = x_linear;
+        *x_upper = x_linear;
+        return ::Value::Success;
+    }
+
+    /* Discard the half of the interval which doesn't contain the root. */
+    let w = if (f_lower > 0f64 && f_linear < 0f64) || (f_lower < 0f64 && f_linear > 0f64) {
+        *root = x_linear;
+        *x_upper = x_linear;
+        state.f_upper = f_linear;
+        x_linear - x_left
+    } else {
+        *root = x_linear;
+        *x_lower = x_linear;
+        state.f_lower = f_linear;
+        x_right - x_linear
+    };
+
+    if w < 0.5f64 * (x_right - x_left) {
+        return ::Value::Success;
+    }
+
+    let x_bisect = 0.5f64 * (x_left + x_right);
+    let mut f_bisect = 0f64;
+
+    let status = safe_func_call(f, arg, x_bisect, &mut f_bisect);
+    if status != ::Value::Success {
+        return status;
+    }
+
+    if (f_lower > 0f64 && f_bisect < 0f64) || (f_lower < 0f64 && f_bisect > 0f64) {
+        *x_upper = x_bisect;
+        state.f_upper = f_bisect;
+        if *root > x_bisect {
+            *root = 0.5f64 * (x_left + x_bisect);
+        }
+    } else {
+        *x_lower = x_bisect;
+        state.f_lower = f_bisect;
+        if *root < x_bisect {
+            *root = 0.5f64 * (x_bisect + x_right);
+        }
+    }
+
+    ::Value::Success
+}
+
+struct brent_state_t {
+    a: f64,
+    b: f64,
+    c: f64,
+    d: f64,
+    e: f64,
+    fa: f64,
+    fb: f64,
+    fc: f64
+}
+
+fn brent_init<T>(vstate: *mut c_void, f: ::function<T>, arg: &mut T, root: &mut f64, x_lower: f64, x_upper: f64) -> ::Value {
+    let state : &mut brent_state_t = unsafe { ::std::mem::transmute(vstate) };
+    let mut f_lower = 0f64;
+    let mut f_upper = 0f64;
+
+    *root = 0.5f64 * (x_lower + x_upper);
+
+    let status = safe_func_call(f, arg, x_lower, &mut f_lower);
+    if status != ::Value::Success {
+        return status;
+    }
+    let status = safe_func_call(f, arg, x_upper, &mut f_upper);
+    if status != ::Value::Success {
+        return status;
+    }
+
+    state.a = x_lower;
+    state.fa = f_lower;
+
+    state.b = x_upper;
+    state.fb = f_upper;
+
+    state.c = x_upper;
+    state.fc = f_upper;
+
+    state.d = x_upper - x_lower;
+    state.e = x_upper - x_lower;
+
+    if (f_lower < 0f64 && f_upper < 0f64) || (f_lower > 0f64 && f_upper > 0f64) {
+        rgsl_error!("endpoints do not straddle y=0", ::Value::Inval);
+        return ::Value::Inval;
+    }
+
+    ::Value::Success
+}
+
+fn brent_iterate<T>(vstate: *mut c_void, f: ::function<T>, arg: &mut T, root: &mut f64, x_lower: &mut f64, x_upper: &mut f64)
+    -> ::Value {
+    let state : &mut brent_state_t = unsafe { ::std::mem::transmute(vstate) };
+
+    let mut ac_equal = false;
+
+    let mut a = state.a;
+    let mut b = state.b;
+    let mut c = state.c;
+    let mut fa = state.fa;
+    let mut fb = state.fb;
+    let mut fc = state.fc;
+    let mut d = state.d;
+    let mut e = state.e;
+
+    if (fb < 0f64 && fc < 0f64) || (fb > 0f64 && fc > 0f64) {
+        ac_equal = true;
+        c = a;
+        fc = fa;
+        d = b - a;
+        e = b - a;
+    }
+
+    if fc.abs() < fb.abs() {
+        ac_equal = true;
+        a = b;
+        b = c;
+        c = a;
+        fa = fb;
+        fb = fc;
+        fc = fa;
+    }
+
+    let tol = 0.5f64 * ::DBL_EPSILON * b.abs();
+    let m = 0.5f64 * (c - b);
+
+    if fb == 0f64 {
+        *root = b;
+        *x_lower = b;
+        *x_upper = b;
+
+        return ::Value::Success;
+    }
+
+    if m.abs() <= tol {
+        *root = b;
+
+        if b < c {
+            *x_lower = b;
+            *x_upper = c;
+        } else {
+            *x_lower = c;
+            *x_upper = b;
+        }
+
+        return ::Value::Success;
+    }
+
+    if e.abs() < tol || fa.abs() <= fb.abs() {
+        /* use bisection */
+        d = m;
+        e = m;
+    } else {
+        /* use inverse cubic interpolation */
+        let mut p;
+        let mut q;
+        let s = fb / fa;
+
+        if ac_equal {
+            p = 2f64 * m * s;
+            q = 1f64 - s;
+        } else {
+            q = fa / fc;
+            let r = fb / fc;
+            p = s * (2f64 * m * q * (q - r) - (b - a) * (r - 1f64));
+            q = (q - 1f64) * (r - 1f64) * (s - 1f64);
+        }
+
+        if p > 0f64 {
+            q = -q;
+        } else {
+            p = -p;
+        }
+
+        if 2f64 * p < (3f64 * m * q - (tol * q).abs()).min((e * q).abs()) {
+            e = d;
+            d = p / q;
+        } else {
+            /* interpolation failed, fall back to bisection */
+            d = m;
+            e = m;
+        }
+    }
+
+    a = b;
+    fa = fb;
+
+    if d.abs() > tol {
+        b += d;
+    } else {
+        b += if m > 0f64 { tol } else { -tol };
+    }
+
+    let status = safe_func_call(f, arg, b, &mut fb);
+    if status != ::Value::Success {
+        return status;
+    }
+
+    state.a = a;
+    state.b = b;
+    state.c = c;
+    state.d = d;
+    state.e = e;
+    state.fa = fa;
+    state.fb = fb;
+    state.fc = fc;
+
+    /* Update the best estimate of the root and bounds on each iteration */
+    *root = b;
+
+    if (fb < 0f64 && fc < 0f64) || (fb > 0f64 && fc > 0f64) {
+        c = a;
+    }
+
+    if b < c {
+        *x_lower = b;
+        *x_upper = c;
+    } else {
+        *x_lower = c;
+        *x_upper = b;
+    }
+
+    ::Value::Success
+}
+
+struct newton_state_t {
+    f: f64,
+    df: f64
+}
+
+#[allow(unused_variables)]
+fn newton_init<T>(vstate: *mut c_void, f: ::function<T>, df: ::function<T>, fdf: ::fdf_function<T>, arg: &mut T, root: &mut f64)
+    -> ::Value {
+    let state : &mut newton_state_t = unsafe { ::std::mem::transmute(vstate) };
+
+    fdf(*root, arg, &mut state.f, &mut state.df);
+
+    ::Value::Success
+}
+
+#[allow(unused_variables)]
+fn newton_iterate<T>(vstate: *mut c_void, f: ::function<T>, df: ::function<T>, fdf: ::fdf_function<T>, arg: &mut T, root: &mut f64)
+    -> ::Value {
+    let state : &mut newton_state_t = unsafe { ::std::mem::transmute(vstate) };
+
+    if state.df == 0f64 {
+        rgsl_error!("derivative is zero", ::Value::ZeroDiv);
+        return ::Value::ZeroDiv;
+    }
+
+    let root_new = *root - (state.f / state.df);
+    let mut f_new = 0f64;
+    let mut df_new = 0f64;
+
+    *root = root_new;
+
+    fdf(root_new, arg, &mut f_new, &mut df_new);
+
+    state.f = f_new;
+    state.df = df_new;
+
+    if !f_new.is_finite() {
+        rgsl_error!("function value is not finite", ::Value::BadFunc);
+        return ::Value::BadFunc;
+    }
+
+    if !df_new.is_finite() {
+        rgsl_error!("derivative value is not finite", ::Value::BadFunc);
+        return ::Value::BadFunc;
+    }
+
+    ::Value::Success
+}
+
+struct secant_state_t {
+    f: f64,
+    df: f64
+}
+
+#[allow(unused_variables)]
+fn secant_init<T>(vstate: *mut c_void, f: ::function<T>, df: ::function<T>, fdf: ::fdf_function<T>, arg: &mut T, root: &mut f64)
+    -> ::Value {
+    let state : &mut secant_state_t = unsafe { ::std::mem::transmute(vstate) };
+
+    fdf(*root, arg, &mut state.f, &mut state.df);
+
+    ::Value::Success
+}
+
+#[allow(unused_variables)]
+fn secant_iterate<T>(vstate: *mut c_void, f: ::function<T>, df: ::function<T>, fdf: ::fdf_function<T>, arg: &mut T, root: &mut f64)
+    -> ::Value {
+    let state : &mut secant_state_t = unsafe { ::std::mem::transmute(vstate) };
+
+    let x = *root;
+    let f_x = state.f;
+    let df_x = state.df;
+
+    if df_x == 0f64 {
+        rgsl_error!("derivative is zero", ::Value::ZeroDiv);
+        return ::Value::ZeroDiv;
+    }
+
+    let x_new = x - (f_x / df_x);
+    let f_new = f(x_new, arg);
+    let df_new = (f_new - f_x) / (x_new - x);
+
+    *root = x_new;
+
+    state.f = f_new;
+    state.df = df_new;
+
+    if !f_new.is_finite() {
+        rgsl_error!("function value is not finite", ::Value::BadFunc);
+        return ::Value::BadFunc;
+    }
+
+    if !df_new.is_finite() {
+        rgsl_error!("derivative value is not finite", ::Value::BadFunc);
+        return ::Value::BadFunc;
+    }
+
+    ::Value::Success
+}
+
+struct steffenson_state_t {
+    f: f64,
+    df: f64,
+    x: f64,
+    x_1: f64,
+    x_2: f64,
+    count: i32
+}
+
+#[allow(unused_variables)]
+fn steffenson_init<T>(vstate: *mut c_void, f: ::function<T>, df: ::function<T>, fdf: ::fdf_function<T>, arg: &mut T, root: &mut f64)
+    -> ::Value {
+    let state : &mut steffenson_state_t = unsafe { ::std::mem::transmute(vstate) };
+
+    let x = *root;
+
+    state.f = 0f64;
+    state.df = 0f64;
+    fdf(x, arg, &mut state.f, &mut state.df);
+
+    state.x = x;
+    state.x_1 = 0f64;
+    state.x_2 = 0f64;
+
+    state.count = 1;
+
+    ::Value::Success
+}
+
+#[allow(unused_variables)]
+fn steffenson_iterate<T>(vstate: *mut c_void, f: ::function<T>, df: ::function<T>, fdf: ::fdf_function<T>, arg: &mut T, root: &mut f64)
+    -> ::Value {
+    let state : &mut steffenson_state_t = unsafe { ::std::mem::transmute(vstate) };
+
+    let x = state.x;
+    let f_x = state.f;
+    let df_x = state.df;
+
+    let x_1 = state.x_1;
+
+    if df_x == 0f64 {
+        rgsl_error!("derivative is zero", ::Value::ZeroDiv);
+        return ::Value::ZeroDiv;
+    }
+
+    let x_new = x - (f_x / df_x);
+    let mut f_new = 0f64;
+    let mut df_new = 0f64;
+
+    fdf(x_new, arg, &mut f_new, &mut df_new);
+
+    state.x_2 = x_1;
+    state.x_1 = x;
+    state.x = x_new;
+
+    state.f = f_new;
+    state.df = df_new;
+
+    if !f_new.is_finite() {
+        rgsl_error!("function value is not finite", ::Value::BadFunc);
+        return ::Value::BadFunc;
+    }
+
+    if state.count < 3 {
+        *root = x_new;
+        state.count += 1;
+    } else {
+        let u = x - x_1;
+        let v = x_new - 2f64 * x + x_1;
+
+        if v == 0f64 {
+            /* avoid division by zero */
+            *root = x_new;
+        } else {
+            /* accelerated value */
+            *root = x_1 - u * u / v;
+        }
+    }
+
+    if !df_new.is_finite() {
+        rgsl_error!("derivative value is not finite", ::Value::BadFunc);
+        return ::Value::BadFunc;
+    }
+
+    ::Value::Success
+}
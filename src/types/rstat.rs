@@ -0,0 +1,261 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+/*!
+#Running Statistics
+
+This chapter describes routines for computing running statistics, also known as online statistics, of data. These routines are suitable
+for handling large datasets for which it may be inconvenient or impractical to store in memory all at once. The data can be processed in
+a single pass, one point at a time. Each time a data point is added to the accumulator, internal parameters are updated in order to
+compute the current mean, variance, standard deviation, skewness, and kurtosis. These statistics are exact, and are updated with
+numerically stable single-pass algorithms. The median and arbitrary quantiles are also available, however these calculations use
+algorithms which provide approximations, and grow more accurate as more data is added to the accumulator.
+
+##Quantiles
+
+The quantile estimates are computed with the P^2 algorithm of Jain and Chlamtac, which maintains five markers whose heights are updated
+with a piecewise-parabolic formula as data is added. It needs only a fixed amount of memory, regardless of the number of samples.
+
+##Combining accumulators
+
+Two RStatWorkspace objects which accumulated disjoint parts of a dataset, for example on different threads, can be combined with
+`merge`. The mean, variance, skewness, kurtosis, minimum, maximum and count of the merged accumulator are exactly those of the whole
+dataset, using the pairwise update formulas of Chan, Golub and LeVeque extended to third and fourth moments by Pébay. The median
+estimate cannot be merged, see `merge` for details.
+
+##References and Further Reading
+
+The algorithm used to dynamically estimate p-quantiles is described in the paper,
+
+R. Jain and I. Chlamtac. The P^2 algorithm for dynamic calculation of quantiles and histograms without storing observations,
+Communications of the ACM, Volume 28 (October), Number 10, 1985, p. 1076-1085.
+
+The formulas used to combine the moments of two accumulators are described in,
+
+P. Pébay. Formulas for robust, one-pass parallel computation of covariances and arbitrary-order statistical moments, Sandia Report
+SAND2008-6212, 2008.
+!*/
+
+use ffi;
+use enums::GSLResult;
+
+pub struct RStatWorkspace {
+    w: *mut ffi::gsl_rstat_workspace
+}
+
+impl RStatWorkspace {
+    /// This function allocates a workspace for computing running statistics. The size of the workspace is O(1).
+    pub fn new() -> Option<RStatWorkspace> {
+        let tmp = unsafe { ffi::gsl_rstat_alloc() };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(RStatWorkspace {
+                w: tmp
+            })
+        }
+    }
+
+    /// This function adds the data point x to the statistical accumulator, updating calculations of the mean, variance, standard
+    /// deviation, skewness, kurtosis, and median.
+    pub fn add(&mut self, x: f64) -> GSLResult<()> {
+        GSLResult::from(unsafe { ffi::gsl_rstat_add(x, self.w) })
+    }
+
+    /// This function returns the number of data so far added to the accumulator.
+    pub fn n(&self) -> usize {
+        unsafe { ffi::gsl_rstat_n(self.w) }
+    }
+
+    /// This function returns the minimum value added to the accumulator.
+    pub fn min(&self) -> f64 {
+        unsafe { ffi::gsl_rstat_min(self.w) }
+    }
+
+    /// This function returns the maximum value added to the accumulator.
+    pub fn max(&self) -> f64 {
+        unsafe { ffi::gsl_rstat_max(self.w) }
+    }
+
+    /// This function returns the mean of all data added to the accumulator, defined as
+    ///
+    /// \Hat\mu = (1/N) \sum x_i
+    pub fn mean(&self) -> f64 {
+        unsafe { ffi::gsl_rstat_mean(self.w) }
+    }
+
+    /// This function returns the variance of all data added to the accumulator, defined as
+    ///
+    /// \Hat\sigma^2 = (1/(N-1)) \sum (x_i - \Hat\mu)^2
+    pub fn variance(&self) -> f64 {
+        unsafe { ffi::gsl_rstat_variance(self.w) }
+    }
+
+    /// This function returns the standard deviation of all data added to the accumulator, defined as the square root of the variance
+    /// given above.
+    pub fn sd(&self) -> f64 {
+        unsafe { ffi::gsl_rstat_sd(self.w) }
+    }
+
+    /// This function returns the standard deviation of the mean, defined as
+    ///
+    /// sd_mean = \Hat\sigma / \sqrt{N}
+    pub fn sd_mean(&self) -> f64 {
+        unsafe { ffi::gsl_rstat_sd_mean(self.w) }
+    }
+
+    /// This function returns the root mean square of all data added to the accumulator, defined as
+    ///
+    /// rms = \sqrt{(1/N) \sum x_i^2}
+    pub fn rms(&self) -> f64 {
+        unsafe { ffi::gsl_rstat_rms(self.w) }
+    }
+
+    /// This function returns the skewness of all data added to the accumulator, defined as
+    ///
+    /// skew = (1/N) \sum ((x_i - \Hat\mu)/\Hat\sigma)^3
+    pub fn skew(&self) -> f64 {
+        unsafe { ffi::gsl_rstat_skew(self.w) }
+    }
+
+    /// This function returns the kurtosis of all data added to the accumulator, defined as
+    ///
+    /// kurtosis = ((1/N) \sum ((x_i - \Hat\mu)/\Hat\sigma)^4)  - 3
+    pub fn kurtosis(&self) -> f64 {
+        unsafe { ffi::gsl_rstat_kurtosis(self.w) }
+    }
+
+    /// This function returns an estimate of the median of the data added to the accumulator.
+    pub fn median(&mut self) -> f64 {
+        unsafe { ffi::gsl_rstat_median(self.w) }
+    }
+
+    /// This function resets the accumulator to its initial state.
+    pub fn reset(&mut self) -> GSLResult<()> {
+        GSLResult::from(unsafe { ffi::gsl_rstat_reset(self.w) })
+    }
+
+    /// This function combines the data accumulated in other into this accumulator, as if all the data points added to other had been
+    /// added to self. The count, minimum, maximum, mean, variance, skewness and kurtosis are combined exactly. The median estimate
+    /// relies on the P^2 markers of each accumulator, which cannot be combined: if self is empty it takes the median estimate of other,
+    /// otherwise it keeps its own.
+    pub fn merge(&mut self, other: &RStatWorkspace) {
+        let a = unsafe { &mut *self.w };
+        let b = unsafe { &*other.w };
+
+        if b.n == 0 {
+            return;
+        }
+        if a.n == 0 {
+            a.min = b.min;
+            a.max = b.max;
+            a.mean = b.mean;
+            a.M2 = b.M2;
+            a.M3 = b.M3;
+            a.M4 = b.M4;
+            a.n = b.n;
+            unsafe {
+                ::std::ptr::copy_nonoverlapping(b.median_workspace_p, a.median_workspace_p, 1);
+            }
+            return;
+        }
+
+        let na = a.n as f64;
+        let nb = b.n as f64;
+        let n = na + nb;
+        let delta = b.mean - a.mean;
+        let delta2 = delta * delta;
+
+        let m2 = a.M2 + b.M2 + delta2 * na * nb / n;
+        let m3 = a.M3 + b.M3 + delta2 * delta * na * nb * (na - nb) / (n * n) + 3f64 * delta * (na * b.M2 - nb * a.M2) / n;
+        let m4 = a.M4 + b.M4 + delta2 * delta2 * na * nb * (na * na - na * nb + nb * nb) / (n * n * n)
+            + 6f64 * delta2 * (na * na * b.M2 + nb * nb * a.M2) / (n * n) + 4f64 * delta * (na * b.M3 - nb * a.M3) / n;
+
+        a.mean += delta * nb / n;
+        a.M2 = m2;
+        a.M3 = m3;
+        a.M4 = m4;
+        a.n += b.n;
+        if b.min < a.min {
+            a.min = b.min;
+        }
+        if b.max > a.max {
+            a.max = b.max;
+        }
+    }
+}
+
+impl Drop for RStatWorkspace {
+    fn drop(&mut self) {
+        unsafe { ffi::gsl_rstat_free(self.w) };
+        self.w = ::std::ptr::null_mut();
+    }
+}
+
+impl ffi::FFI<ffi::gsl_rstat_workspace> for RStatWorkspace {
+    fn wrap(w: *mut ffi::gsl_rstat_workspace) -> RStatWorkspace {
+        RStatWorkspace {
+            w: w
+        }
+    }
+
+    fn unwrap(w: &RStatWorkspace) -> *mut ffi::gsl_rstat_workspace {
+        w.w
+    }
+}
+
+pub struct RStatQuantile {
+    w: *mut ffi::gsl_rstat_quantile_workspace
+}
+
+impl RStatQuantile {
+    /// This function allocates a workspace for the dynamic estimation of p-quantiles, where p is between 0 and 1. The median
+    /// corresponds to p = 0.5. The size of the workspace is O(1).
+    pub fn new(p: f64) -> Option<RStatQuantile> {
+        let tmp = unsafe { ffi::gsl_rstat_quantile_alloc(p) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(RStatQuantile {
+                w: tmp
+            })
+        }
+    }
+
+    /// This function resets the workspace to its initial state, so it can begin working on a new set of data.
+    pub fn reset(&mut self) -> GSLResult<()> {
+        GSLResult::from(unsafe { ffi::gsl_rstat_quantile_reset(self.w) })
+    }
+
+    /// This function updates the estimate of the p-quantile with the new data point x.
+    pub fn add(&mut self, x: f64) -> GSLResult<()> {
+        GSLResult::from(unsafe { ffi::gsl_rstat_quantile_add(x, self.w) })
+    }
+
+    /// This function returns the current estimate of the p-quantile.
+    pub fn get(&mut self) -> f64 {
+        unsafe { ffi::gsl_rstat_quantile_get(self.w) }
+    }
+}
+
+impl Drop for RStatQuantile {
+    fn drop(&mut self) {
+        unsafe { ffi::gsl_rstat_quantile_free(self.w) };
+        self.w = ::std::ptr::null_mut();
+    }
+}
+
+impl ffi::FFI<ffi::gsl_rstat_quantile_workspace> for RStatQuantile {
+    fn wrap(w: *mut ffi::gsl_rstat_quantile_workspace) -> RStatQuantile {
+        RStatQuantile {
+            w: w
+        }
+    }
+
+    fn unwrap(w: &RStatQuantile) -> *mut ffi::gsl_rstat_quantile_workspace {
+        w.w
+    }
+}
@@ -0,0 +1,351 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+/*!
+#Sparse Matrices
+
+This chapter describes functions for the construction and manipulation of sparse matrices, matrices which are populated primarily with
+zeros and contain only a few non-zero elements. Sparse matrices often appear in the solution of partial differential equations. It is
+beneficial to use specialized data structures and algorithms for storing and working with sparse matrices, since dense matrix algorithms
+and structures can be very slow and use huge amounts of memory when applied to sparse matrices.
+
+##Data types
+
+The sparse matrix is stored in one of the following formats, described by `SpMatrixType`:
+
+ * Triplet: each non-zero element is stored with its row index, column index and value. This format is used to assemble the matrix,
+   elements being added with `set`.
+ * Compressed column storage (CCS): the row indices and values are stored column by column, the start of each column being given by an
+   array of column pointers.
+ * Compressed row storage (CRS): the column indices and values are stored row by row, the start of each row being given by an array of
+   row pointers.
+
+Most of the arithmetic routines (transposition, scaling, addition, products) require the compressed formats. A matrix is first assembled
+in triplet format, then compressed with `ccs` or `crs`.
+
+##Iterating over non-zero elements
+
+The stored elements of a matrix in any of the formats can be visited with `iter`, which yields the (i, j, value) triplets in storage
+order. This gives direct access to the sparse structure for building custom kernels.
+!*/
+
+use std::fmt;
+use std::fmt::{Formatter, Debug};
+use ffi;
+use enums;
+use enums::GSLResult;
+
+pub struct SpMatrixF64 {
+    mat: *mut ffi::gsl_spmatrix,
+    can_free: bool
+}
+
+impl SpMatrixF64 {
+    /// This function allocates a sparse matrix of size n1-by-n2 in triplet format, with a default initial capacity for non-zero
+    /// elements. The capacity grows automatically as elements are added.
+    pub fn new(n1: usize, n2: usize) -> Option<SpMatrixF64> {
+        let tmp = unsafe { ffi::gsl_spmatrix_alloc(n1, n2) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(SpMatrixF64 {
+                mat: tmp,
+                can_free: true
+            })
+        }
+    }
+
+    /// This function allocates a sparse matrix of size n1-by-n2 and initializes it to all zeros. If the size of the matrix is not known
+    /// at allocation time, both n1 and n2 may be set to 1, and they will automatically grow as elements are added to the matrix. The
+    /// parameter nzmax specifies the maximum number of non-zero elements which will be added to the matrix. It does not need to be
+    /// precisely known in advance, since storage space will automatically grow as elements are added. The parameter sptype specifies the
+    /// storage format of the sparse matrix.
+    pub fn with_nzmax(n1: usize, n2: usize, nzmax: usize, sptype: enums::SpMatrixType) -> Option<SpMatrixF64> {
+        let tmp = unsafe { ffi::gsl_spmatrix_alloc_nzmax(n1, n2, nzmax, sptype) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(SpMatrixF64 {
+                mat: tmp,
+                can_free: true
+            })
+        }
+    }
+
+    /// This function converts the dense matrix dense into sparse triplet format. Only the non-zero elements of dense are stored.
+    pub fn from_dense(dense: &::MatrixF64) -> Option<SpMatrixF64> {
+        match SpMatrixF64::new(dense.size1(), dense.size2()) {
+            Some(m) => {
+                if unsafe { ffi::gsl_spmatrix_d2sp(m.mat, ffi::FFI::unwrap(dense)) } == enums::Value::Success {
+                    Some(m)
+                } else {
+                    None
+                }
+            }
+            None => None
+        }
+    }
+
+    /// This function converts the sparse matrix into the dense matrix dense, which must have the same dimensions.
+    pub fn to_dense(&self, dense: &mut ::MatrixF64) -> enums::Value {
+        unsafe { ffi::gsl_spmatrix_sp2d(ffi::FFI::unwrap(dense), self.mat) }
+    }
+
+    /// This function returns the (i,j)-th element of the matrix. If i or j lie outside the allowed range of 0 to n1-1 and 0 to n2-1
+    /// then the error handler is invoked and 0 is returned. Elements which are not stored are returned as 0.
+    pub fn get(&self, i: usize, j: usize) -> f64 {
+        unsafe { ffi::gsl_spmatrix_get(self.mat, i, j) }
+    }
+
+    /// This function sets element (i,j) of the matrix to the value x. The matrix must be in triplet representation.
+    pub fn set(&mut self, i: usize, j: usize, x: f64) -> enums::Value {
+        unsafe { ffi::gsl_spmatrix_set(self.mat, i, j, x) }
+    }
+
+    /// This function sets (or resets) all the elements of the matrix to zero. For the triplet format, all stored elements are removed.
+    pub fn set_zero(&mut self) -> enums::Value {
+        unsafe { ffi::gsl_spmatrix_set_zero(self.mat) }
+    }
+
+    /// This function copies the elements of the other matrix into the self matrix. The two matrices must have the same dimensions and be
+    /// in the same storage format.
+    pub fn copy_from(&mut self, other: &SpMatrixF64) -> enums::Value {
+        unsafe { ffi::gsl_spmatrix_memcpy(self.mat, other.mat) }
+    }
+
+    /// This function copies the elements of the self matrix into the other matrix. The two matrices must have the same dimensions and be
+    /// in the same storage format.
+    pub fn copy_to(&self, other: &mut SpMatrixF64) -> enums::Value {
+        unsafe { ffi::gsl_spmatrix_memcpy(other.mat, self.mat) }
+    }
+
+    /// This function returns a new matrix containing the transpose of the matrix, in the same storage format.
+    pub fn transpose_memcpy(&self) -> Option<(SpMatrixF64, enums::Value)> {
+        let dest = unsafe { ffi::gsl_spmatrix_alloc_nzmax((*self.mat).size2, (*self.mat).size1, (*self.mat).nz, self.sptype()) };
+
+        if dest.is_null() {
+            None
+        } else {
+            let ret = unsafe { ffi::gsl_spmatrix_transpose_memcpy(dest, self.mat) };
+
+            Some((SpMatrixF64 {mat: dest, can_free: true}, ret))
+        }
+    }
+
+    /// This function replaces the matrix by its transpose, preserving the storage format of the input matrix. The matrix does not need to
+    /// be square.
+    pub fn transpose(&mut self) -> enums::Value {
+        unsafe { ffi::gsl_spmatrix_transpose(self.mat) }
+    }
+
+    /// This function scales all elements of the matrix by the constant factor x.
+    pub fn scale(&mut self, x: f64) -> enums::Value {
+        unsafe { ffi::gsl_spmatrix_scale(self.mat, x) }
+    }
+
+    /// This function computes the sum self = a + b. The three matrices must have the same dimensions and be in a compressed format.
+    pub fn add(&mut self, a: &SpMatrixF64, b: &SpMatrixF64) -> enums::Value {
+        unsafe { ffi::gsl_spmatrix_add(self.mat, a.mat, b.mat) }
+    }
+
+    /// This function returns the number of non-zero elements stored in the matrix.
+    pub fn nnz(&self) -> usize {
+        unsafe { ffi::gsl_spmatrix_nnz(self.mat) }
+    }
+
+    /// This function returns the storage format of the matrix.
+    pub fn sptype(&self) -> enums::SpMatrixType {
+        match unsafe { (*self.mat).sptype } {
+            1 => enums::SpMatrixType::CCS,
+            2 => enums::SpMatrixType::CRS,
+            _ => enums::SpMatrixType::Triplet
+        }
+    }
+
+    /// This function returns the maximum value of the stored elements of the matrix, or the error returned by `minmax`.
+    pub fn max(&self) -> GSLResult<f64> {
+        let mut min = 0f64;
+        let mut max = 0f64;
+
+        GSLResult::from(self.minmax(&mut min, &mut max))?;
+        Ok(max)
+    }
+
+    /// This function returns the minimum value of the stored elements of the matrix, or the error returned by `minmax`.
+    pub fn min(&self) -> GSLResult<f64> {
+        let mut min = 0f64;
+        let mut max = 0f64;
+
+        GSLResult::from(self.minmax(&mut min, &mut max))?;
+        Ok(min)
+    }
+
+    /// This function returns the minimum and maximum values of the stored elements of the matrix, storing them in min_out and max_out.
+    pub fn minmax(&self, min_out: &mut f64, max_out: &mut f64) -> enums::Value {
+        unsafe { ffi::gsl_spmatrix_minmax(self.mat, min_out, max_out) }
+    }
+
+    /// This function returns true if the matrices a and b are equal, by comparing their dimensions and elements. The two matrices must
+    /// have the same storage format.
+    pub fn equal(&self, other: &SpMatrixF64) -> bool {
+        match unsafe { ffi::gsl_spmatrix_equal(self.mat, other.mat) } {
+            1 => true,
+            _ => false
+        }
+    }
+
+    /// This function creates a new sparse matrix in compressed column format from the input triplet matrix.
+    pub fn ccs(&self) -> Option<SpMatrixF64> {
+        let tmp = unsafe { ffi::gsl_spmatrix_ccs(self.mat) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(SpMatrixF64 {
+                mat: tmp,
+                can_free: true
+            })
+        }
+    }
+
+    /// This function creates a new sparse matrix in compressed row format from the input triplet matrix.
+    pub fn crs(&self) -> Option<SpMatrixF64> {
+        let tmp = unsafe { ffi::gsl_spmatrix_crs(self.mat) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(SpMatrixF64 {
+                mat: tmp,
+                can_free: true
+            })
+        }
+    }
+
+    /// Returns an iterator over the stored elements of the matrix, yielding (i, j, value) triplets in storage order.
+    pub fn iter<'a>(&'a self) -> SpMatrixIter<'a> {
+        SpMatrixIter {
+            mat: self,
+            k: 0,
+            outer: 0
+        }
+    }
+
+    pub fn size1(&self) -> usize {
+        if self.mat.is_null() {
+            0usize
+        } else {
+            unsafe { (*self.mat).size1 }
+        }
+    }
+
+    pub fn size2(&self) -> usize {
+        if self.mat.is_null() {
+            0usize
+        } else {
+            unsafe { (*self.mat).size2 }
+        }
+    }
+
+    pub fn clone(&self) -> Option<SpMatrixF64> {
+        unsafe {
+            if self.mat.is_null() {
+                None
+            } else {
+                let tmp = ffi::gsl_spmatrix_alloc_nzmax((*self.mat).size1, (*self.mat).size2, (*self.mat).nz, self.sptype());
+
+                if tmp.is_null() {
+                    None
+                } else {
+                    let mut m = SpMatrixF64 {
+                        mat: tmp,
+                        can_free: true
+                    };
+
+                    m.copy_from(self);
+                    Some(m)
+                }
+            }
+        }
+    }
+}
+
+impl Drop for SpMatrixF64 {
+    fn drop(&mut self) {
+        if self.can_free {
+            unsafe { ffi::gsl_spmatrix_free(self.mat) };
+            self.mat = ::std::ptr::null_mut();
+        }
+    }
+}
+
+impl Debug for SpMatrixF64 {
+    #[allow(unused_must_use)]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "[");
+        for (n, (i, j, x)) in self.iter().enumerate() {
+            if n > 0 {
+                write!(f, ", ");
+            }
+            write!(f, "({}, {}): {}", i, j, x);
+        }
+        write!(f, "]")
+    }
+}
+
+impl ffi::FFI<ffi::gsl_spmatrix> for SpMatrixF64 {
+    fn wrap(r: *mut ffi::gsl_spmatrix) -> SpMatrixF64 {
+        SpMatrixF64 {
+            mat: r,
+            can_free: true
+        }
+    }
+
+    fn unwrap(m: &SpMatrixF64) -> *mut ffi::gsl_spmatrix {
+        m.mat
+    }
+}
+
+/// Iterator over the stored elements of a `SpMatrixF64`, created by `SpMatrixF64::iter`.
+pub struct SpMatrixIter<'a> {
+    mat: &'a SpMatrixF64,
+    k: usize,
+    outer: usize
+}
+
+impl<'a> Iterator for SpMatrixIter<'a> {
+    type Item = (usize, usize, f64);
+
+    fn next(&mut self) -> Option<(usize, usize, f64)> {
+        unsafe {
+            let m = &*self.mat.mat;
+
+            if self.k >= m.nz {
+                return None;
+            }
+
+            let inner = *m.i.offset(self.k as isize) as usize;
+            let x = *m.data.offset(self.k as isize);
+            let ret = match self.mat.sptype() {
+                enums::SpMatrixType::Triplet => (inner, *m.p.offset(self.k as isize) as usize, x),
+                sptype => {
+                    /* p holds the start of each column (CCS) or row (CRS) */
+                    while *m.p.offset(self.outer as isize + 1) as usize <= self.k {
+                        self.outer += 1;
+                    }
+                    if sptype == enums::SpMatrixType::CCS {
+                        (inner, self.outer, x)
+                    } else {
+                        (self.outer, inner, x)
+                    }
+                }
+            };
+
+            self.k += 1;
+            Some(ret)
+        }
+    }
+}
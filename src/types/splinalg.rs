@@ -0,0 +1,145 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+/*!
+#Sparse Linear Algebra
+
+This chapter describes functions for solving sparse linear systems. The library provides linear algebra routines which operate directly
+on the SpMatrixF64 and VectorF64 objects.
+
+##Overview
+
+This chapter is primarily concerned with the solution of the linear system
+
+A x = b
+
+where A is a general square n-by-n non-singular sparse matrix, x is an unknown n-by-1 vector, and b is a given n-by-1 right hand side
+vector. There exist many methods for solving such sparse linear systems, which broadly fall into either direct or iterative categories.
+Direct methods include LU and QR decompositions, while iterative methods start with an initial guess for the vector x and update the
+guess through iteration until convergence. GSL does not currently provide any direct sparse solvers.
+
+##Sparse Iterative Solvers
+
+Many practical iterative methods of solving large n-by-n sparse linear systems involve projecting an approximate solution for x onto a
+subspace of R^n. If we define a m-dimensional subspace \cal K as the subspace of approximations to the solution x, then m constraints must
+be imposed to determine the next approximation. These m constraints define another m-dimensional subspace denoted by \cal L. The subspace
+dimensions m is typically chosen to be much smaller than n in order to reduce the computational effort needed to generate the next
+approximate solution vector. The many iterative algorithms which exist differ mainly in their choice of \cal K and \cal L.
+!*/
+
+use ffi;
+use enums::GSLResult;
+
+pub struct SpLinalgIterSolver {
+    w: *mut ffi::gsl_splinalg_itersolve
+}
+
+impl SpLinalgIterSolver {
+    /// This function allocates a workspace for the iterative solution of n-by-n sparse matrix systems. The iterative solver type is
+    /// specified by t. The argument m specifies the size of the solution candidate subspace \cal K_m. The dimension m may be set to 0
+    /// in which case a reasonable default value is chosen.
+    ///
+    /// For GMRES, m is the restart parameter: the Krylov subspace is rebuilt from the current solution every m inner iterations. Larger
+    /// values converge in fewer iterations at the cost of more memory and work per iteration.
+    pub fn new(t: &SpLinalgIterSolverType, n: usize, m: usize) -> Option<SpLinalgIterSolver> {
+        let tmp = unsafe { ffi::gsl_splinalg_itersolve_alloc(t.t, n, m) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(SpLinalgIterSolver {
+                w: tmp
+            })
+        }
+    }
+
+    /// This function returns the name of the linear solver.
+    pub fn name(&self) -> Option<String> {
+        let tmp = unsafe { ffi::gsl_splinalg_itersolve_name(self.w) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            unsafe { Some(String::from_utf8_lossy(::std::ffi::CStr::from_ptr(tmp).to_bytes()).to_string()) }
+        }
+    }
+
+    /// This function performs one iteration of the iterative method for the sparse linear system specified by the matrix A, right hand
+    /// side vector b and solution vector x. On input, x must be set to an initial guess for the solution. On output, x is updated to give
+    /// the current solution estimate. The parameter tol specifies the relative tolerance between the residual norm and norm of b in order
+    /// to check for convergence. When the following condition is satisfied:
+    ///
+    /// || A x - b || <= tol || b ||
+    ///
+    /// the method has converged, the function returns Ok(()) and the final solution is provided in x. Otherwise, the function returns
+    /// Err(::Value::Continue) to signal that more iterations are required. Here, || \cdot || represents the Euclidean norm. The input
+    /// matrix A may be in triplet or compressed format.
+    pub fn iterate(&mut self, A: &::SpMatrixF64, b: &::VectorF64, tol: f64, x: &mut ::VectorF64) -> GSLResult<()> {
+        GSLResult::from(unsafe {
+            ffi::gsl_splinalg_itersolve_iterate(ffi::FFI::unwrap(A), ffi::FFI::unwrap(b), tol, ffi::FFI::unwrap(x), self.w)
+        })
+    }
+
+    /// This function returns the current residual norm ||r|| = ||A x - b||, which is updated after each call to `iterate`.
+    pub fn normr(&self) -> f64 {
+        unsafe { ffi::gsl_splinalg_itersolve_normr(self.w) }
+    }
+}
+
+impl Drop for SpLinalgIterSolver {
+    fn drop(&mut self) {
+        unsafe { ffi::gsl_splinalg_itersolve_free(self.w) };
+        self.w = ::std::ptr::null_mut();
+    }
+}
+
+impl ffi::FFI<ffi::gsl_splinalg_itersolve> for SpLinalgIterSolver {
+    fn wrap(w: *mut ffi::gsl_splinalg_itersolve) -> SpLinalgIterSolver {
+        SpLinalgIterSolver {
+            w: w
+        }
+    }
+
+    fn unwrap(w: &SpLinalgIterSolver) -> *mut ffi::gsl_splinalg_itersolve {
+        w.w
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct SpLinalgIterSolverType {
+    t: *const ffi::gsl_splinalg_itersolve_type
+}
+
+impl SpLinalgIterSolverType {
+    /// This specifies the Generalized Minimum Residual Method (GMRES). This is a projection method using \cal K = \cal K_m and
+    /// \cal L = A \cal K_m where \cal K_m is the m-th Krylov subspace
+    ///
+    /// K_m = span( r_0, A r_0, ..., A^{m-1} r_0)
+    ///
+    /// and r_0 = b - A x_0 is the residual vector of the initial guess x_0. If m is set equal to n, then the Krylov subspace is R^n and
+    /// GMRES will provide the exact solution x. However, the goal is for the method to arrive at a very good approximation to x using a
+    /// much smaller subspace \cal K_m. By default, the GMRES method selects m = MIN(n,10) but the user may specify a different value
+    /// for m.
+    ///
+    /// The GMRES algorithm maintains two separate m-by-m matrices and m-by-1 vectors, so the memory requirements are O(m^2) in addition
+    /// to the storage for the sparse matrix. This method is restarted every m iterations (with the current solution as the new initial
+    /// guess) until convergence is reached.
+    pub fn gmres() -> SpLinalgIterSolverType {
+        SpLinalgIterSolverType {
+            t: unsafe { ffi::gsl_splinalg_itersolve_gmres }
+        }
+    }
+}
+
+impl ffi::FFI<ffi::gsl_splinalg_itersolve_type> for SpLinalgIterSolverType {
+    fn wrap(t: *mut ffi::gsl_splinalg_itersolve_type) -> SpLinalgIterSolverType {
+        SpLinalgIterSolverType {
+            t: t
+        }
+    }
+
+    fn unwrap(t: &SpLinalgIterSolverType) -> *mut ffi::gsl_splinalg_itersolve_type {
+        t.t as *mut ffi::gsl_splinalg_itersolve_type
+    }
+}
@@ -28,18 +28,160 @@ element of the vector in memory. The pointer block stores the location of the me
 any). If the vector owns this block then the owner field is set to one and the block will be deallocated when the vector is freed. If the 
 vector points to a block owned by another object then the owner field is zero and any underlying block will not be deallocated with the 
 vector.
+
+##Vector views
+
+A vector view is a temporary object which can be used to operate on a subset of vector elements, or on an array of elements. A
+VectorView borrows the vector or the array it was created from, so it cannot outlive it, and only gives read access to the elements
+through `vector`, which can be passed to any function taking a vector. A VectorViewMut borrows mutably the vector or the array it was
+created from, and its elements can be modified with the functions of the same name as on vectors (`set`, `set_all`, `scale`, indexing,
+`iter_mut`...). A mutable reference to the underlying vector is never given, as swapping it with an owned vector would free the elements
+of the view.
+
+The elements of a vector with unit stride are stored contiguously in memory, and can be read and written directly as a Rust slice
+with `as_slice` and `as_mut_slice`, without going through `get` and `set` for each element.
 !*/
 
 use std::fmt;
 use std::fmt::{Formatter, Debug};
+use std::marker::PhantomData;
 use ffi;
 use enums;
+use std::ops::{Index, IndexMut};
+use types::VectorIterMut;
+
+// The elements of a mutable view are modified through these functions, which forward to the vector held by the view, instead of through
+// a &mut to that vector: it could be swapped with an owned vector, which would then be freed when the view is dropped.
+macro_rules! impl_vector_view_mut {
+    ($view:ident, $vector:ident, $elem:ty) => (
+        impl<'a> $view<'a> {
+            /// This function sets the value of the i-th element of the view to x. If i lies outside the allowed range of 0 to n-1 then
+            /// the error handler is invoked.
+            pub fn set(&mut self, i: usize, x: $elem) -> &mut $view<'a> {
+                self.vec.set(i, x);
+                self
+            }
+
+            /// This function sets all the elements of the view to the value x.
+            pub fn set_all(&mut self, x: $elem) -> &mut $view<'a> {
+                self.vec.set_all(x);
+                self
+            }
+
+            /// This function sets all the elements of the view to zero.
+            pub fn set_zero(&mut self) -> &mut $view<'a> {
+                self.vec.set_zero();
+                self
+            }
+
+            /// This function sets all the elements of the view to zero except for the i-th element which is set to one.
+            pub fn set_basis(&mut self, i: usize) -> &mut $view<'a> {
+                self.vec.set_basis(i);
+                self
+            }
+
+            /// This function copies the elements of the other vector into the view. The two must have the same length.
+            pub fn copy_from(&mut self, other: &$vector) -> enums::Value {
+                self.vec.copy_from(other)
+            }
+
+            /// This function exchanges the elements of the view and of the other vector by copying. The two must have the same length.
+            pub fn swap(&mut self, other: &mut $vector) -> enums::Value {
+                self.vec.swap(other)
+            }
+
+            /// This function exchanges the i-th and j-th elements of the view in-place.
+            pub fn swap_elements(&mut self, i: usize, j: usize) -> enums::Value {
+                self.vec.swap_elements(i, j)
+            }
+
+            /// This function reverses the order of the elements of the view.
+            pub fn reverse(&mut self) -> enums::Value {
+                self.vec.reverse()
+            }
+
+            /// This function adds the elements of the other vector to the elements of the view. The two must have the same length.
+            pub fn add(&mut self, other: &$vector) -> enums::Value {
+                $vector::add(&mut self.vec, other)
+            }
+
+            /// This function subtracts the elements of the other vector from the elements of the view. The two must have the same
+            /// length.
+            pub fn sub(&mut self, other: &$vector) -> enums::Value {
+                $vector::sub(&mut self.vec, other)
+            }
+
+            /// This function multiplies the elements of the view by the elements of the other vector. The two must have the same length.
+            pub fn mul(&mut self, other: &$vector) -> enums::Value {
+                $vector::mul(&mut self.vec, other)
+            }
 
-pub struct VectorView {
-    v: ffi::gsl_vector_view
+            /// This function divides the elements of the view by the elements of the other vector. The two must have the same length.
+            pub fn div(&mut self, other: &$vector) -> enums::Value {
+                $vector::div(&mut self.vec, other)
+            }
+
+            /// This function multiplies the elements of the view by the constant factor x.
+            pub fn scale(&mut self, x: $elem) -> enums::Value {
+                self.vec.scale(x)
+            }
+
+            /// This function adds the constant value x to the elements of the view.
+            pub fn add_constant(&mut self, x: $elem) -> enums::Value {
+                self.vec.add_constant(x)
+            }
+
+            /// Returns the elements of the view as a mutable slice, or None if its stride is not 1.
+            pub fn as_mut_slice(&mut self) -> Option<&mut [$elem]> {
+                self.vec.as_mut_slice()
+            }
+
+            /// Returns an iterator over the elements of the view, allowing to modify them.
+            pub fn iter_mut<'b>(&'b mut self) -> VectorIterMut<'b, $elem> {
+                self.vec.iter_mut()
+            }
+        }
+
+        impl<'a> Index<usize> for $view<'a> {
+            type Output = $elem;
+
+            fn index(&self, i: usize) -> &$elem {
+                &self.vec[i]
+            }
+        }
+
+        impl<'a> IndexMut<usize> for $view<'a> {
+            fn index_mut(&mut self, i: usize) -> &mut $elem {
+                &mut self.vec[i]
+            }
+        }
+    )
+}
+
+/// A read-only view of the elements of a vector or of an array. It borrows the vector or the array it was created from, so it cannot be
+/// modified while the view exists.
+pub struct VectorView<'a> {
+    // boxed so that the address given to vec stays valid when the view is moved
+    _v: Box<ffi::gsl_vector_view>,
+    vec: VectorF64,
+    phantom: PhantomData<&'a ()>
 }
 
-impl VectorView {
+impl<'a> VectorView<'a> {
+    pub(crate) fn new(v: ffi::gsl_vector_view) -> VectorView<'a> {
+        let mut v = Box::new(v);
+        let vec = VectorF64 {
+            vec: &mut v.vector,
+            can_free: false
+        };
+
+        VectorView {
+            _v: v,
+            vec: vec,
+            phantom: PhantomData
+        }
+    }
+
     /// These functions return a vector view of a subvector of another vector v. The start of the new vector is offset by offset elements
     /// from the start of the original vector. The new vector has n elements. Mathematically, the i-th element of the new vector v’ is given by,
     /// 
@@ -56,12 +198,8 @@ impl VectorView {
     /// in use.
     /// 
     /// The function gsl_vector_const_subvector is equivalent to gsl_vector_subvector but can be used for vectors which are declared const.
-    pub fn from_vector(v: &VectorF64, offset: usize, n: usize) -> VectorView {
-        unsafe {
-            VectorView {
-                v: ffi::gsl_vector_subvector(v.vec, offset, n)
-            }
-        }
+    pub fn from_vector(v: &'a VectorF64, offset: usize, n: usize) -> VectorView<'a> {
+        VectorView::new(unsafe { ffi::gsl_vector_subvector(v.vec, offset, n) })
     }
 
     /// These functions return a vector view of a subvector of another vector v with an additional stride argument. The subvector is formed
@@ -89,12 +227,66 @@ impl VectorView {
     /// ```
     /// The function gsl_vector_const_subvector_with_stride is equivalent to gsl_vector_subvector_with_stride but can be used for vectors which
     /// are declared const.
-    pub fn from_vector_with_stride(v: &VectorF64, offset: usize, stride: usize, n: usize) -> VectorView {
-        unsafe {
-            VectorView {
-                v: ffi::gsl_vector_subvector_with_stride(v.vec, offset, stride, n)
-            }
+    pub fn from_vector_with_stride(v: &'a VectorF64, offset: usize, stride: usize, n: usize) -> VectorView<'a> {
+        VectorView::new(unsafe { ffi::gsl_vector_subvector_with_stride(v.vec, offset, stride, n) })
+    }
+
+    /// Returns a read-only vector view of the array base, like `VectorViewMut::from_array`. The array is borrowed mutably all the same,
+    /// so that it cannot be modified while the view exists.
+    pub fn from_array(base: &'a mut [f64]) -> VectorView<'a> {
+        VectorView::new(unsafe { ffi::gsl_vector_view_array(base.as_mut_ptr(), base.len() as usize) })
+    }
+
+    /// Returns a read-only vector view of the array base with a step-size of stride, like `VectorViewMut::from_array_with_stride`. None
+    /// is returned if stride is 0.
+    pub fn from_array_with_stride(base: &'a mut [f64], stride: usize) -> Option<VectorView<'a>> {
+        if stride == 0 {
+            return None;
         }
+        let n = (base.len() + stride - 1) / stride;
+
+        Some(VectorView::new(unsafe { ffi::gsl_vector_view_array_with_stride(base.as_mut_ptr(), stride, n) }))
+    }
+
+    /// Returns the view as a vector, which can be passed to any function taking a vector. The vector cannot outlive the view.
+    pub fn vector(&self) -> &VectorF64 {
+        &self.vec
+    }
+}
+
+/// A view through which the elements of a vector or of an array can be modified. It borrows mutably the vector or the array it was
+/// created from.
+pub struct VectorViewMut<'a> {
+    // boxed so that the address given to vec stays valid when the view is moved
+    _v: Box<ffi::gsl_vector_view>,
+    vec: VectorF64,
+    phantom: PhantomData<&'a mut ()>
+}
+
+impl<'a> VectorViewMut<'a> {
+    pub(crate) fn new(v: ffi::gsl_vector_view) -> VectorViewMut<'a> {
+        let mut v = Box::new(v);
+        let vec = VectorF64 {
+            vec: &mut v.vector,
+            can_free: false
+        };
+
+        VectorViewMut {
+            _v: v,
+            vec: vec,
+            phantom: PhantomData
+        }
+    }
+
+    /// Returns a mutable vector view of n elements of the vector v, starting at offset, like `VectorView::from_vector`.
+    pub fn from_vector(v: &'a mut VectorF64, offset: usize, n: usize) -> VectorViewMut<'a> {
+        VectorViewMut::new(unsafe { ffi::gsl_vector_subvector(v.vec, offset, n) })
+    }
+
+    /// Returns a mutable vector view of n elements of the vector v, starting at offset with a step-size of stride, like
+    /// `VectorView::from_vector_with_stride`.
+    pub fn from_vector_with_stride(v: &'a mut VectorF64, offset: usize, stride: usize, n: usize) -> VectorViewMut<'a> {
+        VectorViewMut::new(unsafe { ffi::gsl_vector_subvector_with_stride(v.vec, offset, stride, n) })
     }
 
     /// These functions return a vector view of an array. The start of the new vector is given by base and has n elements. Mathematically,
@@ -109,12 +301,8 @@ impl VectorView {
     /// should not be deallocated while the view is still in use.
     /// 
     /// The function gsl_vector_const_view_array is equivalent to gsl_vector_view_array but can be used for arrays which are declared const.
-    pub fn from_array(base: &mut [f64]) -> VectorView {
-        unsafe {
-            VectorView {
-                v: ffi::gsl_vector_view_array(base.as_mut_ptr(), base.len() as usize)
-            }
-        }
+    pub fn from_array(base: &'a mut [f64]) -> VectorViewMut<'a> {
+        VectorViewMut::new(unsafe { ffi::gsl_vector_view_array(base.as_mut_ptr(), base.len() as usize) })
     }
 
     /// These functions return a vector view of an array base with an additional stride argument. The subvector is formed in the same way as
@@ -130,24 +318,138 @@ impl VectorView {
     /// 
     /// The function gsl_vector_const_view_array_with_stride is equivalent to gsl_vector_view_array_with_stride but can be used for arrays
     /// which are declared const.
-    pub fn from_array_with_stride(base: &mut [f64], stride: usize) -> VectorView {
-        unsafe {
-            VectorView {
-                v: ffi::gsl_vector_view_array_with_stride(base.as_mut_ptr(), stride, base.len() as usize)
-            }
+    ///
+    /// The view has base.len() / stride elements, rounded up, so that it covers the whole array. None is returned if stride is 0.
+    pub fn from_array_with_stride(base: &'a mut [f64], stride: usize) -> Option<VectorViewMut<'a>> {
+        if stride == 0 {
+            return None;
         }
+        let n = (base.len() + stride - 1) / stride;
+
+        Some(VectorViewMut::new(unsafe { ffi::gsl_vector_view_array_with_stride(base.as_mut_ptr(), stride, n) }))
     }
 
-    pub fn vector(&mut self) -> VectorF64 {
-        unsafe {
-            VectorF64 {
-                vec: ::std::mem::transmute(&mut self.v),
-                can_free: false
-            }
+    /// Returns the view as a vector, which can be passed to any function taking a vector. The vector cannot outlive the view.
+    pub fn vector(&self) -> &VectorF64 {
+        &self.vec
+    }
+}
+
+/// A read-only view of the elements of a VectorF32, like `VectorView`.
+pub struct VectorViewF32<'a> {
+    // boxed so that the address given to vec stays valid when the view is moved
+    _v: Box<ffi::gsl_vector_float_view>,
+    vec: VectorF32,
+    phantom: PhantomData<&'a ()>
+}
+
+impl<'a> VectorViewF32<'a> {
+    fn new(v: ffi::gsl_vector_float_view) -> VectorViewF32<'a> {
+        let mut v = Box::new(v);
+        let vec = VectorF32 {
+            vec: &mut v.vector,
+            can_free: false
+        };
+
+        VectorViewF32 {
+            _v: v,
+            vec: vec,
+            phantom: PhantomData
         }
     }
+
+    /// Returns a vector view of n elements of the vector v, starting at offset, like `VectorView::from_vector`.
+    pub fn from_vector(v: &'a VectorF32, offset: usize, n: usize) -> VectorViewF32<'a> {
+        VectorViewF32::new(unsafe { ffi::gsl_vector_float_subvector(v.vec, offset, n) })
+    }
+
+    /// Returns a vector view of n elements of the vector v, starting at offset with a step-size of stride, like
+    /// `VectorView::from_vector_with_stride`.
+    pub fn from_vector_with_stride(v: &'a VectorF32, offset: usize, stride: usize, n: usize) -> VectorViewF32<'a> {
+        VectorViewF32::new(unsafe { ffi::gsl_vector_float_subvector_with_stride(v.vec, offset, stride, n) })
+    }
+
+    /// Returns a read-only vector view of the array base, like `VectorView::from_array`.
+    pub fn from_array(base: &'a mut [f32]) -> VectorViewF32<'a> {
+        VectorViewF32::new(unsafe { ffi::gsl_vector_float_view_array(base.as_mut_ptr(), base.len() as usize) })
+    }
+
+    /// Returns a read-only vector view of the array base with a step-size of stride, like `VectorView::from_array_with_stride`. None is
+    /// returned if stride is 0.
+    pub fn from_array_with_stride(base: &'a mut [f32], stride: usize) -> Option<VectorViewF32<'a>> {
+        if stride == 0 {
+            return None;
+        }
+        let n = (base.len() + stride - 1) / stride;
+
+        Some(VectorViewF32::new(unsafe { ffi::gsl_vector_float_view_array_with_stride(base.as_mut_ptr(), stride, n) }))
+    }
+
+    /// Returns the view as a vector, which can be passed to any function taking a vector. The vector cannot outlive the view.
+    pub fn vector(&self) -> &VectorF32 {
+        &self.vec
+    }
 }
 
+/// A view through which the elements of a VectorF32 or of an array can be modified, like `VectorViewMut`.
+pub struct VectorViewMutF32<'a> {
+    // boxed so that the address given to vec stays valid when the view is moved
+    _v: Box<ffi::gsl_vector_float_view>,
+    vec: VectorF32,
+    phantom: PhantomData<&'a mut ()>
+}
+
+impl<'a> VectorViewMutF32<'a> {
+    fn new(v: ffi::gsl_vector_float_view) -> VectorViewMutF32<'a> {
+        let mut v = Box::new(v);
+        let vec = VectorF32 {
+            vec: &mut v.vector,
+            can_free: false
+        };
+
+        VectorViewMutF32 {
+            _v: v,
+            vec: vec,
+            phantom: PhantomData
+        }
+    }
+
+    /// Returns a mutable vector view of n elements of the vector v, starting at offset, like `VectorView::from_vector`.
+    pub fn from_vector(v: &'a mut VectorF32, offset: usize, n: usize) -> VectorViewMutF32<'a> {
+        VectorViewMutF32::new(unsafe { ffi::gsl_vector_float_subvector(v.vec, offset, n) })
+    }
+
+    /// Returns a mutable vector view of n elements of the vector v, starting at offset with a step-size of stride, like
+    /// `VectorView::from_vector_with_stride`.
+    pub fn from_vector_with_stride(v: &'a mut VectorF32, offset: usize, stride: usize, n: usize) -> VectorViewMutF32<'a> {
+        VectorViewMutF32::new(unsafe { ffi::gsl_vector_float_subvector_with_stride(v.vec, offset, stride, n) })
+    }
+
+    /// Returns a vector view of the array base, like `VectorViewMut::from_array`.
+    pub fn from_array(base: &'a mut [f32]) -> VectorViewMutF32<'a> {
+        VectorViewMutF32::new(unsafe { ffi::gsl_vector_float_view_array(base.as_mut_ptr(), base.len() as usize) })
+    }
+
+    /// Returns a vector view of the array base with a step-size of stride, like `VectorViewMut::from_array_with_stride`. None is
+    /// returned if stride is 0.
+    pub fn from_array_with_stride(base: &'a mut [f32], stride: usize) -> Option<VectorViewMutF32<'a>> {
+        if stride == 0 {
+            return None;
+        }
+        let n = (base.len() + stride - 1) / stride;
+
+        Some(VectorViewMutF32::new(unsafe { ffi::gsl_vector_float_view_array_with_stride(base.as_mut_ptr(), stride, n) }))
+    }
+
+    /// Returns the view as a vector, which can be passed to any function taking a vector. The vector cannot outlive the view.
+    pub fn vector(&self) -> &VectorF32 {
+        &self.vec
+    }
+}
+
+impl_vector_view_mut!(VectorViewMut, VectorF64, f64);
+impl_vector_view_mut!(VectorViewMutF32, VectorF32, f32);
+
 pub struct VectorF32 {
     vec: *mut ffi::gsl_vector_float,
     can_free: bool
@@ -174,7 +476,7 @@ impl VectorF32 {
         if tmp.is_null() {
             None
         } else {
-            let v = VectorF32 {
+            let mut v = VectorF32 {
                 vec: tmp,
                 can_free: true
             };
@@ -202,85 +504,85 @@ impl VectorF32 {
     }
 
     /// This function sets the value of the i-th element of a vector v to x. If i lies outside the allowed range of 0 to n-1 then the error handler is invoked.
-    pub fn set(&self, i: usize, x: f32) -> &VectorF32 {
+    pub fn set(&mut self, i: usize, x: f32) -> &mut VectorF32 {
         unsafe { ffi::gsl_vector_float_set(self.vec, i, x) };
         self
     }
 
     /// This function sets all the elements of the vector v to the value x.
-    pub fn set_all(&self, x: f32) -> &VectorF32 {
+    pub fn set_all(&mut self, x: f32) -> &mut VectorF32 {
         unsafe { ffi::gsl_vector_float_set_all(self.vec, x) };
         self
     }
 
     /// This function sets all the elements of the vector v to zero.
-    pub fn set_zero(&self) -> &VectorF32 {
+    pub fn set_zero(&mut self) -> &mut VectorF32 {
         unsafe { ffi::gsl_vector_float_set_zero(self.vec) };
         self
     }
 
     /// This function makes a basis vector by setting all the elements of the vector v to zero except for the i-th element which is set to one.
-    pub fn set_basis(&self, i: usize) -> &VectorF32 {
+    pub fn set_basis(&mut self, i: usize) -> &mut VectorF32 {
         unsafe { ffi::gsl_vector_float_set_basis(self.vec, i) };
         self
     }
 
     /// This function copies the elements of the other vector into the self vector. The two vectors must have the same length.
-    pub fn copy_from(&self, other: &VectorF32) -> enums::Value {
+    pub fn copy_from(&mut self, other: &VectorF32) -> enums::Value {
         unsafe { ffi::gsl_vector_float_memcpy(self.vec, other.vec) }
     }
 
     /// This function copies the elements of the self vector into the other vector. The two vectors must have the same length.
-    pub fn copy_to(&self, other: &VectorF32) -> enums::Value {
+    pub fn copy_to(&self, other: &mut VectorF32) -> enums::Value {
         unsafe { ffi::gsl_vector_float_memcpy(other.vec, self.vec) }
     }
 
     /// This function exchanges the elements of the vectors by copying. The two vectors must have the same length.
-    pub fn swap(&self, other: &VectorF32) -> enums::Value {
+    pub fn swap(&mut self, other: &mut VectorF32) -> enums::Value {
         unsafe { ffi::gsl_vector_float_swap(other.vec, self.vec) }
     }
 
     /// This function exchanges the i-th and j-th elements of the vector v in-place.
-    pub fn swap_elements(&self, i: usize, j: usize) -> enums::Value {
+    pub fn swap_elements(&mut self, i: usize, j: usize) -> enums::Value {
         unsafe { ffi::gsl_vector_float_swap_elements(self.vec, i, j) }
     }
 
     /// This function reverses the order of the elements of the vector v.
-    pub fn reverse(&self) -> enums::Value {
+    pub fn reverse(&mut self) -> enums::Value {
         unsafe { ffi::gsl_vector_float_reverse(self.vec) }
     }
 
     /// This function adds the elements of the other vector to the elements of the self vector.
     /// The result a_i <- a_i + b_i is stored in self and other remains unchanged. The two vectors must have the same length.
-    pub fn add(&self, other: &VectorF32) -> enums::Value {
+    pub fn add(&mut self, other: &VectorF32) -> enums::Value {
         unsafe { ffi::gsl_vector_float_add(self.vec, other.vec) }
     }
 
     /// This function subtracts the elements of the self vector from the elements of the other vector.
     /// The result a_i <- a_i - b_i is stored in self and other remains unchanged. The two vectors must have the same length.
-    pub fn sub(&self, other: &VectorF32) -> enums::Value {
+    pub fn sub(&mut self, other: &VectorF32) -> enums::Value {
         unsafe { ffi::gsl_vector_float_sub(self.vec, other.vec) }
     }
 
     /// This function multiplies the elements of the self vector a by the elements of the other vector.
     /// The result a_i <- a_i * b_i is stored in self and other remains unchanged. The two vectors must have the same length.
-    pub fn mul(&self, other: &VectorF32) -> enums::Value {
+    pub fn mul(&mut self, other: &VectorF32) -> enums::Value {
         unsafe { ffi::gsl_vector_float_mul(self.vec, other.vec) }
     }
 
     /// This function divides the elements of the self vector by the elements of the other vector.
     /// The result a_i <- a_i / b_i is stored in self and other remains unchanged. The two vectors must have the same length.
-    pub fn div(&self, other: &VectorF32) -> enums::Value {
+    pub fn div(&mut self, other: &VectorF32) -> enums::Value {
         unsafe { ffi::gsl_vector_float_div(self.vec, other.vec) }
     }
 
     /// This function multiplies the elements of the self vector by the constant factor x. The result a_i <- a_i is stored in self.
-    pub fn scale(&self, x: f32) -> enums::Value {
+    pub fn scale(&mut self, x: f32) -> enums::Value {
         unsafe { ffi::gsl_vector_float_scale(self.vec, x) }
     }
 
     /// This function adds the constant value x to the elements of the self vector. The result a_i <- a_i + x is stored in self.
-    pub fn add_constant(&self, x: f32) -> enums::Value {
+    pub fn add_constant(&mut self, x: f32) -> enums::Value {
         unsafe { ffi::gsl_vector_float_add_constant(self.vec, x) }
     }
 
@@ -360,20 +662,56 @@ impl VectorF32 {
         }
     }
 
-    // I'll find a way to do that later
-    /*pub fn as_slice<'a>(&self) -> &'a [f32] {
+    /// Returns the elements of the vector as a slice, without copying them. This is only possible if the elements are contiguous in
+    /// memory, so None is returned if the stride of the vector is not 1.
+    pub fn as_slice(&self) -> Option<&[f32]> {
         unsafe {
-            if self.vec.is_null() {
-                let tmp : Vec<f32> = Vec::new();
-
-                tmp.as_ref()
+            if self.vec.is_null() || (*self.vec).stride != 1 {
+                None
+            } else if (*self.vec).size == 0 {
+                Some(&[])
             } else {
-                let tmp : CSlice<f32> = CSlice::new((*self.vec).data, (*self.vec).size as usize);
+                Some(::std::slice::from_raw_parts((*self.vec).data, (*self.vec).size))
+            }
+        }
+    }
 
-                tmp.as_ref()
+    /// Returns the elements of the vector as a mutable slice, without copying them. This is only possible if the elements are
+    /// contiguous in memory, so None is returned if the stride of the vector is not 1.
+    pub fn as_mut_slice(&mut self) -> Option<&mut [f32]> {
+        unsafe {
+            if self.vec.is_null() || (*self.vec).stride != 1 {
+                None
+            } else if (*self.vec).size == 0 {
+                Some(&mut [])
+            } else {
+                Some(::std::slice::from_raw_parts_mut((*self.vec).data, (*self.vec).size))
             }
         }
-    }*/
+    }
+
+    /// Returns a view of n elements of the vector, starting at offset. The view borrows the vector, so it cannot outlive it.
+    pub fn subvector<'a>(&'a self, offset: usize, n: usize) -> VectorViewF32<'a> {
+        VectorViewF32::from_vector(self, offset, n)
+    }
+
+    /// Returns a view of n elements of the vector, starting at offset with a step-size of stride. The view borrows the vector, so it
+    /// cannot outlive it.
+    pub fn subvector_with_stride<'a>(&'a self, offset: usize, stride: usize, n: usize) -> VectorViewF32<'a> {
+        VectorViewF32::from_vector_with_stride(self, offset, stride, n)
+    }
+
+    /// Returns a view of n elements of the vector, starting at offset, through which they can be modified. The view borrows mutably the
+    /// vector, so it cannot outlive it.
+    pub fn subvector_mut<'a>(&'a mut self, offset: usize, n: usize) -> VectorViewMutF32<'a> {
+        VectorViewMutF32::from_vector(self, offset, n)
+    }
+
+    /// Returns a view of n elements of the vector, starting at offset with a step-size of stride, through which they can be modified.
+    /// The view borrows mutably the vector, so it cannot outlive it.
+    pub fn subvector_with_stride_mut<'a>(&'a mut self, offset: usize, stride: usize, n: usize) -> VectorViewMutF32<'a> {
+        VectorViewMutF32::from_vector_with_stride(self, offset, stride, n)
+    }
 
     pub fn clone(&self) -> Option<VectorF32> {
         unsafe {
@@ -381,7 +719,7 @@ impl VectorF32 {
                 None
             } else {
                 match VectorF32::new((*self.vec).size) {
-                    Some(v) => {
+                    Some(mut v) => {
                         v.copy_from(self);
                         Some(v)
                     }
@@ -457,7 +795,7 @@ impl VectorF64 {
         if tmp.is_null() {
             None
         } else {
-            let v = VectorF64 {
+            let mut v = VectorF64 {
                 vec: tmp,
                 can_free: true
             };
@@ -485,85 +823,85 @@ impl VectorF64 {
     }
 
     /// This function sets the value of the i-th element of a vector v to x. If i lies outside the allowed range of 0 to n-1 then the error handler is invoked.
-    pub fn set(&self, i: usize, x: f64) -> &VectorF64 {
+    pub fn set(&mut self, i: usize, x: f64) -> &mut VectorF64 {
         unsafe { ffi::gsl_vector_set(self.vec, i, x) };
         self
     }
 
     /// This function sets all the elements of the vector v to the value x.
-    pub fn set_all(&self, x: f64) -> &VectorF64 {
+    pub fn set_all(&mut self, x: f64) -> &mut VectorF64 {
         unsafe { ffi::gsl_vector_set_all(self.vec, x) };
         self
     }
 
     /// This function sets all the elements of the vector v to zero.
-    pub fn set_zero(&self) -> &VectorF64 {
+    pub fn set_zero(&mut self) -> &mut VectorF64 {
         unsafe { ffi::gsl_vector_set_zero(self.vec) };
         self
     }
 
     /// This function makes a basis vector by setting all the elements of the vector v to zero except for the i-th element which is set to one.
-    pub fn set_basis(&self, i: usize) -> &VectorF64 {
+    pub fn set_basis(&mut self, i: usize) -> &mut VectorF64 {
         unsafe { ffi::gsl_vector_set_basis(self.vec, i) };
         self
     }
 
     /// This function copies the elements of the other vector into the self vector. The two vectors must have the same length.
-    pub fn copy_from(&self, other: &VectorF64) -> enums::Value {
+    pub fn copy_from(&mut self, other: &VectorF64) -> enums::Value {
         unsafe { ffi::gsl_vector_memcpy(self.vec, other.vec) }
     }
 
     /// This function copies the elements of the self vector into the other vector. The two vectors must have the same length.
-    pub fn copy_to(&self, other: &VectorF64) -> enums::Value {
+    pub fn copy_to(&self, other: &mut VectorF64) -> enums::Value {
         unsafe { ffi::gsl_vector_memcpy(other.vec, self.vec) }
     }
 
     /// This function exchanges the elements of the vectors by copying. The two vectors must have the same length.
-    pub fn swap(&self, other: &VectorF64) -> enums::Value {
+    pub fn swap(&mut self, other: &mut VectorF64) -> enums::Value {
         unsafe { ffi::gsl_vector_swap(other.vec, self.vec) }
     }
 
     /// This function exchanges the i-th and j-th elements of the vector v in-place.
-    pub fn swap_elements(&self, i: usize, j: usize) -> enums::Value {
+    pub fn swap_elements(&mut self, i: usize, j: usize) -> enums::Value {
         unsafe { ffi::gsl_vector_swap_elements(self.vec, i, j) }
     }
 
     /// This function reverses the order of the elements of the vector v.
-    pub fn reverse(&self) -> enums::Value {
+    pub fn reverse(&mut self) -> enums::Value {
         unsafe { ffi::gsl_vector_reverse(self.vec) }
     }
 
     /// This function adds the elements of the other vector to the elements of the self vector.
     /// The result a_i <- a_i + b_i is stored in self and other remains unchanged. The two vectors must have the same length.
-    pub fn add(&self, other: &VectorF64) -> enums::Value {
+    pub fn add(&mut self, other: &VectorF64) -> enums::Value {
         unsafe { ffi::gsl_vector_add(self.vec, other.vec) }
     }
 
     /// This function subtracts the elements of the self vector from the elements of the other vector.
     /// The result a_i <- a_i - b_i is stored in self and other remains unchanged. The two vectors must have the same length.
-    pub fn sub(&self, other: &VectorF64) -> enums::Value {
+    pub fn sub(&mut self, other: &VectorF64) -> enums::Value {
         unsafe { ffi::gsl_vector_sub(self.vec, other.vec) }
     }
 
     /// This function multiplies the elements of the self vector a by the elements of the other vector.
     /// The result a_i <- a_i * b_i is stored in self and other remains unchanged. The two vectors must have the same length.
-    pub fn mul(&self, other: &VectorF64) -> enums::Value {
+    pub fn mul(&mut self, other: &VectorF64) -> enums::Value {
         unsafe { ffi::gsl_vector_mul(self.vec, other.vec) }
     }
 
     /// This function divides the elements of the self vector by the elements of the other vector.
     /// The result a_i <- a_i / b_i is stored in self and other remains unchanged. The two vectors must have the same length.
-    pub fn div(&self, other: &VectorF64) -> enums::Value {
+    pub fn div(&mut self, other: &VectorF64) -> enums::Value {
         unsafe { ffi::gsl_vector_div(self.vec, other.vec) }
     }
 
     /// This function multiplies the elements of the self vector by the constant factor x. The result a_i <- a_i is stored in self.
-    pub fn scale(&self, x: f64) -> enums::Value {
+    pub fn scale(&mut self, x: f64) -> enums::Value {
         unsafe { ffi::gsl_vector_scale(self.vec, x) }
     }
 
     /// This function adds the constant value x to the elements of the self vector. The result a_i <- a_i + x is stored in self.
-    pub fn add_constant(&self, x: f64) -> enums::Value {
+    pub fn add_constant(&mut self, x: f64) -> enums::Value {
         unsafe { ffi::gsl_vector_add_constant(self.vec, x) }
     }
 
@@ -643,20 +981,56 @@ impl VectorF64 {
         }
     }
 
-    // I'll find a way to do that later
-    /*pub fn as_slice<'a>(&self) -> &'a [f64] {
+    /// Returns the elements of the vector as a slice, without copying them. This is only possible if the elements are contiguous in
+    /// memory, so None is returned if the stride of the vector is not 1.
+    pub fn as_slice(&self) -> Option<&[f64]> {
         unsafe {
-            if self.vec.is_null() {
-                let tmp : Vec<f64> = Vec::new();
-
-                tmp.as_ref()
+            if self.vec.is_null() || (*self.vec).stride != 1 {
+                None
+            } else if (*self.vec).size == 0 {
+                Some(&[])
             } else {
-                let tmp : CSlice<f64> = CSlice::new((*self.vec).data, (*self.vec).size as usize);
+                Some(::std::slice::from_raw_parts((*self.vec).data, (*self.vec).size))
+            }
+        }
+    }
 
-                tmp.as_ref()
+    /// Returns the elements of the vector as a mutable slice, without copying them. This is only possible if the elements are
+    /// contiguous in memory, so None is returned if the stride of the vector is not 1.
+    pub fn as_mut_slice(&mut self) -> Option<&mut [f64]> {
+        unsafe {
+            if self.vec.is_null() || (*self.vec).stride != 1 {
+                None
+            } else if (*self.vec).size == 0 {
+                Some(&mut [])
+            } else {
+                Some(::std::slice::from_raw_parts_mut((*self.vec).data, (*self.vec).size))
             }
         }
-    }*/
+    }
+
+    /// Returns a view of n elements of the vector, starting at offset. The view borrows the vector, so it cannot outlive it.
+    pub fn subvector<'a>(&'a self, offset: usize, n: usize) -> VectorView<'a> {
+        VectorView::from_vector(self, offset, n)
+    }
+
+    /// Returns a view of n elements of the vector, starting at offset with a step-size of stride. The view borrows the vector, so it
+    /// cannot outlive it.
+    pub fn subvector_with_stride<'a>(&'a self, offset: usize, stride: usize, n: usize) -> VectorView<'a> {
+        VectorView::from_vector_with_stride(self, offset, stride, n)
+    }
+
+    /// Returns a view of n elements of the vector, starting at offset, through which they can be modified. The view borrows mutably the
+    /// vector, so it cannot outlive it.
+    pub fn subvector_mut<'a>(&'a mut self, offset: usize, n: usize) -> VectorViewMut<'a> {
+        VectorViewMut::from_vector(self, offset, n)
+    }
+
+    /// Returns a view of n elements of the vector, starting at offset with a step-size of stride, through which they can be modified.
+    /// The view borrows mutably the vector, so it cannot outlive it.
+    pub fn subvector_with_stride_mut<'a>(&'a mut self, offset: usize, stride: usize, n: usize) -> VectorViewMut<'a> {
+        VectorViewMut::from_vector_with_stride(self, offset, stride, n)
+    }
 
     pub fn clone(&self) -> Option<VectorF64> {
         unsafe {
@@ -664,7 +1038,7 @@ impl VectorF64 {
                 None
             } else {
                 match VectorF64::new((*self.vec).size) {
-                    Some(v) => {
+                    Some(mut v) => {
                         v.copy_from(self);
                         Some(v)
                     }
@@ -719,4 +1093,4 @@ pub fn wrap(v: *mut ffi::gsl_vector) -> VectorF64 {
         vec: v,
         can_free: false
     }
-}
\ No newline at end of file
+}
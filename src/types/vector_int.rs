@@ -0,0 +1,404 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+/*!
+#Integer vectors
+
+GSL provides a vector type for each of the standard C numeric types, with the same functions as gsl_vector. The integer ones are wrapped
+here as VectorI32 (gsl_vector_int), VectorU32 (gsl_vector_uint), VectorI64 (gsl_vector_long) and VectorU8 (gsl_vector_uchar), with the
+same API as VectorF64. VectorI64 uses the C type long, which is 64 bits wide on all the LP64 platforms supported by this binding.
+
+The vectors of long double (gsl_vector_long_double) are not wrapped, as Rust has no floating point type matching long double.
+
+The elements of an integer vector can be sorted with `sort`, or sorted indirectly into a Permutation with `sort_index`. A Permutation
+can then be applied to the vector with `permute` and `permute_inverse`.
+!*/
+
+use std::fmt;
+use std::fmt::{Formatter, Debug};
+use ffi;
+use enums;
+use types::Permutation;
+
+macro_rules! impl_vector_int {
+    ($vector:ident, $elem:ty, $ffi:ident,
+     alloc: $alloc:ident, calloc: $calloc:ident, free: $free:ident, get: $get:ident, set: $set:ident, set_all: $set_all:ident,
+     set_zero: $set_zero:ident, set_basis: $set_basis:ident, memcpy: $memcpy:ident, swap: $swap:ident,
+     swap_elements: $swap_elements:ident, reverse: $reverse:ident, add: $add:ident, sub: $sub:ident, mul: $mul:ident, div: $div:ident,
+     scale: $scale:ident, add_constant: $add_constant:ident, max: $max:ident, min: $min:ident, minmax: $minmax:ident,
+     max_index: $max_index:ident, min_index: $min_index:ident, minmax_index: $minmax_index:ident, isnull: $isnull:ident,
+     ispos: $ispos:ident, isneg: $isneg:ident, isnonneg: $isnonneg:ident, equal: $equal:ident, permute: $permute:ident,
+     permute_inverse: $permute_inverse:ident, sort: $sort:ident, sort_index: $sort_index:ident) => (
+        pub struct $vector {
+            vec: *mut ffi::$ffi,
+            can_free: bool
+        }
+
+        impl $vector {
+            /// create a new vector with all elements set to zero
+            pub fn new(size: usize) -> Option<$vector> {
+                let tmp = unsafe { ffi::$calloc(size) };
+
+                if tmp.is_null() {
+                    None
+                } else {
+                    Some($vector {
+                        vec: tmp,
+                        can_free: true
+                    })
+                }
+            }
+
+            pub fn from_slice(slice: &[$elem]) -> Option<$vector> {
+                let tmp = unsafe { ffi::$alloc(slice.len() as usize) };
+
+                if tmp.is_null() {
+                    None
+                } else {
+                    let mut v = $vector {
+                        vec: tmp,
+                        can_free: true
+                    };
+
+                    for (pos, tmp) in slice.iter().enumerate() {
+                        v.set(pos, *tmp);
+                    }
+                    Some(v)
+                }
+            }
+
+            pub fn len(&self) -> usize {
+                if self.vec.is_null() {
+                    0usize
+                } else {
+                    unsafe { (*self.vec).size }
+                }
+            }
+
+            /// This function returns the i-th element of a vector v. If i lies outside the allowed range of 0 to n-1 then the error handler
+            /// is invoked and 0 is returned.
+            pub fn get(&self, i: usize) -> $elem {
+                unsafe { ffi::$get(self.vec, i) }
+            }
+
+            /// This function sets the value of the i-th element of a vector v to x. If i lies outside the allowed range of 0 to n-1 then
+            /// the error handler is invoked.
+            pub fn set(&mut self, i: usize, x: $elem) -> &mut $vector {
+                unsafe { ffi::$set(self.vec, i, x) };
+                self
+            }
+
+            /// This function sets all the elements of the vector v to the value x.
+            pub fn set_all(&mut self, x: $elem) -> &mut $vector {
+                unsafe { ffi::$set_all(self.vec, x) };
+                self
+            }
+
+            /// This function sets all the elements of the vector v to zero.
+            pub fn set_zero(&mut self) -> &mut $vector {
+                unsafe { ffi::$set_zero(self.vec) };
+                self
+            }
+
+            /// This function makes a basis vector by setting all the elements of the vector v to zero except for the i-th element which is
+            /// set to one.
+            pub fn set_basis(&mut self, i: usize) -> &mut $vector {
+                unsafe { ffi::$set_basis(self.vec, i) };
+                self
+            }
+
+            /// This function copies the elements of the other vector into the self vector. The two vectors must have the same length.
+            pub fn copy_from(&mut self, other: &$vector) -> enums::Value {
+                unsafe { ffi::$memcpy(self.vec, other.vec) }
+            }
+
+            /// This function copies the elements of the self vector into the other vector. The two vectors must have the same length.
+            pub fn copy_to(&self, other: &mut $vector) -> enums::Value {
+                unsafe { ffi::$memcpy(other.vec, self.vec) }
+            }
+
+            /// This function exchanges the elements of the vectors by copying. The two vectors must have the same length.
+            pub fn swap(&mut self, other: &mut $vector) -> enums::Value {
+                unsafe { ffi::$swap(other.vec, self.vec) }
+            }
+
+            /// This function exchanges the i-th and j-th elements of the vector v in-place.
+            pub fn swap_elements(&mut self, i: usize, j: usize) -> enums::Value {
+                unsafe { ffi::$swap_elements(self.vec, i, j) }
+            }
+
+            /// This function reverses the order of the elements of the vector v.
+            pub fn reverse(&mut self) -> enums::Value {
+                unsafe { ffi::$reverse(self.vec) }
+            }
+
+            /// This function adds the elements of the other vector to the elements of the self vector.
+            /// The result a_i <- a_i + b_i is stored in self and other remains unchanged. The two vectors must have the same length.
+            pub fn add(&mut self, other: &$vector) -> enums::Value {
+                unsafe { ffi::$add(self.vec, other.vec) }
+            }
+
+            /// This function subtracts the elements of the self vector from the elements of the other vector.
+            /// The result a_i <- a_i - b_i is stored in self and other remains unchanged. The two vectors must have the same length.
+            pub fn sub(&mut self, other: &$vector) -> enums::Value {
+                unsafe { ffi::$sub(self.vec, other.vec) }
+            }
+
+            /// This function multiplies the elements of the self vector a by the elements of the other vector.
+            /// The result a_i <- a_i * b_i is stored in self and other remains unchanged. The two vectors must have the same length.
+            pub fn mul(&mut self, other: &$vector) -> enums::Value {
+                unsafe { ffi::$mul(self.vec, other.vec) }
+            }
+
+            /// This function divides the elements of the self vector by the elements of the other vector.
+            /// The result a_i <- a_i / b_i is stored in self and other remains unchanged. The two vectors must have the same length.
+            pub fn div(&mut self, other: &$vector) -> enums::Value {
+                unsafe { ffi::$div(self.vec, other.vec) }
+            }
+
+            /// This function multiplies the elements of the self vector by the constant factor x. The result a_i <- a_i is stored in self.
+            /// x is converted to a double, as GSL takes a double for this function.
+            pub fn scale(&mut self, x: $elem) -> enums::Value {
+                unsafe { ffi::$scale(self.vec, x as f64) }
+            }
+
+            /// This function adds the constant value x to the elements of the self vector. The result a_i <- a_i + x is stored in self.
+            /// x is converted to a double, as GSL takes a double for this function.
+            pub fn add_constant(&mut self, x: $elem) -> enums::Value {
+                unsafe { ffi::$add_constant(self.vec, x as f64) }
+            }
+
+            /// This function returns the maximum value in the self vector.
+            pub fn max(&self) -> $elem {
+                unsafe { ffi::$max(self.vec) }
+            }
+
+            /// This function returns the minimum value in the self vector.
+            pub fn min(&self) -> $elem {
+                unsafe { ffi::$min(self.vec) }
+            }
+
+            /// This function returns the minimum and maximum values in the self vector, storing them in min_out and max_out.
+            pub fn minmax(&self, min_out: &mut $elem, max_out: &mut $elem) {
+                unsafe { ffi::$minmax(self.vec, min_out, max_out) }
+            }
+
+            /// This function returns the index of the maximum value in the self vector.
+            /// When there are several equal maximum elements then the lowest index is returned.
+            pub fn max_index(&self) -> usize {
+                unsafe { ffi::$max_index(self.vec) }
+            }
+
+            /// This function returns the index of the minimum value in the self vector.
+            /// When there are several equal minimum elements then the lowest index is returned.
+            pub fn min_index(&self) -> usize {
+                unsafe { ffi::$min_index(self.vec) }
+            }
+
+            /// This function returns the indices of the minimum and maximum values in the self vector, storing them in imin and imax.
+            /// When there are several equal minimum or maximum elements then the lowest indices are returned.
+            pub fn minmax_index(&self) -> (usize, usize) {
+                let mut imin = 0usize;
+                let mut imax = 0usize;
+
+                unsafe { ffi::$minmax_index(self.vec, &mut imin, &mut imax) };
+                (imin, imax)
+            }
+
+            /// This function returns true if all the elements of the self vector are equal to 0.
+            pub fn is_null(&self) -> bool {
+                match unsafe { ffi::$isnull(self.vec) } {
+                    1 => true,
+                    _ => false
+                }
+            }
+
+            /// This function returns true if all the elements of the self vector are stricly positive.
+            pub fn is_pos(&self) -> bool {
+                match unsafe { ffi::$ispos(self.vec) } {
+                    1 => true,
+                    _ => false
+                }
+            }
+
+            /// This function returns true if all the elements of the self vector are stricly negative.
+            pub fn is_neg(&self) -> bool {
+                match unsafe { ffi::$isneg(self.vec) } {
+                    1 => true,
+                    _ => false
+                }
+            }
+
+            /// This function returns true if all the elements of the self vector are stricly non-negative.
+            pub fn is_non_neg(&self) -> bool {
+                match unsafe { ffi::$isnonneg(self.vec) } {
+                    1 => true,
+                    _ => false
+                }
+            }
+
+            pub fn equal(&self, other: &$vector) -> bool {
+                match unsafe { ffi::$equal(self.vec, other.vec) } {
+                    1 => true,
+                    _ => false
+                }
+            }
+
+            /// Returns the elements of the vector as a slice, without copying them. This is only possible if the elements are contiguous
+            /// in memory, so None is returned if the stride of the vector is not 1.
+            pub fn as_slice(&self) -> Option<&[$elem]> {
+                unsafe {
+                    if self.vec.is_null() || (*self.vec).stride != 1 {
+                        None
+                    } else if (*self.vec).size == 0 {
+                        Some(&[])
+                    } else {
+                        Some(::std::slice::from_raw_parts((*self.vec).data, (*self.vec).size))
+                    }
+                }
+            }
+
+            /// Returns the elements of the vector as a mutable slice, without copying them. This is only possible if the elements are
+            /// contiguous in memory, so None is returned if the stride of the vector is not 1.
+            pub fn as_mut_slice(&mut self) -> Option<&mut [$elem]> {
+                unsafe {
+                    if self.vec.is_null() || (*self.vec).stride != 1 {
+                        None
+                    } else if (*self.vec).size == 0 {
+                        Some(&mut [])
+                    } else {
+                        Some(::std::slice::from_raw_parts_mut((*self.vec).data, (*self.vec).size))
+                    }
+                }
+            }
+
+            /// This function applies the permutation p to the elements of the vector, v' = v P. The permutation p and the vector must have
+            /// the same length.
+            pub fn permute(&mut self, p: &Permutation) -> enums::Value {
+                unsafe { ffi::$permute(ffi::FFI::unwrap(p), self.vec) }
+            }
+
+            /// This function applies the inverse of the permutation p to the elements of the vector, v' = v P^T. The permutation p and the
+            /// vector must have the same length.
+            pub fn permute_inverse(&mut self, p: &Permutation) -> enums::Value {
+                unsafe { ffi::$permute_inverse(ffi::FFI::unwrap(p), self.vec) }
+            }
+
+            /// This function sorts the elements of the vector into ascending numerical order.
+            pub fn sort(&mut self) {
+                unsafe { ffi::$sort(self.vec) }
+            }
+
+            /// This function indirectly sorts the elements of the vector into ascending order, storing the resulting permutation in p. The
+            /// first element of p gives the index of the least element of the vector, and the last element of p gives the index of the
+            /// greatest one. The vector is not changed.
+            pub fn sort_index(&self, p: &Permutation) -> enums::Value {
+                unsafe { ffi::$sort_index(ffi::FFI::unwrap(p), self.vec) }
+            }
+
+            pub fn clone(&self) -> Option<$vector> {
+                unsafe {
+                    if self.vec.is_null() {
+                        None
+                    } else {
+                        match $vector::new((*self.vec).size) {
+                            Some(mut v) => {
+                                v.copy_from(self);
+                                Some(v)
+                            }
+                            None => None
+                        }
+                    }
+                }
+            }
+        }
+
+        impl Drop for $vector {
+            fn drop(&mut self) {
+                if self.can_free {
+                    unsafe { ffi::$free(self.vec) };
+                    self.vec = ::std::ptr::null_mut();
+                }
+            }
+        }
+
+        impl Debug for $vector {
+            #[allow(unused_must_use)]
+            fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+                unsafe {
+                    write!(f, "[");
+                    for x in 0usize..(*self.vec).size {
+                        if x < (*self.vec).size - 1 {
+                            write!(f, "{}, ", self.get(x));
+                        } else {
+                            write!(f, "{}", self.get(x));
+                        }
+                    }
+                }
+                write!(f, "]")
+            }
+        }
+
+        impl ffi::FFI<ffi::$ffi> for $vector {
+            fn wrap(r: *mut ffi::$ffi) -> $vector {
+                $vector {
+                    vec: r,
+                    can_free: true
+                }
+            }
+
+            fn unwrap(v: &$vector) -> *mut ffi::$ffi {
+                v.vec
+            }
+        }
+    );
+}
+
+impl_vector_int!(VectorI32, i32, gsl_vector_int,
+    alloc: gsl_vector_int_alloc, calloc: gsl_vector_int_calloc, free: gsl_vector_int_free, get: gsl_vector_int_get,
+    set: gsl_vector_int_set, set_all: gsl_vector_int_set_all, set_zero: gsl_vector_int_set_zero, set_basis: gsl_vector_int_set_basis,
+    memcpy: gsl_vector_int_memcpy, swap: gsl_vector_int_swap, swap_elements: gsl_vector_int_swap_elements,
+    reverse: gsl_vector_int_reverse, add: gsl_vector_int_add, sub: gsl_vector_int_sub, mul: gsl_vector_int_mul,
+    div: gsl_vector_int_div, scale: gsl_vector_int_scale, add_constant: gsl_vector_int_add_constant, max: gsl_vector_int_max,
+    min: gsl_vector_int_min, minmax: gsl_vector_int_minmax, max_index: gsl_vector_int_max_index, min_index: gsl_vector_int_min_index,
+    minmax_index: gsl_vector_int_minmax_index, isnull: gsl_vector_int_isnull, ispos: gsl_vector_int_ispos,
+    isneg: gsl_vector_int_isneg, isnonneg: gsl_vector_int_isnonneg, equal: gsl_vector_int_equal, permute: gsl_permute_vector_int,
+    permute_inverse: gsl_permute_vector_int_inverse, sort: gsl_sort_vector_int, sort_index: gsl_sort_vector_int_index);
+
+impl_vector_int!(VectorU32, u32, gsl_vector_uint,
+    alloc: gsl_vector_uint_alloc, calloc: gsl_vector_uint_calloc, free: gsl_vector_uint_free, get: gsl_vector_uint_get,
+    set: gsl_vector_uint_set, set_all: gsl_vector_uint_set_all, set_zero: gsl_vector_uint_set_zero,
+    set_basis: gsl_vector_uint_set_basis, memcpy: gsl_vector_uint_memcpy, swap: gsl_vector_uint_swap,
+    swap_elements: gsl_vector_uint_swap_elements, reverse: gsl_vector_uint_reverse, add: gsl_vector_uint_add,
+    sub: gsl_vector_uint_sub, mul: gsl_vector_uint_mul, div: gsl_vector_uint_div, scale: gsl_vector_uint_scale,
+    add_constant: gsl_vector_uint_add_constant, max: gsl_vector_uint_max, min: gsl_vector_uint_min, minmax: gsl_vector_uint_minmax,
+    max_index: gsl_vector_uint_max_index, min_index: gsl_vector_uint_min_index, minmax_index: gsl_vector_uint_minmax_index,
+    isnull: gsl_vector_uint_isnull, ispos: gsl_vector_uint_ispos, isneg: gsl_vector_uint_isneg,
+    isnonneg: gsl_vector_uint_isnonneg, equal: gsl_vector_uint_equal, permute: gsl_permute_vector_uint,
+    permute_inverse: gsl_permute_vector_uint_inverse, sort: gsl_sort_vector_uint, sort_index: gsl_sort_vector_uint_index);
+
+impl_vector_int!(VectorI64, i64, gsl_vector_long,
+    alloc: gsl_vector_long_alloc, calloc: gsl_vector_long_calloc, free: gsl_vector_long_free, get: gsl_vector_long_get,
+    set: gsl_vector_long_set, set_all: gsl_vector_long_set_all, set_zero: gsl_vector_long_set_zero,
+    set_basis: gsl_vector_long_set_basis, memcpy: gsl_vector_long_memcpy, swap: gsl_vector_long_swap,
+    swap_elements: gsl_vector_long_swap_elements, reverse: gsl_vector_long_reverse, add: gsl_vector_long_add,
+    sub: gsl_vector_long_sub, mul: gsl_vector_long_mul, div: gsl_vector_long_div, scale: gsl_vector_long_scale,
+    add_constant: gsl_vector_long_add_constant, max: gsl_vector_long_max, min: gsl_vector_long_min, minmax: gsl_vector_long_minmax,
+    max_index: gsl_vector_long_max_index, min_index: gsl_vector_long_min_index, minmax_index: gsl_vector_long_minmax_index,
+    isnull: gsl_vector_long_isnull, ispos: gsl_vector_long_ispos, isneg: gsl_vector_long_isneg,
+    isnonneg: gsl_vector_long_isnonneg, equal: gsl_vector_long_equal, permute: gsl_permute_vector_long,
+    permute_inverse: gsl_permute_vector_long_inverse, sort: gsl_sort_vector_long, sort_index: gsl_sort_vector_long_index);
+
+impl_vector_int!(VectorU8, u8, gsl_vector_uchar,
+    alloc: gsl_vector_uchar_alloc, calloc: gsl_vector_uchar_calloc, free: gsl_vector_uchar_free, get: gsl_vector_uchar_get,
+    set: gsl_vector_uchar_set, set_all: gsl_vector_uchar_set_all, set_zero: gsl_vector_uchar_set_zero,
+    set_basis: gsl_vector_uchar_set_basis, memcpy: gsl_vector_uchar_memcpy, swap: gsl_vector_uchar_swap,
+    swap_elements: gsl_vector_uchar_swap_elements, reverse: gsl_vector_uchar_reverse, add: gsl_vector_uchar_add,
+    sub: gsl_vector_uchar_sub, mul: gsl_vector_uchar_mul, div: gsl_vector_uchar_div, scale: gsl_vector_uchar_scale,
+    add_constant: gsl_vector_uchar_add_constant, max: gsl_vector_uchar_max, min: gsl_vector_uchar_min,
+    minmax: gsl_vector_uchar_minmax, max_index: gsl_vector_uchar_max_index, min_index: gsl_vector_uchar_min_index,
+    minmax_index: gsl_vector_uchar_minmax_index, isnull: gsl_vector_uchar_isnull, ispos: gsl_vector_uchar_ispos,
+    isneg: gsl_vector_uchar_isneg, isnonneg: gsl_vector_uchar_isnonneg, equal: gsl_vector_uchar_equal,
+    permute: gsl_permute_vector_uchar, permute_inverse: gsl_permute_vector_uchar_inverse, sort: gsl_sort_vector_uchar,
+    sort_index: gsl_sort_vector_uchar_index);